@@ -0,0 +1,23 @@
+//! Module implementing the Solidity contract ABI: human-readable function
+//! signatures, selectors, and encoding and decoding of calldata, return
+//! data, revert reasons and event logs, as well as loading JSON ABIs.
+
+pub mod contract;
+pub mod encoding;
+pub mod error;
+pub mod event;
+pub mod function;
+pub mod revert;
+pub mod types;
+pub mod value;
+
+pub use self::{
+    contract::Contract,
+    encoding::{decode, encode},
+    error::CustomError,
+    event::{Event, LogParam, LogValue},
+    function::{selector, Function, Param},
+    revert::Revert,
+    types::ParamType,
+    value::Value,
+};
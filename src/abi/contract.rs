@@ -0,0 +1,368 @@
+//! Module for loading contract JSON ABIs, as produced by `solc` and found in
+//! Foundry and Hardhat build artifacts.
+
+use super::{
+    error::CustomError,
+    event::{Event, LogParam},
+    function::{Function, Param},
+    types::ParamType,
+    value::Value,
+};
+use anyhow::{Context as _, Result};
+use serde::Deserialize;
+use std::{fs, path::Path};
+
+/// The functions, events and errors of a contract.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Contract {
+    pub functions: Vec<Function>,
+    pub events: Vec<Event>,
+    pub errors: Vec<CustomError>,
+}
+
+impl Contract {
+    /// Parses a JSON ABI, either a plain array of ABI items or a build
+    /// artifact object with an `abi` field.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let mut json = serde_json::from_str::<serde_json::Value>(json)?;
+        if let Some(abi) = json.get_mut("abi") {
+            json = abi.take();
+        }
+
+        let mut contract = Self::default();
+        for item in serde_json::from_value::<Vec<Item>>(json)? {
+            match item {
+                Item::Function {
+                    name,
+                    inputs,
+                    outputs,
+                } => contract.functions.push(Function {
+                    name,
+                    inputs: params(inputs)?,
+                    outputs: params(outputs)?,
+                }),
+                Item::Event {
+                    name,
+                    inputs,
+                    anonymous,
+                } => contract.events.push(Event {
+                    name,
+                    inputs: params(inputs)?,
+                    anonymous,
+                }),
+                Item::Error { name, inputs } => contract.errors.push(CustomError {
+                    name,
+                    inputs: params(inputs)?,
+                }),
+                Item::Constructor {} | Item::Fallback {} | Item::Receive {} => {}
+            }
+        }
+        Ok(contract)
+    }
+
+    /// Loads a JSON ABI or build artifact file.
+    pub fn load<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path)?;
+        Self::from_json(&json).with_context(|| format!("invalid JSON ABI {}", path.display()))
+    }
+
+    /// Returns the function with the specified name, or with the specified
+    /// canonical signature for overloaded functions.
+    pub fn function(&self, name: &str) -> Result<&Function> {
+        find(
+            &self.functions,
+            name,
+            |f| &f.name,
+            Function::signature,
+            "function",
+        )
+    }
+
+    /// Returns the function with the specified selector.
+    pub fn function_by_selector(&self, selector: [u8; 4]) -> Option<&Function> {
+        self.functions.iter().find(|f| f.selector() == selector)
+    }
+
+    /// Returns the event with the specified name, or with the specified
+    /// canonical signature for overloaded events.
+    pub fn event(&self, name: &str) -> Result<&Event> {
+        find(&self.events, name, |e| &e.name, Event::signature, "event")
+    }
+
+    /// Returns the non-anonymous event with the specified topic.
+    pub fn event_by_topic(&self, topic: [u8; 32]) -> Option<&Event> {
+        self.events
+            .iter()
+            .find(|e| !e.anonymous && e.topic() == topic)
+    }
+
+    /// Returns the error with the specified name, or with the specified
+    /// canonical signature for overloaded errors.
+    pub fn error(&self, name: &str) -> Result<&CustomError> {
+        find(
+            &self.errors,
+            name,
+            |e| &e.name,
+            CustomError::signature,
+            "error",
+        )
+    }
+
+    /// Returns the error with the specified selector.
+    pub fn error_by_selector(&self, selector: [u8; 4]) -> Option<&CustomError> {
+        self.errors.iter().find(|e| e.selector() == selector)
+    }
+
+    /// Decodes a log emitted by one of the contract's non-anonymous events,
+    /// identified by its first topic.
+    pub fn decode_log(&self, topics: &[[u8; 32]], data: &[u8]) -> Result<(&Event, Vec<LogParam>)> {
+        let topic = topics.first().context("log without topics")?;
+        let event = self
+            .event_by_topic(*topic)
+            .with_context(|| format!("unknown event topic 0x{}", hex::encode(topic)))?;
+        Ok((event, event.decode_log(topics, data)?))
+    }
+
+    /// Decodes revert data raised with one of the contract's custom errors.
+    pub fn decode_error(&self, data: &[u8]) -> Result<(&CustomError, Vec<Value>)> {
+        let selector = data
+            .first_chunk::<4>()
+            .context("revert data shorter than a selector")?;
+        let error = self
+            .error_by_selector(*selector)
+            .with_context(|| format!("unknown error selector 0x{}", hex::encode(selector)))?;
+        Ok((error, error.decode(data)?))
+    }
+}
+
+// Finds an item by name, requiring a signature for overloaded names.
+fn find<'a, T>(
+    items: &'a [T],
+    name: &str,
+    item_name: impl Fn(&T) -> &String,
+    signature: impl Fn(&T) -> String,
+    what: &str,
+) -> Result<&'a T> {
+    if name.contains('(') {
+        return items
+            .iter()
+            .find(|item| signature(item) == name)
+            .with_context(|| format!("unknown {what} {name}"));
+    }
+
+    let mut matches = items.iter().filter(|item| item_name(item) == name);
+    let item = matches
+        .next()
+        .with_context(|| format!("unknown {what} {name}"))?;
+    anyhow::ensure!(
+        matches.next().is_none(),
+        "{what} {name} is overloaded, use its signature"
+    );
+    Ok(item)
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum Item {
+    Function {
+        name: String,
+        #[serde(default)]
+        inputs: Vec<JsonParam>,
+        #[serde(default)]
+        outputs: Vec<JsonParam>,
+    },
+    Event {
+        name: String,
+        #[serde(default)]
+        inputs: Vec<JsonParam>,
+        #[serde(default)]
+        anonymous: bool,
+    },
+    Error {
+        name: String,
+        #[serde(default)]
+        inputs: Vec<JsonParam>,
+    },
+    Constructor {},
+    Fallback {},
+    Receive {},
+}
+
+#[derive(Deserialize)]
+struct JsonParam {
+    #[serde(default)]
+    name: String,
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    components: Vec<JsonParam>,
+    #[serde(default)]
+    indexed: bool,
+}
+
+impl JsonParam {
+    // Returns the canonical type, replacing `tuple` with its components.
+    fn canonical_type(&self) -> String {
+        match self.kind.strip_prefix("tuple") {
+            Some(suffix) => format!(
+                "({}){suffix}",
+                self.components
+                    .iter()
+                    .map(Self::canonical_type)
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+            None => self.kind.clone(),
+        }
+    }
+}
+
+fn params(params: Vec<JsonParam>) -> Result<Vec<Param>> {
+    params
+        .into_iter()
+        .map(|param| {
+            Ok(Param {
+                kind: param
+                    .canonical_type()
+                    .parse::<ParamType>()
+                    .with_context(|| format!("invalid ABI parameter '{}'", param.name))?,
+                name: param.name,
+                indexed: param.indexed,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address;
+    use crate::utils::hash::keccak256;
+    use ethnum::U256;
+    use hex_literal::hex;
+
+    const ABI: &str = r#"{
+        "contractName": "Token",
+        "abi": [
+            {"type": "constructor", "inputs": [], "stateMutability": "nonpayable"},
+            {
+                "type": "function",
+                "name": "transfer",
+                "inputs": [
+                    {"name": "to", "type": "address", "internalType": "address"},
+                    {"name": "amount", "type": "uint256", "internalType": "uint256"}
+                ],
+                "outputs": [{"name": "", "type": "bool", "internalType": "bool"}],
+                "stateMutability": "nonpayable"
+            },
+            {
+                "type": "function",
+                "name": "batch",
+                "inputs": [{
+                    "name": "calls",
+                    "type": "tuple[]",
+                    "components": [
+                        {"name": "target", "type": "address"},
+                        {"name": "data", "type": "bytes"}
+                    ]
+                }],
+                "outputs": []
+            },
+            {
+                "type": "function",
+                "name": "batch",
+                "inputs": [{"name": "data", "type": "bytes[]"}],
+                "outputs": []
+            },
+            {
+                "type": "event",
+                "name": "Transfer",
+                "inputs": [
+                    {"name": "from", "type": "address", "indexed": true},
+                    {"name": "to", "type": "address", "indexed": true},
+                    {"name": "value", "type": "uint256", "indexed": false}
+                ],
+                "anonymous": false
+            },
+            {
+                "type": "error",
+                "name": "InsufficientBalance",
+                "inputs": [
+                    {"name": "available", "type": "uint256"},
+                    {"name": "required", "type": "uint256"}
+                ]
+            },
+            {"type": "receive", "stateMutability": "payable"}
+        ]
+    }"#;
+
+    #[test]
+    fn load_json_abi() {
+        let contract = Contract::from_json(ABI).unwrap();
+        assert_eq!(contract.functions.len(), 3);
+
+        let transfer = contract.function("transfer").unwrap();
+        assert_eq!(
+            *transfer,
+            "transfer(address to, uint256 amount) returns (bool)"
+                .parse()
+                .unwrap()
+        );
+        assert_eq!(
+            contract.function_by_selector(hex!("a9059cbb")),
+            Some(transfer)
+        );
+
+        assert!(contract.function("batch").is_err());
+        assert_eq!(
+            contract
+                .function("batch((address,bytes)[])")
+                .unwrap()
+                .inputs[0]
+                .name,
+            "calls"
+        );
+        assert!(contract.function("approve").is_err());
+
+        let abi = serde_json::from_str::<serde_json::Value>(ABI).unwrap()["abi"].to_string();
+        assert_eq!(Contract::from_json(&abi).unwrap(), contract);
+        assert!(Contract::from_json(
+            r#"[{"type": "function", "name": "f", "inputs": [{"type": "uint7"}]}]"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn decode_logs_and_errors() {
+        let contract = Contract::from_json(ABI).unwrap();
+        let topics = [
+            keccak256("Transfer(address,address,uint256)"),
+            hex!("00000000000000000000000090f8bf6a479f320ead074411a4b0e7944ea8c9c1"),
+            hex!("000000000000000000000000ffcf8fdee72ac11b5c542428b35eef5769c409f0"),
+        ];
+        let (event, log) = contract
+            .decode_log(&topics, &U256::new(5).to_be_bytes())
+            .unwrap();
+        assert_eq!(event.name, "Transfer");
+        assert_eq!(
+            log[0].value.value(),
+            Some(&Value::Address(address!(
+                "0x90F8bf6A479f320ead074411a4B0e7944Ea8c9C1"
+            )))
+        );
+        assert!(contract.decode_log(&topics[1..], &[]).is_err());
+
+        let error = contract.error("InsufficientBalance").unwrap();
+        let mut data = error.selector().to_vec();
+        data.extend_from_slice(&U256::new(1).to_be_bytes());
+        data.extend_from_slice(&U256::new(2).to_be_bytes());
+        let (decoded, args) = contract.decode_error(&data).unwrap();
+        assert_eq!(decoded, error);
+        assert_eq!(args, [Value::Uint(U256::new(1)), Value::Uint(U256::new(2))]);
+        assert!(contract.decode_error(&hex!("08c379a0")).is_err());
+    }
+}
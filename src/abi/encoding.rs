@@ -0,0 +1,315 @@
+//! Module implementing the Solidity contract ABI encoding.
+//!
+//! Values are encoded as a tuple: static values are stored in place in the
+//! head, while dynamic values are stored in the tail with their offset,
+//! relative to the start of the tuple, in the head.
+//!
+//! <https://docs.soliditylang.org/en/latest/abi-spec.html>
+
+use super::{types::ParamType, value::Value};
+use crate::address::Address;
+use anyhow::{ensure, Context as _, Result};
+use ethnum::{I256, U256};
+
+/// ABI encodes values of the specified types.
+pub fn encode(kinds: &[ParamType], values: &[Value]) -> Result<Vec<u8>> {
+    ensure!(
+        kinds.len() == values.len(),
+        "expected {} ABI values but got {}",
+        kinds.len(),
+        values.len(),
+    );
+    for (i, (kind, value)) in kinds.iter().zip(values).enumerate() {
+        ensure!(
+            value.matches(kind),
+            "ABI value #{i} {value} is not a {kind}"
+        );
+    }
+
+    let mut buffer = Vec::new();
+    encode_tuple(kinds.iter(), values, &mut buffer);
+    Ok(buffer)
+}
+
+/// Decodes ABI encoded values of the specified types.
+pub fn decode(kinds: &[ParamType], data: &[u8]) -> Result<Vec<Value>> {
+    decode_tuple(kinds.iter(), data)
+}
+
+fn encode_tuple<'a>(
+    kinds: impl Iterator<Item = &'a ParamType> + Clone,
+    values: &[Value],
+    buffer: &mut Vec<u8>,
+) {
+    let start = buffer.len();
+    let head_size = kinds.clone().map(ParamType::head_size).sum::<usize>();
+    let mut tail = Vec::new();
+    for (kind, value) in kinds.zip(values) {
+        if kind.is_dynamic() {
+            buffer.extend_from_slice(&word(U256::from((head_size + tail.len()) as u64)));
+            encode_value(kind, value, &mut tail);
+        } else {
+            encode_value(kind, value, buffer);
+        }
+    }
+    debug_assert_eq!(buffer.len() - start, head_size);
+    buffer.extend_from_slice(&tail);
+}
+
+fn encode_value(kind: &ParamType, value: &Value, buffer: &mut Vec<u8>) {
+    match (kind, value) {
+        (_, Value::Address(address)) => {
+            buffer.extend_from_slice(&[0; 12]);
+            buffer.extend_from_slice(address.as_slice());
+        }
+        (_, Value::Bool(value)) => buffer.extend_from_slice(&word(U256::from(*value as u8))),
+        (_, Value::Int(value)) => buffer.extend_from_slice(&value.to_be_bytes()),
+        (_, Value::Uint(value)) => buffer.extend_from_slice(&word(*value)),
+        (_, Value::FixedBytes(bytes)) => padded(bytes, buffer),
+        (_, Value::Bytes(bytes)) => {
+            buffer.extend_from_slice(&word(U256::from(bytes.len() as u64)));
+            padded(bytes, buffer);
+        }
+        (_, Value::String(value)) => {
+            buffer.extend_from_slice(&word(U256::from(value.len() as u64)));
+            padded(value.as_bytes(), buffer);
+        }
+        (ParamType::Array(kind), Value::Array(values)) => {
+            buffer.extend_from_slice(&word(U256::from(values.len() as u64)));
+            encode_tuple(std::iter::repeat_n(&**kind, values.len()), values, buffer);
+        }
+        (ParamType::FixedArray(kind, len), Value::Array(values)) => {
+            encode_tuple(std::iter::repeat_n(&**kind, *len), values, buffer);
+        }
+        (ParamType::Tuple(kinds), Value::Tuple(values)) => {
+            encode_tuple(kinds.iter(), values, buffer);
+        }
+        _ => unreachable!("ABI value type checked before encoding"),
+    }
+}
+
+fn decode_tuple<'a>(kinds: impl Iterator<Item = &'a ParamType>, data: &[u8]) -> Result<Vec<Value>> {
+    let mut values = Vec::new();
+    let mut head = 0;
+    for kind in kinds {
+        let value = if kind.is_dynamic() {
+            let offset = read_len(data, head)?;
+            ensure!(offset <= data.len(), "ABI offset {offset} out of bounds");
+            decode_value(kind, &data[offset..])
+        } else {
+            decode_value(kind, data.get(head..).unwrap_or_default())
+        };
+        values.push(value.with_context(|| format!("invalid ABI encoded {kind}"))?);
+        head += kind.head_size();
+    }
+    Ok(values)
+}
+
+fn decode_value(kind: &ParamType, data: &[u8]) -> Result<Value> {
+    Ok(match kind {
+        ParamType::Address => {
+            let word = read_word(data, 0)?;
+            ensure!(word[..12] == [0; 12], "address has dirty upper bytes");
+            Value::Address(Address::from_slice(&word[12..]))
+        }
+        ParamType::Bool => {
+            let word = read_word(data, 0)?;
+            ensure!(word[..31] == [0; 31] && word[31] <= 1, "invalid bool");
+            Value::Bool(word[31] == 1)
+        }
+        ParamType::Int(_) => Value::Int(I256::from_be_bytes(read_word(data, 0)?)),
+        ParamType::Uint(_) => Value::Uint(U256::from_be_bytes(read_word(data, 0)?)),
+        ParamType::FixedBytes(len) => {
+            let word = read_word(data, 0)?;
+            ensure!(
+                word[*len..].iter().all(|b| *b == 0),
+                "fixed bytes have dirty padding"
+            );
+            Value::FixedBytes(word[..*len].to_vec())
+        }
+        ParamType::Bytes => Value::Bytes(read_bytes(data)?.to_vec()),
+        ParamType::String => Value::String(String::from_utf8(read_bytes(data)?.to_vec())?),
+        ParamType::Array(kind) => {
+            let len = read_len(data, 0)?;
+            let data = &data[32..];
+            // NOTE: Every element takes at least one word, which prevents
+            // huge allocations for malicious lengths.
+            ensure!(
+                len.checked_mul(32).is_some_and(|size| size <= data.len()),
+                "array length {len} exceeds data"
+            );
+            Value::Array(decode_tuple(std::iter::repeat_n(&**kind, len), data)?)
+        }
+        ParamType::FixedArray(kind, len) => {
+            Value::Array(decode_tuple(std::iter::repeat_n(&**kind, *len), data)?)
+        }
+        ParamType::Tuple(kinds) => Value::Tuple(decode_tuple(kinds.iter(), data)?),
+    })
+    .and_then(|value| {
+        ensure!(value.matches(kind), "value {value} out of range");
+        Ok(value)
+    })
+}
+
+fn word(value: U256) -> [u8; 32] {
+    value.to_be_bytes()
+}
+
+// Appends bytes right padded to a multiple of 32 bytes.
+fn padded(bytes: &[u8], buffer: &mut Vec<u8>) {
+    buffer.extend_from_slice(bytes);
+    buffer.resize(buffer.len() + (32 - bytes.len() % 32) % 32, 0);
+}
+
+fn read_word(data: &[u8], offset: usize) -> Result<[u8; 32]> {
+    Ok(data
+        .get(offset..offset + 32)
+        .context("ABI data too short")?
+        .try_into()
+        .unwrap())
+}
+
+// Reads a length or offset, which must fit into a `usize`.
+fn read_len(data: &[u8], offset: usize) -> Result<usize> {
+    let value = U256::from_be_bytes(read_word(data, offset)?);
+    ensure!(
+        value <= U256::from(u32::MAX),
+        "ABI length or offset {value} too large"
+    );
+    Ok(value.as_usize())
+}
+
+fn read_bytes(data: &[u8]) -> Result<&[u8]> {
+    let len = read_len(data, 0)?;
+    data.get(32..32 + len).context("ABI bytes exceed data")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address;
+
+    fn kinds(kinds: &[&str]) -> Vec<ParamType> {
+        kinds.iter().map(|kind| kind.parse().unwrap()).collect()
+    }
+
+    fn words(hex: &str) -> Vec<u8> {
+        hex::decode(hex.split_whitespace().collect::<String>()).unwrap()
+    }
+
+    #[test]
+    fn static_values() {
+        // Solidity documentation example `baz(uint32,bool)`.
+        let kinds = kinds(&["uint32", "bool"]);
+        let values = [Value::Uint(U256::new(69)), Value::Bool(true)];
+        let encoded = words(
+            "0000000000000000000000000000000000000000000000000000000000000045
+             0000000000000000000000000000000000000000000000000000000000000001",
+        );
+        assert_eq!(encode(&kinds, &values).unwrap(), encoded);
+        assert_eq!(decode(&kinds, &encoded).unwrap(), values);
+
+        let kinds = self::kinds(&["int8", "address", "bytes3"]);
+        let values = [
+            Value::Int(I256::new(-1)),
+            Value::Address(address!("0x90F8bf6A479f320ead074411a4B0e7944Ea8c9C1")),
+            Value::FixedBytes(b"abc".to_vec()),
+        ];
+        let encoded = encode(&kinds, &values).unwrap();
+        assert_eq!(encoded[..32], [0xff; 32]);
+        assert_eq!(decode(&kinds, &encoded).unwrap(), values);
+
+        assert!(encode(&kinds[..1], &[Value::Int(I256::new(128))]).is_err());
+        assert!(encode(&kinds[..1], &[Value::Bool(true)]).is_err());
+    }
+
+    #[test]
+    fn dynamic_values() {
+        // Solidity documentation example `f(uint256,uint32[],bytes10,bytes)`.
+        let kinds = kinds(&["uint256", "uint32[]", "bytes10", "bytes"]);
+        let values = [
+            Value::Uint(U256::new(0x123)),
+            Value::Array(vec![
+                Value::Uint(U256::new(0x456)),
+                Value::Uint(U256::new(0x789)),
+            ]),
+            Value::FixedBytes(b"1234567890".to_vec()),
+            Value::Bytes(b"Hello, world!".to_vec()),
+        ];
+        let encoded = words(
+            "0000000000000000000000000000000000000000000000000000000000000123
+             0000000000000000000000000000000000000000000000000000000000000080
+             3132333435363738393000000000000000000000000000000000000000000000
+             00000000000000000000000000000000000000000000000000000000000000e0
+             0000000000000000000000000000000000000000000000000000000000000002
+             0000000000000000000000000000000000000000000000000000000000000456
+             0000000000000000000000000000000000000000000000000000000000000789
+             000000000000000000000000000000000000000000000000000000000000000d
+             48656c6c6f2c20776f726c642100000000000000000000000000000000000000",
+        );
+        assert_eq!(encode(&kinds, &values).unwrap(), encoded);
+        assert_eq!(decode(&kinds, &encoded).unwrap(), values);
+    }
+
+    #[test]
+    fn nested_values() {
+        // Solidity documentation example `g(uint256[][],string[])`.
+        let kinds = kinds(&["uint256[][]", "string[]"]);
+        let uints = |values: &[u64]| {
+            Value::Array(values.iter().map(|v| Value::Uint(U256::from(*v))).collect())
+        };
+        let values = [
+            Value::Array(vec![uints(&[1, 2]), uints(&[3])]),
+            Value::Array(vec!["one".into(), "two".into(), "three".into()]),
+        ];
+        let encoded = words(
+            "0000000000000000000000000000000000000000000000000000000000000040
+             0000000000000000000000000000000000000000000000000000000000000140
+             0000000000000000000000000000000000000000000000000000000000000002
+             0000000000000000000000000000000000000000000000000000000000000040
+             00000000000000000000000000000000000000000000000000000000000000a0
+             0000000000000000000000000000000000000000000000000000000000000002
+             0000000000000000000000000000000000000000000000000000000000000001
+             0000000000000000000000000000000000000000000000000000000000000002
+             0000000000000000000000000000000000000000000000000000000000000001
+             0000000000000000000000000000000000000000000000000000000000000003
+             0000000000000000000000000000000000000000000000000000000000000003
+             0000000000000000000000000000000000000000000000000000000000000060
+             00000000000000000000000000000000000000000000000000000000000000a0
+             00000000000000000000000000000000000000000000000000000000000000e0
+             0000000000000000000000000000000000000000000000000000000000000003
+             6f6e650000000000000000000000000000000000000000000000000000000000
+             0000000000000000000000000000000000000000000000000000000000000003
+             74776f0000000000000000000000000000000000000000000000000000000000
+             0000000000000000000000000000000000000000000000000000000000000005
+             7468726565000000000000000000000000000000000000000000000000000000",
+        );
+        assert_eq!(encode(&kinds, &values).unwrap(), encoded);
+        assert_eq!(decode(&kinds, &encoded).unwrap(), values);
+
+        let kinds = self::kinds(&["(uint8,string)[2]", "bool"]);
+        let values = [
+            Value::Array(vec![
+                Value::Tuple(vec![Value::Uint(U256::new(1)), "a".into()]),
+                Value::Tuple(vec![Value::Uint(U256::new(2)), "b".into()]),
+            ]),
+            Value::Bool(false),
+        ];
+        let encoded = encode(&kinds, &values).unwrap();
+        assert_eq!(decode(&kinds, &encoded).unwrap(), values);
+    }
+
+    #[test]
+    fn invalid_data() {
+        let kind = kinds(&["uint8"]);
+        assert!(decode(&kind, &word(U256::new(256))).is_err());
+        assert!(decode(&kind, &[0; 31]).is_err());
+        assert!(decode(&kinds(&["bool"]), &word(U256::new(2))).is_err());
+        assert!(decode(&kinds(&["address"]), &[0xff; 32]).is_err());
+
+        let mut huge_array = word(U256::new(32)).to_vec();
+        huge_array.extend_from_slice(&word(U256::new(u32::MAX.into())));
+        assert!(decode(&kinds(&["uint256[]"]), &huge_array).is_err());
+        assert!(decode(&kinds(&["bytes"]), &word(U256::new(64))).is_err());
+    }
+}
@@ -0,0 +1,64 @@
+//! Module implementing custom contract errors.
+
+use super::{
+    encoding,
+    function::{kinds, params, parse_signature, selector, Param},
+    types::ParamType,
+    value::Value,
+};
+use anyhow::{ensure, Context as _, Result};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// A custom contract error, raised with `revert`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomError {
+    pub name: String,
+    pub inputs: Vec<Param>,
+}
+
+impl CustomError {
+    /// Returns the canonical signature, for example
+    /// `InsufficientBalance(uint256,uint256)`.
+    pub fn signature(&self) -> String {
+        format!("{}{}", self.name, ParamType::Tuple(kinds(&self.inputs)))
+    }
+
+    /// Returns the 4-byte selector that prefixes the revert data.
+    pub fn selector(&self) -> [u8; 4] {
+        selector(&self.signature())
+    }
+
+    /// Decodes the arguments of revert data raised with this error.
+    pub fn decode(&self, data: &[u8]) -> Result<Vec<Value>> {
+        let args = data
+            .strip_prefix(&self.selector())
+            .with_context(|| format!("revert data is not a {} error", self.signature()))?;
+        encoding::decode(&kinds(&self.inputs), args)
+    }
+}
+
+impl Display for CustomError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "error {}({})", self.name, params(&self.inputs))
+    }
+}
+
+impl FromStr for CustomError {
+    type Err = anyhow::Error;
+
+    /// Parses a human-readable error signature such as
+    /// `error InsufficientBalance(uint256 available, uint256 required)`.
+    /// The `error` keyword is optional.
+    fn from_str(s: &str) -> Result<Self> {
+        let (name, inputs, rest) = parse_signature(s, "error")?;
+        ensure!(
+            rest.trim().is_empty(),
+            "unexpected '{}' after error parameters",
+            rest.trim()
+        );
+        Ok(Self { name, inputs })
+    }
+}
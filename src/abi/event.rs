@@ -0,0 +1,257 @@
+//! Module implementing contract events and the decoding of event logs.
+//!
+//! Indexed parameters are stored in the log topics, after the event topic
+//! for non-anonymous events, and the remaining parameters are ABI encoded in
+//! the log data. Indexed parameters of reference types (strings, bytes,
+//! arrays and tuples) are stored as the Keccak-256 hash of their encoding,
+//! so only the hash can be recovered from a log.
+
+use super::{
+    encoding,
+    function::{kinds, params, parse_signature, Param},
+    types::ParamType,
+    value::Value,
+};
+use crate::utils::hash::keccak256;
+use anyhow::{ensure, Context as _, Result};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// A contract event.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Event {
+    pub name: String,
+    pub inputs: Vec<Param>,
+    /// Whether the event is anonymous, in which case its topic is not
+    /// included in the logs.
+    pub anonymous: bool,
+}
+
+impl Event {
+    /// Returns the canonical signature, for example
+    /// `Transfer(address,address,uint256)`.
+    pub fn signature(&self) -> String {
+        format!("{}{}", self.name, ParamType::Tuple(kinds(&self.inputs)))
+    }
+
+    /// Returns the event topic, the Keccak-256 hash of the canonical
+    /// signature.
+    pub fn topic(&self) -> [u8; 32] {
+        keccak256(self.signature())
+    }
+
+    /// Decodes the parameters of a log emitted by this event from its topics
+    /// and data.
+    pub fn decode_log(&self, topics: &[[u8; 32]], data: &[u8]) -> Result<Vec<LogParam>> {
+        let topics = if self.anonymous {
+            topics
+        } else {
+            match topics.split_first() {
+                Some((topic, topics)) if *topic == self.topic() => topics,
+                _ => anyhow::bail!("log is not a {} event", self.signature()),
+            }
+        };
+
+        let (indexed, unindexed) = self
+            .inputs
+            .iter()
+            .partition::<Vec<_>, _>(|param| param.indexed);
+        ensure!(
+            topics.len() == indexed.len(),
+            "expected {} indexed topics for {} but got {}",
+            indexed.len(),
+            self.signature(),
+            topics.len(),
+        );
+        let mut topics = topics.iter();
+        let mut values = encoding::decode(
+            &unindexed
+                .iter()
+                .map(|param| param.kind.clone())
+                .collect::<Vec<_>>(),
+            data,
+        )
+        .with_context(|| format!("invalid {} log data", self.signature()))?
+        .into_iter();
+
+        self.inputs
+            .iter()
+            .map(|param| {
+                let value = if !param.indexed {
+                    LogValue::Value(values.next().unwrap())
+                } else {
+                    let topic = topics.next().unwrap();
+                    if is_value_type(&param.kind) {
+                        LogValue::Value(
+                            encoding::decode(std::slice::from_ref(&param.kind), topic)
+                                .with_context(|| format!("invalid topic for '{}'", param.name))?
+                                .remove(0),
+                        )
+                    } else {
+                        LogValue::Hash(*topic)
+                    }
+                };
+                Ok(LogParam {
+                    name: param.name.clone(),
+                    value,
+                })
+            })
+            .collect()
+    }
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "event {}({})", self.name, params(&self.inputs))?;
+        if self.anonymous {
+            f.write_str(" anonymous")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Event {
+    type Err = anyhow::Error;
+
+    /// Parses a human-readable event signature such as
+    /// `event Transfer(address indexed from, address indexed to, uint256 value)`.
+    /// The `event` keyword is optional.
+    fn from_str(s: &str) -> Result<Self> {
+        let (name, inputs, rest) = parse_signature(s, "event")?;
+        let anonymous = match rest.trim() {
+            "" => false,
+            "anonymous" => true,
+            rest => anyhow::bail!("unexpected '{rest}' after event parameters"),
+        };
+        ensure!(
+            inputs.iter().filter(|param| param.indexed).count() <= if anonymous { 4 } else { 3 },
+            "event {name} has too many indexed parameters"
+        );
+        Ok(Self {
+            name,
+            inputs,
+            anonymous,
+        })
+    }
+}
+
+/// A decoded event log parameter.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LogParam {
+    pub name: String,
+    pub value: LogValue,
+}
+
+/// The value of a decoded event log parameter.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LogValue {
+    Value(Value),
+    /// The Keccak-256 hash of an indexed reference type value.
+    Hash([u8; 32]),
+}
+
+impl LogValue {
+    /// Returns the value, or `None` if only its hash was logged.
+    pub fn value(&self) -> Option<&Value> {
+        match self {
+            Self::Value(value) => Some(value),
+            Self::Hash(_) => None,
+        }
+    }
+}
+
+impl Display for LogValue {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Value(value) => write!(f, "{value}"),
+            Self::Hash(hash) => write!(f, "keccak256:0x{}", hex::encode(hash)),
+        }
+    }
+}
+
+// Returns whether an indexed parameter of the type is stored in its topic
+// as is, rather than hashed.
+fn is_value_type(kind: &ParamType) -> bool {
+    matches!(
+        kind,
+        ParamType::Address
+            | ParamType::Bool
+            | ParamType::Int(_)
+            | ParamType::Uint(_)
+            | ParamType::FixedBytes(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address;
+    use ethnum::U256;
+    use hex_literal::hex;
+
+    #[test]
+    fn parse_events() {
+        let event = "event Transfer(address indexed from, address indexed to, uint256 value)"
+            .parse::<Event>()
+            .unwrap();
+        assert_eq!(event.signature(), "Transfer(address,address,uint256)");
+        assert_eq!(
+            event.topic(),
+            hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"),
+        );
+        assert!(event.inputs[0].indexed && !event.inputs[2].indexed);
+        assert_eq!(
+            event.to_string(),
+            "event Transfer(address indexed from, address indexed to, uint256 value)"
+        );
+
+        assert!("Ping(uint a) anonymous".parse::<Event>().unwrap().anonymous);
+        for signature in [
+            "Ping(uint a) view",
+            "E(uint indexed, uint indexed, uint indexed, uint indexed)",
+        ] {
+            assert!(signature.parse::<Event>().is_err(), "{signature}");
+        }
+    }
+
+    #[test]
+    fn decode_logs() {
+        let event = "Transfer(address indexed from, address indexed to, uint256 value)"
+            .parse::<Event>()
+            .unwrap();
+        let topics = [
+            event.topic(),
+            hex!("00000000000000000000000090f8bf6a479f320ead074411a4b0e7944ea8c9c1"),
+            hex!("000000000000000000000000ffcf8fdee72ac11b5c542428b35eef5769c409f0"),
+        ];
+        let data = U256::new(1_000_000).to_be_bytes();
+        let log = event.decode_log(&topics, &data).unwrap();
+        assert_eq!(log[0].name, "from");
+        assert_eq!(
+            log[1].value,
+            LogValue::Value(Value::Address(address!(
+                "0xFFcf8FDEE72ac11b5c542428B35EEF5769C409f0"
+            ))),
+        );
+        assert_eq!(
+            log[2].value.value(),
+            Some(&Value::Uint(U256::new(1_000_000)))
+        );
+
+        assert!(event.decode_log(&topics[..2], &data).is_err());
+        assert!(event.decode_log(&topics[1..], &data).is_err());
+        assert!(event.decode_log(&topics, &[]).is_err());
+
+        // Indexed strings are only logged as their hash.
+        let event = "Named(string indexed name, bytes data) anonymous"
+            .parse::<Event>()
+            .unwrap();
+        let topics = [keccak256("alice")];
+        let data = encoding::encode(&[ParamType::Bytes], &[Value::Bytes(vec![1, 2])]).unwrap();
+        let log = event.decode_log(&topics, &data).unwrap();
+        assert_eq!(log[0].value, LogValue::Hash(keccak256("alice")));
+        assert_eq!(log[1].value, LogValue::Value(Value::Bytes(vec![1, 2])));
+    }
+}
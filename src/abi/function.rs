@@ -0,0 +1,272 @@
+//! Module implementing human-readable contract function signatures.
+
+use super::{
+    encoding,
+    types::{parse_param, split_params, ParamType},
+    value::Value,
+};
+use crate::utils::hash::keccak256;
+use anyhow::{ensure, Context as _, Result};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// A named function or event parameter.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Param {
+    /// The parameter name, empty for unnamed parameters.
+    pub name: String,
+    pub kind: ParamType,
+    /// Whether an event parameter is indexed, always `false` for functions.
+    pub indexed: bool,
+}
+
+impl FromStr for Param {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (kind, name, indexed) = parse_param(s)?;
+        Ok(Self {
+            name,
+            kind,
+            indexed,
+        })
+    }
+}
+
+/// A contract function.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Function {
+    pub name: String,
+    pub inputs: Vec<Param>,
+    pub outputs: Vec<Param>,
+}
+
+impl Function {
+    /// Returns the canonical signature, for example
+    /// `transfer(address,uint256)`.
+    pub fn signature(&self) -> String {
+        format!("{}{}", self.name, ParamType::Tuple(kinds(&self.inputs)))
+    }
+
+    /// Returns the 4-byte selector, the first bytes of the Keccak-256 hash of
+    /// the canonical signature.
+    pub fn selector(&self) -> [u8; 4] {
+        selector(&self.signature())
+    }
+
+    /// Encodes calldata for a call with the specified arguments.
+    pub fn encode_input(&self, args: &[Value]) -> Result<Vec<u8>> {
+        let mut data = self.selector().to_vec();
+        data.extend(encoding::encode(&kinds(&self.inputs), args)?);
+        Ok(data)
+    }
+
+    /// Decodes the arguments of calldata for this function.
+    pub fn decode_input(&self, data: &[u8]) -> Result<Vec<Value>> {
+        let args = data
+            .strip_prefix(&self.selector())
+            .with_context(|| format!("calldata is not a call to {}", self.signature()))?;
+        encoding::decode(&kinds(&self.inputs), args)
+    }
+
+    /// Decodes the return data of a successful call.
+    pub fn decode_output(&self, data: &[u8]) -> Result<Vec<Value>> {
+        encoding::decode(&kinds(&self.outputs), data)
+    }
+}
+
+impl Display for Function {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "function {}({})", self.name, params(&self.inputs))?;
+        if !self.outputs.is_empty() {
+            write!(f, " returns ({})", params(&self.outputs))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Function {
+    type Err = anyhow::Error;
+
+    /// Parses a human-readable function signature such as
+    /// `function balanceOf(address owner) view returns (uint256)`. The
+    /// `function` keyword, visibility and mutability are optional.
+    fn from_str(s: &str) -> Result<Self> {
+        let (name, inputs, rest) = parse_signature(s, "function")?;
+        let outputs = match rest.find("returns") {
+            Some(i) => {
+                let rest = rest[i + "returns".len()..].trim_start();
+                let rest = rest
+                    .strip_prefix('(')
+                    .context("function outputs missing parentheses")?;
+                let (outputs, rest) = split_group(rest)?;
+                ensure!(
+                    rest.trim().is_empty(),
+                    "unexpected '{}' after function outputs",
+                    rest.trim()
+                );
+                parse_params(outputs)?
+            }
+            None => Vec::new(),
+        };
+
+        Ok(Self {
+            name,
+            inputs,
+            outputs,
+        })
+    }
+}
+
+/// Returns the 4-byte selector of a canonical function signature.
+pub fn selector(signature: &str) -> [u8; 4] {
+    keccak256(signature)[..4].try_into().unwrap()
+}
+
+/// Parses the name and parameters of a human-readable signature with an
+/// optional leading keyword, returning the rest of the string after the
+/// parameter list.
+pub(super) fn parse_signature<'a>(
+    s: &'a str,
+    keyword: &str,
+) -> Result<(String, Vec<Param>, &'a str)> {
+    let s = s.trim();
+    let s = match s.strip_prefix(keyword) {
+        Some(rest) if rest.starts_with(' ') => rest.trim_start(),
+        _ => s,
+    };
+    let (name, rest) = s
+        .split_once('(')
+        .with_context(|| format!("{keyword} signature '{s}' missing parameters"))?;
+    let name = name.trim();
+    ensure!(is_identifier(name), "invalid {keyword} name '{name}'");
+
+    let (params, rest) = split_group(rest)?;
+    Ok((name.to_owned(), parse_params(params)?, rest))
+}
+
+fn parse_params(params: &str) -> Result<Vec<Param>> {
+    split_params(params)?
+        .into_iter()
+        .map(Param::from_str)
+        .collect()
+}
+
+pub(super) fn kinds(params: &[Param]) -> Vec<ParamType> {
+    params.iter().map(|param| param.kind.clone()).collect()
+}
+
+// Formats parameters with their names.
+pub(super) fn params(params: &[Param]) -> String {
+    params
+        .iter()
+        .map(|param| {
+            let mut param_str = param.kind.to_string();
+            if param.indexed {
+                param_str.push_str(" indexed");
+            }
+            if !param.name.is_empty() {
+                param_str.push(' ');
+                param_str.push_str(&param.name);
+            }
+            param_str
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// Splits the contents of a parenthesized group, which starts after the
+// opening parenthesis, from the rest of the string.
+fn split_group(s: &str) -> Result<(&str, &str)> {
+    let mut depth = 0usize;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Ok((&s[..i], &s[i + 1..])),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    anyhow::bail!("unclosed parameter list")
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address;
+    use ethnum::U256;
+
+    #[test]
+    fn parse_signatures() {
+        let function = "function transfer(address to, uint256 amount) external returns (bool)"
+            .parse::<Function>()
+            .unwrap();
+        assert_eq!(function.name, "transfer");
+        assert_eq!(function.inputs[1].name, "amount");
+        assert_eq!(function.signature(), "transfer(address,uint256)");
+        assert_eq!(hex::encode(function.selector()), "a9059cbb");
+        assert_eq!(
+            function.to_string(),
+            "function transfer(address to, uint256 amount) returns (bool)"
+        );
+
+        let function = "swap((address,uint)[] calldata orders, bytes memory)"
+            .parse::<Function>()
+            .unwrap();
+        assert_eq!(function.signature(), "swap((address,uint256)[],bytes)");
+        assert!(function.outputs.is_empty());
+
+        assert_eq!(
+            hex::encode("balanceOf(address)".parse::<Function>().unwrap().selector()),
+            "70a08231"
+        );
+
+        for signature in [
+            "transfer",
+            "1up()",
+            "f(uint",
+            "f() returns bool",
+            "f(uint a b)",
+        ] {
+            assert!(signature.parse::<Function>().is_err(), "{signature}");
+        }
+    }
+
+    #[test]
+    fn calldata() {
+        let function = "transfer(address to, uint256 amount) returns (bool)"
+            .parse::<Function>()
+            .unwrap();
+        let args = [
+            Value::Address(address!("0x90F8bf6A479f320ead074411a4B0e7944Ea8c9C1")),
+            Value::Uint(U256::new(1_000_000)),
+        ];
+        let data = function.encode_input(&args).unwrap();
+        assert_eq!(
+            hex::encode(&data),
+            "a9059cbb\
+             00000000000000000000000090f8bf6a479f320ead074411a4b0e7944ea8c9c1\
+             00000000000000000000000000000000000000000000000000000000000f4240",
+        );
+        assert_eq!(function.decode_input(&data).unwrap(), args);
+        assert!(function.decode_input(&data[4..]).is_err());
+        assert!(function.encode_input(&args[..1]).is_err());
+
+        let mut output = [0; 32];
+        output[31] = 1;
+        assert_eq!(
+            function.decode_output(&output).unwrap(),
+            [Value::Bool(true)]
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! Module for decoding the reason of a reverted call.
+
+use super::{encoding, function::selector, types::ParamType, value::Value};
+use anyhow::Result;
+use ethnum::U256;
+use std::fmt::{self, Display, Formatter};
+
+/// The decoded return data of a reverted call.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Revert {
+    /// A `require` or `revert` with a reason string, `Error(string)`.
+    Error(String),
+    /// A failed assertion or runtime error, `Panic(uint256)`.
+    Panic(U256),
+    /// A custom error with its selector and ABI encoded arguments.
+    Custom([u8; 4], Vec<u8>),
+    /// A revert without return data.
+    Empty,
+}
+
+impl Revert {
+    /// Decodes the return data of a reverted call. Data too short for a
+    /// selector, or with a malformed `Error` or `Panic` payload, is rejected.
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        if data.is_empty() {
+            return Ok(Self::Empty);
+        }
+        let (selector, args) = data
+            .split_first_chunk::<4>()
+            .ok_or_else(|| anyhow::anyhow!("revert data shorter than a selector"))?;
+
+        Ok(if *selector == self::selector("Error(string)") {
+            match &encoding::decode(&[ParamType::String], args)?[..] {
+                [Value::String(reason)] => Self::Error(reason.clone()),
+                _ => unreachable!(),
+            }
+        } else if *selector == self::selector("Panic(uint256)") {
+            match &encoding::decode(&[ParamType::Uint(256)], args)?[..] {
+                [Value::Uint(code)] => Self::Panic(*code),
+                _ => unreachable!(),
+            }
+        } else {
+            Self::Custom(*selector, args.to_vec())
+        })
+    }
+
+    /// Returns the description of a Solidity panic code.
+    pub fn panic_description(code: U256) -> Option<&'static str> {
+        Some(match code.as_u64() {
+            _ if code > U256::from(u64::MAX) => return None,
+            0x00 => "generic compiler inserted panic",
+            0x01 => "assertion failed",
+            0x11 => "arithmetic overflow or underflow",
+            0x12 => "division or modulo by zero",
+            0x21 => "invalid enum value",
+            0x22 => "invalid storage byte array encoding",
+            0x31 => "pop on empty array",
+            0x32 => "array index out of bounds",
+            0x41 => "out of memory",
+            0x51 => "call to uninitialized internal function",
+            _ => return None,
+        })
+    }
+}
+
+impl Display for Revert {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Error(reason) => write!(f, "execution reverted: {reason}"),
+            Self::Panic(code) => match Self::panic_description(*code) {
+                Some(description) => write!(f, "panic 0x{code:02x}: {description}"),
+                None => write!(f, "panic 0x{code:02x}"),
+            },
+            Self::Custom(selector, _) => {
+                write!(
+                    f,
+                    "execution reverted with custom error 0x{}",
+                    hex::encode(selector)
+                )
+            }
+            Self::Empty => f.write_str("execution reverted"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_reverts() {
+        let data = hex::decode(
+            "08c379a0\
+             0000000000000000000000000000000000000000000000000000000000000020\
+             000000000000000000000000000000000000000000000000000000000000001a\
+             4e6f7420656e6f7567682045746865722070726f76696465642e000000000000",
+        )
+        .unwrap();
+        let revert = Revert::decode(&data).unwrap();
+        assert_eq!(
+            revert,
+            Revert::Error("Not enough Ether provided.".to_owned())
+        );
+        assert_eq!(
+            revert.to_string(),
+            "execution reverted: Not enough Ether provided."
+        );
+
+        let data = hex::decode(
+            "4e487b71\
+             0000000000000000000000000000000000000000000000000000000000000011",
+        )
+        .unwrap();
+        let revert = Revert::decode(&data).unwrap();
+        assert_eq!(revert, Revert::Panic(U256::new(0x11)));
+        assert_eq!(
+            revert.to_string(),
+            "panic 0x11: arithmetic overflow or underflow"
+        );
+
+        assert_eq!(
+            Revert::decode(&hex::decode("deadbeef01").unwrap()).unwrap(),
+            Revert::Custom([0xde, 0xad, 0xbe, 0xef], vec![1]),
+        );
+        assert_eq!(Revert::decode(&[]).unwrap(), Revert::Empty);
+        assert!(Revert::decode(&[0x08, 0xc3]).is_err());
+        assert!(Revert::decode(&data[..20]).is_err());
+    }
+}
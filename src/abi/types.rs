@@ -0,0 +1,261 @@
+//! Module implementing Solidity ABI parameter types.
+
+use anyhow::{bail, ensure, Context as _, Result};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// A Solidity ABI type.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ParamType {
+    Address,
+    Bool,
+    /// A signed integer with the specified bit width.
+    Int(usize),
+    /// An unsigned integer with the specified bit width.
+    Uint(usize),
+    /// A fixed size byte array of 1 to 32 bytes.
+    FixedBytes(usize),
+    Bytes,
+    String,
+    /// A dynamically sized array.
+    Array(Box<ParamType>),
+    /// A fixed size array.
+    FixedArray(Box<ParamType>, usize),
+    Tuple(Vec<ParamType>),
+}
+
+impl ParamType {
+    /// Returns whether values of the type are encoded in the tail of the
+    /// enclosing tuple, with an offset in the head.
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            Self::Bytes | Self::String | Self::Array(_) => true,
+            Self::FixedArray(kind, _) => kind.is_dynamic(),
+            Self::Tuple(kinds) => kinds.iter().any(Self::is_dynamic),
+            _ => false,
+        }
+    }
+
+    /// Returns the size of the type in the head of the enclosing tuple.
+    pub fn head_size(&self) -> usize {
+        match self {
+            _ if self.is_dynamic() => 32,
+            Self::FixedArray(kind, len) => kind.head_size() * len,
+            Self::Tuple(kinds) => kinds.iter().map(Self::head_size).sum(),
+            _ => 32,
+        }
+    }
+}
+
+impl Display for ParamType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Address => f.write_str("address"),
+            Self::Bool => f.write_str("bool"),
+            Self::Int(bits) => write!(f, "int{bits}"),
+            Self::Uint(bits) => write!(f, "uint{bits}"),
+            Self::FixedBytes(len) => write!(f, "bytes{len}"),
+            Self::Bytes => f.write_str("bytes"),
+            Self::String => f.write_str("string"),
+            Self::Array(kind) => write!(f, "{kind}[]"),
+            Self::FixedArray(kind, len) => write!(f, "{kind}[{len}]"),
+            Self::Tuple(kinds) => {
+                f.write_str("(")?;
+                for (i, kind) in kinds.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{kind}")?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+impl FromStr for ParamType {
+    type Err = anyhow::Error;
+
+    /// Parses a type such as `uint256`, `bytes32[2]` or `(address,uint)[]`.
+    /// `uint` and `int` are aliases for their 256-bit variants, and tuples
+    /// may be written with a `tuple` prefix and named components.
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+
+        // Array suffixes bind to everything before them.
+        if let Some(rest) = s.strip_suffix(']') {
+            let (kind, len) = rest
+                .rsplit_once('[')
+                .with_context(|| format!("invalid ABI type '{s}'"))?;
+            let kind = Box::new(kind.parse()?);
+            return Ok(match len {
+                "" => Self::Array(kind),
+                len => {
+                    let len = len
+                        .parse()
+                        .with_context(|| format!("invalid ABI array length in '{s}'"))?;
+                    ensure!(len > 0, "ABI fixed array '{s}' has zero length");
+                    Self::FixedArray(kind, len)
+                }
+            });
+        }
+
+        if let Some(components) = s.strip_prefix("tuple").unwrap_or(s).strip_prefix('(') {
+            let components = components
+                .strip_suffix(')')
+                .with_context(|| format!("unclosed ABI tuple '{s}'"))?;
+            return Ok(Self::Tuple(
+                split_params(components)?
+                    .into_iter()
+                    .map(|param| Ok(parse_param(param)?.0))
+                    .collect::<Result<_>>()?,
+            ));
+        }
+
+        Ok(match s {
+            "address" => Self::Address,
+            "bool" => Self::Bool,
+            "bytes" => Self::Bytes,
+            "string" => Self::String,
+            "int" => Self::Int(256),
+            "uint" => Self::Uint(256),
+            _ => {
+                if let Some(bits) = s.strip_prefix("uint") {
+                    Self::Uint(integer_bits(s, bits)?)
+                } else if let Some(bits) = s.strip_prefix("int") {
+                    Self::Int(integer_bits(s, bits)?)
+                } else if let Some(len) = s.strip_prefix("bytes") {
+                    let len = decimal(len).with_context(|| format!("invalid ABI type '{s}'"))?;
+                    ensure!((1..=32).contains(&len), "invalid ABI type '{s}'");
+                    Self::FixedBytes(len)
+                } else {
+                    bail!("invalid ABI type '{s}'")
+                }
+            }
+        })
+    }
+}
+
+/// Splits a comma separated parameter list at the top level, ignoring commas
+/// inside nested tuples.
+pub(super) fn split_params(params: &str) -> Result<Vec<&str>> {
+    if params.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in params.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.checked_sub(1).context("unbalanced parentheses")?,
+            ',' if depth == 0 => {
+                parts.push(params[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    ensure!(depth == 0, "unbalanced parentheses");
+    parts.push(params[start..].trim());
+    ensure!(
+        parts.iter().all(|part| !part.is_empty()),
+        "empty ABI parameter in '{params}'"
+    );
+    Ok(parts)
+}
+
+/// Parses a parameter such as `address indexed to` into its type, name and
+/// whether it is indexed. Data location keywords are ignored.
+pub(super) fn parse_param(param: &str) -> Result<(ParamType, String, bool)> {
+    // The type ends after the last closing parenthesis of a tuple, or at the
+    // first space otherwise.
+    let split = match param.rfind(')') {
+        Some(end) => param[end..]
+            .find(' ')
+            .map(|i| end + i)
+            .unwrap_or(param.len()),
+        None => param.find(' ').unwrap_or(param.len()),
+    };
+    let (kind, rest) = param.split_at(split);
+
+    let mut name = String::new();
+    let mut indexed = false;
+    for word in rest.split_whitespace() {
+        match word {
+            "indexed" => indexed = true,
+            "memory" | "calldata" | "storage" | "payable" => {}
+            _ => {
+                ensure!(name.is_empty(), "invalid ABI parameter '{param}'");
+                name = word.to_owned();
+            }
+        }
+    }
+    Ok((kind.parse()?, name, indexed))
+}
+
+fn integer_bits(s: &str, bits: &str) -> Result<usize> {
+    let bits = decimal(bits).with_context(|| format!("invalid ABI type '{s}'"))?;
+    ensure!(
+        matches!(bits, 8..=256) && bits & 7 == 0,
+        "invalid ABI integer width in '{s}'"
+    );
+    Ok(bits)
+}
+
+// Parses a decimal number without sign or leading zeros.
+fn decimal(s: &str) -> Option<usize> {
+    if s.starts_with('0') || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_types() {
+        for (kind, canonical) in [
+            ("uint", "uint256"),
+            ("int8", "int8"),
+            ("bytes32", "bytes32"),
+            ("address[]", "address[]"),
+            ("uint256[2][]", "uint256[2][]"),
+            ("(address,uint)[3]", "(address,uint256)[3]"),
+            ("tuple(address to, bytes data)[]", "(address,bytes)[]"),
+            ("((bool,string),int16)", "((bool,string),int16)"),
+            ("()", "()"),
+        ] {
+            assert_eq!(kind.parse::<ParamType>().unwrap().to_string(), canonical);
+        }
+        for kind in [
+            "uint7", "uint264", "int0", "bytes0", "bytes33", "bytes01", "uint[0]", "uint[",
+            "(uint", "foo", "(uint,)",
+        ] {
+            assert!(kind.parse::<ParamType>().is_err(), "{kind}");
+        }
+
+        assert_eq!(
+            "uint256[2][]".parse::<ParamType>().unwrap(),
+            ParamType::Array(Box::new(ParamType::FixedArray(
+                Box::new(ParamType::Uint(256)),
+                2
+            ))),
+        );
+    }
+
+    #[test]
+    fn dynamic_and_sizes() {
+        let kind = |s: &str| s.parse::<ParamType>().unwrap();
+        assert!(!kind("(uint256,bytes32)[2]").is_dynamic());
+        assert_eq!(kind("(uint256,bytes32)[2]").head_size(), 128);
+        assert!(kind("(uint256,bytes)[2]").is_dynamic());
+        assert_eq!(kind("(uint256,bytes)[2]").head_size(), 32);
+        assert!(kind("uint8[]").is_dynamic());
+    }
+}
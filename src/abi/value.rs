@@ -0,0 +1,176 @@
+//! Module containing dynamically typed Solidity ABI values.
+
+use super::types::ParamType;
+use crate::address::Address;
+use ethnum::{I256, U256};
+use std::fmt::{self, Display, Formatter};
+
+/// A Solidity ABI value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Value {
+    Address(Address),
+    Bool(bool),
+    Int(I256),
+    Uint(U256),
+    /// A fixed size byte array of 1 to 32 bytes.
+    FixedBytes(Vec<u8>),
+    Bytes(Vec<u8>),
+    String(String),
+    /// The values of a dynamically or fixed sized array.
+    Array(Vec<Value>),
+    Tuple(Vec<Value>),
+}
+
+impl Value {
+    /// Returns whether the value can be encoded as the specified type,
+    /// including whether integers fit into the type's bit width.
+    pub fn matches(&self, kind: &ParamType) -> bool {
+        match (self, kind) {
+            (Self::Address(_), ParamType::Address) => true,
+            (Self::Bool(_), ParamType::Bool) => true,
+            (Self::Int(value), ParamType::Int(bits)) => {
+                let (min, max) = int_range(*bits);
+                (min..=max).contains(value)
+            }
+            (Self::Uint(value), ParamType::Uint(bits)) => {
+                *bits == 256 || *value >> *bits as u32 == U256::ZERO
+            }
+            (Self::FixedBytes(bytes), ParamType::FixedBytes(len)) => bytes.len() == *len,
+            (Self::Bytes(_), ParamType::Bytes) => true,
+            (Self::String(_), ParamType::String) => true,
+            (Self::Array(values), ParamType::Array(kind)) => {
+                values.iter().all(|value| value.matches(kind))
+            }
+            (Self::Array(values), ParamType::FixedArray(kind, len)) => {
+                values.len() == *len && values.iter().all(|value| value.matches(kind))
+            }
+            (Self::Tuple(values), ParamType::Tuple(kinds)) => {
+                values.len() == kinds.len()
+                    && values
+                        .iter()
+                        .zip(kinds)
+                        .all(|(value, kind)| value.matches(kind))
+            }
+            _ => false,
+        }
+    }
+
+    pub fn as_address(&self) -> Option<Address> {
+        match self {
+            Self::Address(address) => Some(*address),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<I256> {
+        match self {
+            Self::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_uint(&self) -> Option<U256> {
+        match self {
+            Self::Uint(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the bytes of a `bytes` or `bytesN` value.
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::FixedBytes(bytes) | Self::Bytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of an array or tuple.
+    pub fn as_slice(&self) -> Option<&[Value]> {
+        match self {
+            Self::Array(values) | Self::Tuple(values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let list = |f: &mut Formatter, values: &[Value], open, close| {
+            f.write_str(open)?;
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{value}")?;
+            }
+            f.write_str(close)
+        };
+
+        match self {
+            Self::Address(address) => write!(f, "{address}"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Uint(value) => write!(f, "{value}"),
+            Self::FixedBytes(bytes) | Self::Bytes(bytes) => write!(f, "0x{}", hex::encode(bytes)),
+            Self::String(value) => write!(f, "{value:?}"),
+            Self::Array(values) => list(f, values, "[", "]"),
+            Self::Tuple(values) => list(f, values, "(", ")"),
+        }
+    }
+}
+
+impl From<Address> for Value {
+    fn from(value: Address) -> Self {
+        Self::Address(value)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<U256> for Value {
+    fn from(value: U256) -> Self {
+        Self::Uint(value)
+    }
+}
+
+impl From<I256> for Value {
+    fn from(value: I256) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+// Returns the range of a signed integer type.
+pub(super) fn int_range(bits: usize) -> (I256, I256) {
+    let max = I256::MAX >> (256 - bits) as u32;
+    (-max - 1, max)
+}
@@ -0,0 +1,540 @@
+//! Module implementing Ethereum addresses with EIP-55 mixed-case checksums,
+//! EIP-1191 chain specific checksums and ICAP conversion.
+//!
+//! Conversions to and from the address types of `ethaddr`, `ethereum-types`
+//! and `alloy` are provided for interoperability with other libraries.
+
+use crate::{transaction::rlp, utils::hash::keccak256};
+use anyhow::{bail, ensure, Context as _, Result};
+use ethnum::U256;
+use k256::{elliptic_curve::sec1::ToEncodedPoint as _, PublicKey};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    fmt::{self, Debug, Display, Formatter, LowerHex},
+    ops::{Deref, DerefMut},
+    str::FromStr,
+};
+
+/// An Ethereum address.
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Address(pub [u8; 20]);
+
+impl Address {
+    /// The zero address.
+    pub const ZERO: Self = Self([0; 20]);
+
+    /// Creates an address from a slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is not 20 bytes long.
+    pub fn from_slice(bytes: &[u8]) -> Self {
+        Self(bytes.try_into().expect("address must be 20 bytes"))
+    }
+
+    /// Returns the address of a public key, the last 20 bytes of the
+    /// Keccak-256 hash of its uncompressed coordinates.
+    pub fn from_public_key(public_key: &PublicKey) -> Self {
+        // Note that an encoded uncompressed public key is serialized into 65
+        // bytes where the first byte is a SEC1 tag that is always 0x04
+        // (representing an uncompressed point) and the subsequent bytes are
+        // the coordinates we want.
+        let encoded = public_key.to_encoded_point(false);
+        debug_assert_eq!(encoded.as_bytes()[0], 0x04);
+        let hash = keccak256(&encoded.as_bytes()[1..]);
+        Self::from_slice(&hash[12..])
+    }
+
+    /// Returns the address of a contract created by `sender` with `CREATE`,
+    /// or by a contract creation transaction from `sender`, the last 20 bytes
+    /// of the Keccak-256 hash of the RLP encoded sender and nonce.
+    pub fn create(sender: Address, nonce: U256) -> Self {
+        let encoded = rlp::list(&[&rlp::bytes(&sender.0), &rlp::uint(nonce)]);
+        Self::from_slice(&keccak256(encoded)[12..])
+    }
+
+    /// Returns the address of a contract created by `deployer` with
+    /// `CREATE2`, as specified by EIP-1014.
+    pub fn create2(deployer: Address, salt: [u8; 32], init_code_hash: [u8; 32]) -> Self {
+        let hash = keccak256([&[0xff][..], &deployer.0, &salt, &init_code_hash].concat());
+        Self::from_slice(&hash[12..])
+    }
+
+    /// Returns the `CREATE2` address for the init code itself rather than its
+    /// hash.
+    pub fn create2_from_code(deployer: Address, salt: [u8; 32], init_code: &[u8]) -> Self {
+        Self::create2(deployer, salt, keccak256(init_code))
+    }
+
+    /// Returns the EIP-55 checksummed address, with `0x` prefix.
+    pub fn checksum(&self) -> String {
+        format!("0x{}", checksum(&self.0, None))
+    }
+
+    /// Returns the checksummed address for the specified chain: EIP-1191 for
+    /// the RSK chains that adopted it, EIP-55 for all other chains including
+    /// Ethereum.
+    pub fn checksum_with_chain_id(&self, chain_id: u64) -> String {
+        format!("0x{}", checksum(&self.0, eip1191_chain_id(chain_id)))
+    }
+
+    /// Parses an address with an optional `0x` prefix, verifying its checksum
+    /// for the specified chain if it is in mixed case, see
+    /// [`Address::checksum_with_chain_id`].
+    pub fn from_str_with_chain_id(s: &str, chain_id: u64) -> Result<Self> {
+        parse(s, eip1191_chain_id(chain_id))
+    }
+
+    /// Returns the direct ICAP encoding of the address, an IBAN-like code
+    /// with the `XE` country code and the address in base 36. Addresses
+    /// below 2^155 use the 30 character basic BBAN of the ICAP
+    /// specification, larger ones an extra character.
+    pub fn to_icap(&self) -> String {
+        let mut value = self.to_u256();
+        let mut bban = Vec::new();
+        while value > U256::ZERO || bban.len() < 30 {
+            bban.push(BASE36[(value % 36).as_usize()]);
+            value /= 36;
+        }
+        bban.reverse();
+        let bban = String::from_utf8(bban).unwrap();
+        format!("XE{:02}{bban}", 98 - iban_mod97(&bban, "XE00"))
+    }
+
+    /// Parses a direct ICAP address. Indirect ICAP addresses, which refer to
+    /// an institution and client name, cannot be converted to an address.
+    pub fn from_icap(icap: &str) -> Result<Self> {
+        let icap = icap.to_ascii_uppercase();
+        let rest = icap
+            .strip_prefix("XE")
+            .with_context(|| format!("ICAP '{icap}' missing XE country code"))?;
+        ensure!(
+            rest.is_char_boundary(2) && rest[..2].bytes().all(|b| b.is_ascii_digit()),
+            "ICAP '{icap}' missing check digits"
+        );
+        let (check, bban) = rest.split_at(2);
+        ensure!(
+            matches!(bban.len(), 30 | 31),
+            "ICAP '{icap}' is not a direct ICAP address"
+        );
+        ensure!(
+            bban.bytes().all(|b| b.is_ascii_alphanumeric()),
+            "ICAP '{icap}' contains invalid characters"
+        );
+        ensure!(
+            iban_mod97(bban, &format!("XE{check}")) == 1,
+            "ICAP '{icap}' has invalid check digits"
+        );
+
+        let mut value = U256::ZERO;
+        for b in bban.bytes() {
+            let digit = BASE36.iter().position(|c| *c == b).unwrap();
+            value = value * 36 + U256::from(digit as u64);
+        }
+        ensure!(
+            value >> 160 == U256::ZERO,
+            "ICAP '{icap}' exceeds the address range"
+        );
+        Ok(Self::from_slice(&value.to_be_bytes()[12..]))
+    }
+
+    fn to_u256(self) -> U256 {
+        let mut bytes = [0; 32];
+        bytes[12..].copy_from_slice(&self.0);
+        U256::from_be_bytes(bytes)
+    }
+}
+
+impl Deref for Address {
+    type Target = [u8; 20];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Address {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for Address {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<[u8; 20]> for Address {
+    fn from(bytes: [u8; 20]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Address> for [u8; 20] {
+    fn from(address: Address) -> Self {
+        address.0
+    }
+}
+
+impl From<ethaddr::Address> for Address {
+    fn from(address: ethaddr::Address) -> Self {
+        Self(*address)
+    }
+}
+
+impl From<Address> for ethaddr::Address {
+    fn from(address: Address) -> Self {
+        Self(address.0)
+    }
+}
+
+impl From<ethereum_types::H160> for Address {
+    fn from(address: ethereum_types::H160) -> Self {
+        Self(address.0)
+    }
+}
+
+impl From<Address> for ethereum_types::H160 {
+    fn from(address: Address) -> Self {
+        Self(address.0)
+    }
+}
+
+impl From<alloy::primitives::Address> for Address {
+    fn from(address: alloy::primitives::Address) -> Self {
+        Self::from_slice(address.as_slice())
+    }
+}
+
+impl From<Address> for alloy::primitives::Address {
+    fn from(address: Address) -> Self {
+        Self::from_slice(&address.0)
+    }
+}
+
+impl FromStr for Address {
+    type Err = anyhow::Error;
+
+    /// Parses an address with an optional `0x` prefix. All lowercase and all
+    /// uppercase addresses are accepted, while mixed case addresses must have
+    /// a valid EIP-55 checksum.
+    fn from_str(s: &str) -> Result<Self> {
+        parse(s, None)
+    }
+}
+
+impl Display for Address {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.checksum())
+    }
+}
+
+impl Debug for Address {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.checksum())
+    }
+}
+
+impl LowerHex for Address {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        f.write_str(&hex::encode(self.0))
+    }
+}
+
+impl Serialize for Address {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Address {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Parses an address literal, panicking if it is invalid or has an invalid
+/// checksum.
+#[macro_export]
+macro_rules! address {
+    ($address:expr) => {
+        $address
+            .parse::<$crate::address::Address>()
+            .expect("invalid address literal")
+    };
+}
+
+const BASE36: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+/// The chains using EIP-1191 checksums, RSK mainnet and testnet.
+const EIP1191_CHAIN_IDS: [u64; 2] = [30, 31];
+
+// Returns the chain ID if the chain uses EIP-1191 checksums.
+fn eip1191_chain_id(chain_id: u64) -> Option<u64> {
+    EIP1191_CHAIN_IDS.contains(&chain_id).then_some(chain_id)
+}
+
+fn parse(s: &str, chain_id: Option<u64>) -> Result<Address> {
+    let hex = s.strip_prefix("0x").unwrap_or(s);
+    ensure!(
+        hex.len() == 40 && hex.bytes().all(|b| b.is_ascii_hexdigit()),
+        "invalid address '{s}'"
+    );
+    let address = Address::from_slice(&hex::decode(hex)?);
+
+    let mixed_case =
+        hex.bytes().any(|b| b.is_ascii_lowercase()) && hex.bytes().any(|b| b.is_ascii_uppercase());
+    if mixed_case && hex != checksum(&address, chain_id) {
+        match chain_id {
+            Some(chain_id) => bail!("invalid EIP-1191 checksum for chain {chain_id} in '{s}'"),
+            None => bail!("invalid EIP-55 checksum in '{s}'"),
+        }
+    }
+    Ok(address)
+}
+
+// Returns the checksummed hex digits of an address. Letters are uppercased
+// when the corresponding nibble of the Keccak-256 hash of the lowercase
+// address, prefixed by the chain ID for EIP-1191, is 8 or more.
+fn checksum(address: &[u8; 20], chain_id: Option<u64>) -> String {
+    let lowercase = hex::encode(address);
+    let hash = match chain_id {
+        Some(chain_id) => keccak256(format!("{chain_id}0x{lowercase}")),
+        None => keccak256(&lowercase),
+    };
+    lowercase
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> if i & 1 == 0 { 4 } else { 0 }) & 0xf;
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+// Computes the IBAN mod 97 remainder of a BBAN followed by the country code
+// and check digits, with letters converted to the numbers 10 to 35.
+fn iban_mod97(bban: &str, country_and_check: &str) -> u32 {
+    bban.chars()
+        .chain(country_and_check.chars())
+        .map(|c| c.to_digit(36).unwrap())
+        .fold(0, |remainder, digit| {
+            if digit < 10 {
+                (remainder * 10 + digit) % 97
+            } else {
+                (remainder * 100 + digit) % 97
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eip55_checksums() {
+        for address in [
+            "0x52908400098527886E0F7030069857D2E4169EE7",
+            "0x8617E340B3D01FA5F11F306F4090FD50E238070D",
+            "0xde709f2102306220921060314715629080e2fb77",
+            "0x27b1fdb04752bbc536007a920d24acb045561c26",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ] {
+            let parsed = address.parse::<Address>().unwrap();
+            assert_eq!(parsed.checksum().to_lowercase(), address.to_lowercase());
+            if address[2..].bytes().any(|b| b.is_ascii_lowercase())
+                && address[2..].bytes().any(|b| b.is_ascii_uppercase())
+            {
+                assert_eq!(parsed.to_string(), address);
+            }
+        }
+
+        let address = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+            .parse::<Address>()
+            .unwrap();
+        assert_eq!(
+            format!("{address:#x}"),
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"
+        );
+        assert_eq!(
+            "5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED"
+                .parse::<Address>()
+                .unwrap(),
+            address
+        );
+        for invalid in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD",
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beae",
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaedd",
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaeg",
+            "0X5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+        ] {
+            assert!(invalid.parse::<Address>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn eip1191_checksums() {
+        for (chain_id, addresses) in [
+            (
+                30,
+                [
+                    "0x5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD",
+                    "0xFb6916095cA1Df60bb79ce92cE3EA74c37c5d359",
+                    "0xDBF03B407c01E7CD3cBea99509D93F8Dddc8C6FB",
+                    "0xD1220A0Cf47c7B9BE7a2e6ba89F429762E7B9adB",
+                ],
+            ),
+            (
+                31,
+                [
+                    "0x5aAeb6053F3e94c9b9A09F33669435E7EF1BEaEd",
+                    "0xFb6916095CA1dF60bb79CE92ce3Ea74C37c5D359",
+                    "0xdbF03B407C01E7cd3cbEa99509D93f8dDDc8C6fB",
+                    "0xd1220a0CF47c7B9Be7A2E6Ba89f429762E7b9adB",
+                ],
+            ),
+        ] {
+            for address in addresses {
+                let parsed = Address::from_str_with_chain_id(address, chain_id).unwrap();
+                assert_eq!(parsed.checksum_with_chain_id(chain_id), address);
+                assert!(address.parse::<Address>().is_err());
+            }
+        }
+
+        // Other chains, including Ethereum, use EIP-55 checksums.
+        let eip55 = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        for chain_id in [1, 137] {
+            let parsed = Address::from_str_with_chain_id(eip55, chain_id).unwrap();
+            assert_eq!(parsed.checksum_with_chain_id(chain_id), eip55);
+            assert!(Address::from_str_with_chain_id(
+                "0x5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD",
+                chain_id
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn icap() {
+        let address = "0x8ba1f109551bD432803012645Ac136ddd64DBA72"
+            .parse::<Address>()
+            .unwrap();
+        let icap = address.to_icap();
+        assert_eq!(icap, "XE65GB6LDNXYOFTX0NSV3FUWKOWIXAMJK36");
+        assert_eq!(Address::from_icap(&icap).unwrap(), address);
+        assert_eq!(Address::from_icap(&icap.to_lowercase()).unwrap(), address);
+
+        let small = Address::from_slice(&[&[0; 19][..], &[1]].concat());
+        assert_eq!(small.to_icap().len(), 34);
+        let large = Address([0xff; 20]);
+        assert_eq!(large.to_icap().len(), 35);
+        for address in [Address::ZERO, small, large] {
+            assert_eq!(Address::from_icap(&address.to_icap()).unwrap(), address);
+        }
+
+        for invalid in [
+            "XE66GB6LDNXYOFTX0NSV3FUWKOWIXAMJK36",
+            "DE65GB6LDNXYOFTX0NSV3FUWKOWIXAMJK36",
+            "XE81ETHXREGGAVOFYORK",
+            "XE65GB6LDNXYOFTX0NSV3FUWKOWIXAMJK3!",
+        ] {
+            assert!(Address::from_icap(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn contract_addresses() {
+        let sender = address!("0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0");
+        for (nonce, address) in [
+            (0, "0xcd234a471b72ba2f1ccf0a70fcaba648a5eecd8d"),
+            (1, "0x343c43a37d37dff08ae8c4a11544c718abb4fcf8"),
+            (2, "0xf778b86fa74e846c4f0a1fbd1335fe81c00a0c91"),
+            (3, "0xfffd933a0bc612844eaf0c6fe3e5b8e9b6c1d19c"),
+        ] {
+            assert_eq!(Address::create(sender, U256::new(nonce)), address!(address));
+        }
+
+        // EIP-1014 examples.
+        let word = |hex: &str| {
+            let mut word = [0; 32];
+            hex::decode_to_slice(format!("{hex:0>64}"), &mut word).unwrap();
+            word
+        };
+        for (deployer, salt, init_code, address) in [
+            (
+                "0x0000000000000000000000000000000000000000",
+                "00",
+                "00",
+                "0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38",
+            ),
+            (
+                "0xdeadbeef00000000000000000000000000000000",
+                "00",
+                "00",
+                "0xB928f69Bb1D91Cd65274e3c79d8986362984fDA3",
+            ),
+            (
+                "0xdeadbeef00000000000000000000000000000000",
+                "feed000000000000000000000000000000000000",
+                "00",
+                "0xD04116cDd17beBE565EB2422F2497E06cC1C9833",
+            ),
+            (
+                "0x0000000000000000000000000000000000000000",
+                "00",
+                "deadbeef",
+                "0x70f2b2914A2a4b783FaEFb75f459A580616Fcb5e",
+            ),
+            (
+                "0x00000000000000000000000000000000deadbeef",
+                "cafebabe",
+                "deadbeef",
+                "0x60f3f640a8508fC6a86d45DF051962668E1e8AC7",
+            ),
+            (
+                "0x0000000000000000000000000000000000000000",
+                "00",
+                "",
+                "0xE33C0C7F7df4809055C3ebA6c09CFe4BaF1BD9e0",
+            ),
+        ] {
+            let init_code = hex::decode(init_code).unwrap();
+            assert_eq!(
+                Address::create2_from_code(address!(deployer), word(salt), &init_code),
+                address!(address),
+            );
+        }
+    }
+
+    #[test]
+    fn serde() {
+        let address = "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359"
+            .parse::<Address>()
+            .unwrap();
+        let json = serde_json::to_string(&address).unwrap();
+        assert_eq!(json, "\"0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359\"");
+        assert_eq!(serde_json::from_str::<Address>(&json).unwrap(), address);
+        assert!(
+            serde_json::from_str::<Address>("\"0xFB6916095ca1df60bB79Ce92cE3Ea74c37c5d359\"")
+                .is_err()
+        );
+    }
+}
@@ -1,83 +1,344 @@
 //! hierachical deterministic key derivation scheme.
 
 pub use super::path::{Component, Path};
-use crate::wallet::Wallet;
-use anyhow::{Context as _, Result};
+use crate::{utils::hash, wallet::Wallet};
+use anyhow::{ensure, Context as _, Result};
 use hmac::{Hmac, Mac as _};
-use k256::{elliptic_curve::sec1::ToEncodedPoint as _, SecretKey};
+use k256::{elliptic_curve::sec1::ToEncodedPoint as _, PublicKey, SecretKey};
 use sha2::Sha512;
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
 
-/// A value indicating a path component is hardened.
-const HARDENED: u32 = 0x8000_0000;
+/// The version bytes of a serialized mainnet extended private key.
+const XPRV_VERSION: [u8; 4] = [0x04, 0x88, 0xad, 0xe4];
+/// The version bytes of a serialized mainnet extended public key.
+const XPUB_VERSION: [u8; 4] = [0x04, 0x88, 0xb2, 0x1e];
 
 /// Creates a new extended private key from a seed.
 pub fn derive(seed: impl AsRef<[u8]>, path: &Path) -> Result<Wallet> {
-    derive_slice(seed.as_ref(), path)
+    ExtendedPrivateKey::new_master(seed)?.derive(path)?.wallet()
 }
 
-fn derive_slice(seed: &[u8], path: &Path) -> Result<Wallet> {
-    // creating an HMAC-SHA512 hash of the seed.
-    let mut extended_key = {
+/// A BIP-0032 extended private key: a secret key with its chain code and
+/// position in the key tree.
+#[derive(Clone)]
+pub struct ExtendedPrivateKey {
+    secret: SecretKey,
+    chain_code: [u8; 32],
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: u32,
+}
+
+impl ExtendedPrivateKey {
+    /// Creates the master extended private key from a seed.
+    pub fn new_master(seed: impl AsRef<[u8]>) -> Result<Self> {
+        // creating an HMAC-SHA512 hash of the seed.
         let mut hmac = Hmac::<Sha512>::new_from_slice(b"Bitcoin seed")?;
         hmac.update(seed.as_ref());
-        hmac.finalize().into_bytes()
-    };
+        let extended_key = hmac.finalize().into_bytes();
 
-    // Iterate over each component of the derivation path
-    // ie. m/44'/60'/0'/0/0
-    for (i, component) in path.components().enumerate() {
-        // split the extended key into secret and chain code
         let (secret, chain_code) = extended_key.split_at(32);
-        let secret = SecretKey::from_slice(secret)?;
+        Self::from_parts(secret, chain_code)
+    }
+
+    /// Creates a master extended private key from a secret key and chain
+    /// code.
+    pub fn from_parts(secret: &[u8], chain_code: &[u8]) -> Result<Self> {
+        Ok(Self {
+            secret: SecretKey::from_slice(secret)?,
+            chain_code: chain_code.try_into().context("invalid chain code length")?,
+            depth: 0,
+            parent_fingerprint: [0; 4],
+            child_number: 0,
+        })
+    }
+
+    /// Derives the extended private key at the specified path, relative to
+    /// this key.
+    pub fn derive(&self, path: &Path) -> Result<Self> {
+        // Iterate over each component of the derivation path
+        // ie. m/44'/60'/0'/0/0
+        path.components()
+            .enumerate()
+            .try_fold(self.clone(), |key, (i, component)| {
+                key.derive_child(component).with_context(|| {
+                    format!("path '{path}' component #{i} yields invalid child key")
+                })
+            })
+    }
+
+    /// Derives a child extended private key.
+    pub fn derive_child(&self, component: Component) -> Result<Self> {
+        component.validate()?;
+        let secret = &self.secret;
 
         // Create a new HMAC-SHA512 hash using the chain code
-        let mut hmac: Hmac<Sha512> = Hmac::<Sha512>::new_from_slice(chain_code)?;
-        let value = match component {
+        let mut hmac: Hmac<Sha512> = Hmac::<Sha512>::new_from_slice(&self.chain_code)?;
+        match component {
             // If the component is hardened, update the HMAC with the secret key
-            Component::Hardened(value) => {
+            Component::Hardened(_) => {
                 hmac.update(&[0]);
                 hmac.update(&secret.to_bytes());
-                value | HARDENED
             }
             // If the component is normal, update the HMAC with the public key derived from the secret key
-            Component::Normal(value) => {
+            Component::Normal(_) => {
                 hmac.update(secret.public_key().to_encoded_point(true).as_bytes());
-                value
             }
-        };
+        }
+        let value = component.index();
         hmac.update(&value.to_be_bytes());
 
         // Finalize the HMAC to get the child key
-        let mut child_key = hmac.finalize().into_bytes();
+        let child_key = hmac.finalize().into_bytes();
+        let (child_secret, chain_code) = child_key.split_at(32);
 
         // Create a new secret key from the first 32 bytes of the child key
-        let child_secret = SecretKey::from_slice(&child_key[..32])
-            .with_context(|| format!("path '{path}' component #{i} yields invalid child key"))?;
+        let child_secret = SecretKey::from_slice(child_secret)?;
 
         // Create a new secret key by adding the current secret key to the child secret key
         let next_secret =
             SecretKey::new(*child_secret.as_scalar_primitive() + *secret.as_scalar_primitive());
 
-        // Replace the first 32 bytes of the child key with the bytes of the new secret key
-        child_key[..32].copy_from_slice(&next_secret.to_bytes());
+        Ok(Self {
+            secret: next_secret,
+            chain_code: chain_code.try_into()?,
+            depth: self
+                .depth
+                .checked_add(1)
+                .context("maximum key depth exceeded")?,
+            parent_fingerprint: self.fingerprint(),
+            child_number: value,
+        })
+    }
+
+    /// Returns the key fingerprint, the first 4 bytes of the HASH160 of the
+    /// compressed public key.
+    pub fn fingerprint(&self) -> [u8; 4] {
+        fingerprint(&self.secret.public_key())
+    }
+
+    /// Returns the extended public key with the same position in the key
+    /// tree.
+    pub fn public_key(&self) -> ExtendedPublicKey {
+        ExtendedPublicKey {
+            public_key: self.secret.public_key(),
+            chain_code: self.chain_code,
+            depth: self.depth,
+            parent_fingerprint: self.parent_fingerprint,
+            child_number: self.child_number,
+        }
+    }
+
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    pub fn parent_fingerprint(&self) -> [u8; 4] {
+        self.parent_fingerprint
+    }
+
+    /// Returns the BIP-0032 child index of the key, hardened indices
+    /// include the hardened bit.
+    pub fn child_number(&self) -> u32 {
+        self.child_number
+    }
+
+    /// Returns the raw 32-byte secret key.
+    pub fn secret(&self) -> [u8; 32] {
+        self.secret.to_bytes().into()
+    }
+
+    pub fn chain_code(&self) -> [u8; 32] {
+        self.chain_code
+    }
+
+    /// Returns a wallet for the secret key.
+    pub fn wallet(&self) -> Result<Wallet> {
+        Wallet::from_secret(self.secret())
+    }
+}
+
+impl Display for ExtendedPrivateKey {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut data = Vec::with_capacity(78);
+        data.extend_from_slice(&XPRV_VERSION);
+        data.push(self.depth);
+        data.extend_from_slice(&self.parent_fingerprint);
+        data.extend_from_slice(&self.child_number.to_be_bytes());
+        data.extend_from_slice(&self.chain_code);
+        data.push(0);
+        data.extend_from_slice(&self.secret.to_bytes());
 
-        // Set the extended key to the child key
-        extended_key = child_key
+        f.write_str(&bs58::encode(data).with_check().into_string())
     }
+}
+
+impl FromStr for ExtendedPrivateKey {
+    type Err = anyhow::Error;
 
-    // create a new wallet from the first 32 bytes of the extended key
-    Wallet::from_secret(&extended_key[..32])
+    fn from_str(s: &str) -> Result<Self> {
+        let data = bs58::decode(s)
+            .with_check(None)
+            .into_vec()
+            .context("invalid extended private key encoding")?;
+        ensure!(data.len() == 78, "invalid extended private key length");
+        ensure!(
+            data[..4] == XPRV_VERSION,
+            "unsupported extended private key version"
+        );
+        ensure!(data[45] == 0, "invalid extended private key");
+
+        Ok(Self {
+            secret: SecretKey::from_slice(&data[46..])?,
+            chain_code: data[13..45].try_into()?,
+            depth: data[4],
+            parent_fingerprint: data[5..9].try_into()?,
+            child_number: u32::from_be_bytes(data[9..13].try_into()?),
+        })
+    }
+}
+
+/// A BIP-0032 extended public key. Only the public key and chain code are
+/// kept, so it can be shared with watch-only tooling.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExtendedPublicKey {
+    public_key: PublicKey,
+    chain_code: [u8; 32],
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: u32,
+}
+
+impl ExtendedPublicKey {
+    /// Returns the key fingerprint, the first 4 bytes of the HASH160 of the
+    /// compressed public key.
+    pub fn fingerprint(&self) -> [u8; 4] {
+        fingerprint(&self.public_key)
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    pub fn chain_code(&self) -> [u8; 32] {
+        self.chain_code
+    }
+
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    pub fn parent_fingerprint(&self) -> [u8; 4] {
+        self.parent_fingerprint
+    }
+
+    pub fn child_number(&self) -> u32 {
+        self.child_number
+    }
+}
+
+impl Display for ExtendedPublicKey {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut data = Vec::with_capacity(78);
+        data.extend_from_slice(&XPUB_VERSION);
+        data.push(self.depth);
+        data.extend_from_slice(&self.parent_fingerprint);
+        data.extend_from_slice(&self.child_number.to_be_bytes());
+        data.extend_from_slice(&self.chain_code);
+        data.extend_from_slice(self.public_key.to_encoded_point(true).as_bytes());
+
+        f.write_str(&bs58::encode(data).with_check().into_string())
+    }
+}
+
+impl FromStr for ExtendedPublicKey {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let data = bs58::decode(s)
+            .with_check(None)
+            .into_vec()
+            .context("invalid extended public key encoding")?;
+        ensure!(data.len() == 78, "invalid extended public key length");
+        ensure!(
+            data[..4] == XPUB_VERSION,
+            "unsupported extended public key version"
+        );
+
+        Ok(Self {
+            public_key: PublicKey::from_sec1_bytes(&data[45..])?,
+            chain_code: data[13..45].try_into()?,
+            depth: data[4],
+            parent_fingerprint: data[5..9].try_into()?,
+            child_number: u32::from_be_bytes(data[9..13].try_into()?),
+        })
+    }
+}
+
+// Returns the first 4 bytes of the HASH160 of the compressed public key.
+fn fingerprint(public_key: &PublicKey) -> [u8; 4] {
+    hash::hash160(public_key.to_encoded_point(true).as_bytes())[..4]
+        .try_into()
+        .unwrap()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::address;
     use crate::bip39::mnemonic::Mnemonic;
-    use ethaddr::address;
+    use hex_literal::hex;
 
     const MNEMONIC: &str =
         "myth like bonus scare over problem client lizard pioneer submit female collect";
 
+    #[test]
+    fn extended_private_keys() {
+        // BIP-0032 test vector 1, chain m/0'/1.
+        let master =
+            ExtendedPrivateKey::new_master(hex!("000102030405060708090a0b0c0d0e0f")).unwrap();
+        assert_eq!(
+            master.to_string(),
+            "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi",
+        );
+
+        let child = master.derive(&"m/0'/1".parse().unwrap()).unwrap();
+        let xprv = "xprv9wTYmMFdV23N2TdNG573QoEsfRrWKQgWeibmLntzniatZvR9BmLnvSxqu53Kw1UmYPxLgboyZQaXwTCg8MSY3H2EU4pWcQDnRnrVA1xe8fs";
+        assert_eq!(child.to_string(), xprv);
+        assert_eq!(
+            xprv.parse::<ExtendedPrivateKey>().unwrap().to_string(),
+            xprv
+        );
+        assert_eq!(hex::encode(master.fingerprint()), "3442193e");
+        assert_eq!(hex::encode(child.parent_fingerprint()), "5c1bd648");
+        assert_eq!(child.child_number(), 1);
+        assert_eq!(child.depth(), 2);
+
+        assert!("xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHj"
+            .parse::<ExtendedPrivateKey>()
+            .is_err());
+    }
+
+    #[test]
+    fn extended_public_keys() {
+        let master =
+            ExtendedPrivateKey::new_master(hex!("000102030405060708090a0b0c0d0e0f")).unwrap();
+        for (path, xpub) in [
+            ("m", "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8"),
+            ("m/0'/1", "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ"),
+        ] {
+            let key = master.derive(&path.parse().unwrap()).unwrap().public_key();
+            assert_eq!(key.to_string(), xpub);
+            let parsed = xpub.parse::<ExtendedPublicKey>().unwrap();
+            assert_eq!(parsed, key);
+            assert_eq!(parsed.fingerprint(), key.fingerprint());
+        }
+        assert!(master.to_string().parse::<ExtendedPublicKey>().is_err());
+    }
+
     #[test]
     fn ganache_deterministic_mnemonic() {
         let mnemonic = MNEMONIC.parse::<Mnemonic>().unwrap();
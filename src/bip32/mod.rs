@@ -1,2 +1,3 @@
 pub mod hdk;
+pub mod origin;
 pub mod path;
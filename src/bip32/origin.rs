@@ -0,0 +1,96 @@
+//! Module implementing key-origin information as used by output descriptors
+//! and PSBTs.
+//!
+//! A key origin records the fingerprint of the master key and the path a
+//! key was derived at, written as `[d34db33f/44'/60'/0']`, so signers can
+//! find the key in their own key tree.
+
+use super::{hdk::ExtendedPublicKey, path::Path};
+use anyhow::{ensure, Context as _, Result};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// The master key fingerprint and derivation path of a key.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct KeyOrigin {
+    pub fingerprint: [u8; 4],
+    pub path: Path,
+}
+
+impl KeyOrigin {
+    pub fn new(fingerprint: [u8; 4], path: Path) -> Self {
+        Self { fingerprint, path }
+    }
+
+    /// Returns the key expression of an extended public key with this
+    /// origin, for example `[d34db33f/44'/60'/0']xpub...`.
+    pub fn key_expression(&self, key: &ExtendedPublicKey) -> String {
+        format!("{self}{key}")
+    }
+}
+
+impl Display for KeyOrigin {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "[{}", hex::encode(self.fingerprint))?;
+        for component in self.path.components() {
+            write!(f, "/{component}")?;
+        }
+        f.write_str("]")
+    }
+}
+
+impl FromStr for KeyOrigin {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let origin = s
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .context("key origin must be enclosed in brackets")?;
+        let (fingerprint, path) = match origin.split_once('/') {
+            Some((fingerprint, path)) => (fingerprint, path),
+            None => (origin, ""),
+        };
+        ensure!(
+            fingerprint.len() == 8,
+            "invalid key origin fingerprint '{fingerprint}'"
+        );
+
+        let mut bytes = [0; 4];
+        hex::decode_to_slice(fingerprint, &mut bytes)
+            .with_context(|| format!("invalid key origin fingerprint '{fingerprint}'"))?;
+        ensure!(!origin.ends_with('/'), "invalid key origin path '{origin}'");
+        Ok(Self::new(bytes, Path::root().join(path)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_and_parse() {
+        let origin = KeyOrigin::new([0xd3, 0x4d, 0xb3, 0x3f], "m/44'/60'/0'".parse().unwrap());
+        assert_eq!(origin.to_string(), "[d34db33f/44'/60'/0']");
+        assert_eq!(
+            "[d34db33f/44h/60h/0h]".parse::<KeyOrigin>().unwrap(),
+            origin
+        );
+
+        let root = "[d34db33f]".parse::<KeyOrigin>().unwrap();
+        assert!(root.path.is_root());
+        assert_eq!(root.to_string(), "[d34db33f]");
+
+        for origin in [
+            "d34db33f/0",
+            "[d34db3/0]",
+            "[d34db33g/0]",
+            "[d34db33f/]",
+            "[d34db33f/x]",
+        ] {
+            assert!(origin.parse::<KeyOrigin>().is_err(), "{origin}");
+        }
+    }
+}
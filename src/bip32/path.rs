@@ -1,21 +1,45 @@
 //! Module implementing parsing for BIP-0032 HD paths used for key derivation.
 
-use anyhow::{Context as _, Result};
+use anyhow::{ensure, Context as _, Result};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     fmt::{self, Display, Formatter},
     str::FromStr,
 };
 
+/// A value indicating a path component is hardened.
+pub const HARDENED: u32 = 0x8000_0000;
+
 /// A parsed hierarchical derivation path.
-#[derive(Debug)]
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Path {
     components: Vec<Component>,
 }
 
+/// The default Ethereum HD path template, `{index}` is replaced with the
+/// account index.
+pub const DEFAULT_TEMPLATE: &str = "m/44'/60'/0'/0/{index}";
+
 impl Path {
+    /// Returns the root path `m`.
+    pub fn root() -> Self {
+        Self::default()
+    }
+
     /// Creates the default Ethereum HD path for the specified account index.
     pub fn for_index(index: usize) -> Self {
-        format!("m/44'/60'/0'/0/{index}").parse().unwrap()
+        Self::for_template(DEFAULT_TEMPLATE, index).unwrap()
+    }
+
+    /// Creates an HD path from a template for the specified account index.
+    /// All occurrences of `{index}` in the template are replaced by the index,
+    /// for example `m/44'/60'/{index}'/0/0` for Ledger Live accounts.
+    pub fn for_template(template: &str, index: usize) -> Result<Self> {
+        ensure!(
+            template.contains("{index}"),
+            "BIP-0032 path template '{template}' missing index placeholder",
+        );
+        template.replace("{index}", &index.to_string()).parse()
     }
 
     /// Creates a new path from path string.
@@ -23,10 +47,107 @@ impl Path {
         path.parse().unwrap()
     }
 
+    /// Creates a path from its components, validating their indices.
+    pub fn from_components(components: impl IntoIterator<Item = Component>) -> Result<Self> {
+        let mut path = Self::root();
+        for component in components {
+            path.push(component)?;
+        }
+        Ok(path)
+    }
+
     /// Returns an iterator over the path components.
     pub fn components(&self) -> impl Iterator<Item = Component> + '_ {
         self.components.iter().copied()
     }
+
+    /// Returns the number of components, which is the depth of the derived
+    /// key.
+    pub fn depth(&self) -> usize {
+        self.components.len()
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// Appends a component to the path. Paths are at most 255 components
+    /// deep, as the depth of an extended key is a single byte.
+    pub fn push(&mut self, component: Component) -> Result<()> {
+        component.validate()?;
+        ensure!(
+            self.components.len() < u8::MAX as usize,
+            "BIP-0032 path deeper than 255 components",
+        );
+        self.components.push(component);
+        Ok(())
+    }
+
+    /// Returns the path of the parent key, or `None` for the root.
+    pub fn parent(&self) -> Option<Self> {
+        let (_, parent) = self.components.split_last()?;
+        Some(Self {
+            components: parent.to_vec(),
+        })
+    }
+
+    /// Returns the path of a child key.
+    pub fn child(&self, component: Component) -> Result<Self> {
+        let mut path = self.clone();
+        path.push(component)?;
+        Ok(path)
+    }
+
+    /// Appends a relative path such as `0/1'` to the path.
+    pub fn join(&self, relative: &str) -> Result<Self> {
+        let mut path = self.clone();
+        if !relative.is_empty() {
+            for component in relative.split('/') {
+                path.push(component.parse()?)?;
+            }
+        }
+        Ok(path)
+    }
+
+    /// Returns the path relative to an ancestor path, for example `0/1` for
+    /// `m/44'/0/1` relative to `m/44'`.
+    pub fn strip_prefix(&self, ancestor: &Path) -> Option<String> {
+        let relative = self.components.strip_prefix(&ancestor.components[..])?;
+        Some(
+            relative
+                .iter()
+                .map(Component::to_string)
+                .collect::<Vec<_>>()
+                .join("/"),
+        )
+    }
+
+    /// Encodes the path as a component count followed by the big-endian
+    /// child indices, the format used by hardware wallets.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(1 + 4 * self.components.len());
+        bytes.push(self.components.len() as u8);
+        for component in self.components() {
+            bytes.extend_from_slice(&component.index().to_be_bytes());
+        }
+        bytes
+    }
+
+    /// Decodes a path from its binary form.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (count, indices) = bytes.split_first().context("empty BIP-0032 path bytes")?;
+        ensure!(
+            indices.len() == 4 * *count as usize,
+            "BIP-0032 path bytes have invalid length {}",
+            bytes.len(),
+        );
+        Ok(Self {
+            components: indices
+                .chunks_exact(4)
+                .map(|index| Component::from_index(u32::from_be_bytes(index.try_into().unwrap())))
+                .collect(),
+        })
+    }
 }
 
 impl Display for Path {
@@ -44,19 +165,41 @@ impl FromStr for Path {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let components = s
+        if s == "m" {
+            return Ok(Self::root());
+        }
+        let relative = s
             .strip_prefix("m/")
-            .context("BIP-0032 path missing main node")?
-            .split('/')
-            .map(Component::from_str)
-            .collect::<Result<_>>()?;
+            .context("BIP-0032 path missing main node")?;
+        ensure!(
+            !relative.is_empty(),
+            "BIP-0032 path '{s}' has no components"
+        );
+        Self::root().join(relative)
+    }
+}
 
-        Ok(Self { components })
+impl Serialize for Path {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Path {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let path = String::deserialize(deserializer)?;
+        path.parse().map_err(serde::de::Error::custom)
     }
 }
 
 /// A hierarchical path component.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Component {
     /// Component to generate a hardened child key.
     Hardened(u32),
@@ -64,6 +207,47 @@ pub enum Component {
     Normal(u32),
 }
 
+impl Component {
+    /// Creates a component from a BIP-0032 child index, where indices from
+    /// 2^31 are hardened.
+    pub fn from_index(index: u32) -> Self {
+        if index & HARDENED != 0 {
+            Self::Hardened(index & !HARDENED)
+        } else {
+            Self::Normal(index)
+        }
+    }
+
+    /// Returns the BIP-0032 child index of the component.
+    pub fn index(self) -> u32 {
+        match self {
+            Self::Hardened(value) => value | HARDENED,
+            Self::Normal(value) => value,
+        }
+    }
+
+    /// Returns the component value without the hardened bit.
+    pub fn value(self) -> u32 {
+        match self {
+            Self::Hardened(value) | Self::Normal(value) => value,
+        }
+    }
+
+    pub fn is_hardened(self) -> bool {
+        matches!(self, Self::Hardened(_))
+    }
+
+    /// Checks that the component value is below 2^31.
+    pub fn validate(self) -> Result<()> {
+        ensure!(
+            self.value() < HARDENED,
+            "BIP-0032 path component {} out of range",
+            self.value(),
+        );
+        Ok(())
+    }
+}
+
 impl Display for Component {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
@@ -77,13 +261,16 @@ impl FromStr for Component {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let (value, hardened) = match s.strip_suffix('\'') {
+        let (value, hardened) = match s.strip_suffix(['\'', 'h', 'H']) {
             Some(value) => (value, true),
             None => (s, false),
         };
 
-        let value = value
-            .parse()
+        // NOTE: `u32::from_str` accepts a leading `+` sign.
+        let value = Some(value)
+            .filter(|value| value.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|value| value.parse::<u32>().ok())
+            .filter(|value| value & HARDENED == 0)
             .with_context(|| format!("invalid BIP-0032 path component '{s}'"))?;
 
         Ok(if hardened {
@@ -93,3 +280,88 @@ impl FromStr for Component {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_format() {
+        for (path, formatted) in [
+            ("m", "m"),
+            ("m/0", "m/0"),
+            ("m/44'/60'/0'/0/0", "m/44'/60'/0'/0/0"),
+            ("m/44h/60H/0'/0/2147483647", "m/44'/60'/0'/0/2147483647"),
+        ] {
+            assert_eq!(path.parse::<Path>().unwrap().to_string(), formatted);
+        }
+        for path in [
+            "",
+            "m/",
+            "0/1",
+            "M/0",
+            "m0",
+            "m//0",
+            "m/0/",
+            "m/2147483648",
+            "m/1x",
+            "m/+1",
+            "m/-1",
+            "m/0''",
+        ] {
+            assert!(path.parse::<Path>().is_err(), "{path}");
+        }
+        assert_eq!(
+            "m/44'/60h".parse::<Path>().unwrap(),
+            "m/44H/60'".parse::<Path>().unwrap(),
+        );
+    }
+
+    #[test]
+    fn operations() {
+        let mut path = Path::root();
+        assert!(path.is_root());
+        assert!(path.parent().is_none());
+        path.push(Component::Hardened(44)).unwrap();
+        assert!(path.push(Component::Normal(HARDENED)).is_err());
+
+        let account = path.join("60'/0'").unwrap();
+        assert_eq!(account.depth(), 3);
+        let address = account
+            .child(Component::Normal(0))
+            .unwrap()
+            .join("5")
+            .unwrap();
+        assert_eq!(address.to_string(), "m/44'/60'/0'/0/5");
+        assert_eq!(address.parent().unwrap().parent().unwrap(), account);
+        assert_eq!(address.strip_prefix(&account).as_deref(), Some("0/5"));
+        assert_eq!(
+            address.strip_prefix(&Path::for_index(5)).as_deref(),
+            Some("")
+        );
+        assert!(account.strip_prefix(&address).is_none());
+        assert!(account.join("0/").is_err());
+
+        assert_eq!(Component::from_index(HARDENED | 7), Component::Hardened(7));
+        assert_eq!(Component::Hardened(7).index(), HARDENED | 7);
+    }
+
+    #[test]
+    fn encodings() {
+        let path = Path::for_index(1);
+        let bytes = path.to_bytes();
+        assert_eq!(
+            hex::encode(&bytes),
+            "058000002c8000003c800000000000000000000001"
+        );
+        assert_eq!(Path::from_bytes(&bytes).unwrap(), path);
+        assert_eq!(Path::from_bytes(&[0]).unwrap(), Path::root());
+        assert!(Path::from_bytes(&bytes[..20]).is_err());
+        assert!(Path::from_bytes(&[]).is_err());
+
+        let json = serde_json::to_string(&path).unwrap();
+        assert_eq!(json, "\"m/44'/60'/0'/0/1\"");
+        assert_eq!(serde_json::from_str::<Path>(&json).unwrap(), path);
+        assert!(serde_json::from_str::<Path>("\"m/x\"").is_err());
+    }
+}
@@ -0,0 +1,37 @@
+//! Module for restoring legacy Ethereum brain wallets.
+//!
+//! Early Ethereum tools derived the private key directly from a passphrase
+//! as its Keccak-256 hash. There is no salt or key stretching, so these
+//! wallets are easily brute forced and should only be imported to move the
+//! funds to a new wallet.
+
+use crate::{utils::hash::keccak256, wallet::Wallet};
+use anyhow::{ensure, Result};
+
+/// Returns the wallet of a brain wallet passphrase. The passphrase is used
+/// verbatim, as the original tools did not normalize it.
+pub fn brain_wallet(passphrase: &str) -> Result<Wallet> {
+    ensure!(!passphrase.is_empty(), "brain wallet passphrase is empty");
+    Wallet::from_secret(keccak256(passphrase))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn brain_wallets() {
+        let wallet = brain_wallet("correct horse battery staple").unwrap();
+        assert_eq!(
+            hex::encode(wallet.secret()),
+            "3ff888a183487d35cd7e71a75164bcb45ee51392f7a804b917cef66454c1cd2d",
+        );
+        assert_ne!(
+            brain_wallet("Correct horse battery staple")
+                .unwrap()
+                .address(),
+            wallet.address(),
+        );
+        assert!(brain_wallet("").is_err());
+    }
+}
@@ -0,0 +1,286 @@
+//! Module for importing Electrum seed phrases.
+//!
+//! Electrum seeds since version 2.0 are not BIP-0039 mnemonics: instead of a
+//! checksum, the HMAC-SHA512 of the phrase keyed with "Seed version" starts
+//! with a version prefix, and the seed is derived with the salt "electrum"
+//! instead of "mnemonic". The words are not required to come from a
+//! wordlist, so the phrase is only normalized.
+//!
+//! <https://electrum.readthedocs.io/en/latest/seedphrase.html>
+
+use super::mnemonic::Seed;
+use anyhow::{bail, Result};
+use hmac::{Hmac, Mac as _};
+use sha2::Sha512;
+use std::fmt::{self, Display, Formatter};
+use unicode_normalization::{char::canonical_combining_class, UnicodeNormalization as _};
+
+/// The version of an Electrum seed, which determines the wallet type.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SeedVersion {
+    Standard,
+    Segwit,
+    TwoFactor,
+    TwoFactorSegwit,
+}
+
+impl SeedVersion {
+    pub const ALL: [Self; 4] = [
+        Self::Standard,
+        Self::Segwit,
+        Self::TwoFactor,
+        Self::TwoFactorSegwit,
+    ];
+
+    /// Returns the hex prefix of the seed version HMAC.
+    pub fn prefix(self) -> &'static str {
+        match self {
+            Self::Standard => "01",
+            Self::Segwit => "100",
+            Self::TwoFactor => "101",
+            Self::TwoFactorSegwit => "102",
+        }
+    }
+
+    /// Returns the HD path template of the receiving addresses Electrum
+    /// shows for the wallet type. Two-factor wallets are multisig wallets
+    /// whose addresses cannot be derived from the seed alone.
+    pub fn derivation_template(self) -> Option<&'static str> {
+        match self {
+            Self::Standard => Some("m/0/{index}"),
+            Self::Segwit => Some("m/0'/0/{index}"),
+            Self::TwoFactor | Self::TwoFactorSegwit => None,
+        }
+    }
+}
+
+impl Display for SeedVersion {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Standard => "standard",
+            Self::Segwit => "segwit",
+            Self::TwoFactor => "2fa",
+            Self::TwoFactorSegwit => "2fa-segwit",
+        })
+    }
+}
+
+/// A normalized Electrum seed phrase with a recognized version.
+#[derive(Clone)]
+pub struct ElectrumSeed {
+    phrase: String,
+    version: SeedVersion,
+}
+
+impl ElectrumSeed {
+    /// Parses an Electrum seed phrase. Phrases without a valid seed version,
+    /// including BIP-0039 mnemonics and pre-2.0 Electrum seeds, are rejected.
+    pub fn from_phrase(phrase: impl AsRef<str>) -> Result<Self> {
+        let phrase = normalize_text(phrase.as_ref());
+        match version_of(&phrase) {
+            Some(version) => Ok(Self { phrase, version }),
+            None => bail!("phrase is not an Electrum seed with a known version"),
+        }
+    }
+
+    pub fn version(&self) -> SeedVersion {
+        self.version
+    }
+
+    /// Returns the normalized phrase the seed is derived from.
+    pub fn phrase(&self) -> &str {
+        &self.phrase
+    }
+
+    /// Derives the BIP-0032 seed, the passphrase is normalized like the
+    /// phrase.
+    pub fn to_seed(&self, passphrase: impl AsRef<str>) -> Seed {
+        const PBKDF2_ROUNDS: u32 = 2048;
+
+        let salt = format!("electrum{}", normalize_text(passphrase.as_ref()));
+        let mut seed = [0; 64];
+        pbkdf2::pbkdf2::<Hmac<Sha512>>(
+            self.phrase.as_bytes(),
+            salt.as_bytes(),
+            PBKDF2_ROUNDS,
+            &mut seed,
+        )
+        .expect("invalid length");
+        Seed(seed)
+    }
+}
+
+/// Returns the Electrum seed version of a phrase, if it has one.
+pub fn seed_version(phrase: &str) -> Option<SeedVersion> {
+    version_of(&normalize_text(phrase))
+}
+
+fn version_of(normalized: &str) -> Option<SeedVersion> {
+    let mut hmac =
+        Hmac::<Sha512>::new_from_slice(b"Seed version").expect("HMAC accepts any key length");
+    hmac.update(normalized.as_bytes());
+    let digest = hex::encode(hmac.finalize().into_bytes());
+    SeedVersion::ALL
+        .into_iter()
+        .find(|version| digest.starts_with(version.prefix()))
+}
+
+// Normalizes text the way Electrum does: NFKD, lower case, accents removed,
+// whitespace collapsed and no spaces between CJK characters.
+fn normalize_text(text: &str) -> String {
+    let text = text
+        .nfkd()
+        .collect::<String>()
+        .to_lowercase()
+        .chars()
+        .filter(|c| canonical_combining_class(*c) == 0)
+        .collect::<String>();
+    let words = text.split_whitespace().collect::<Vec<_>>();
+
+    let mut normalized = String::with_capacity(text.len());
+    for (i, word) in words.iter().enumerate() {
+        if i > 0 {
+            let previous = words[i - 1].chars().last();
+            let next = word.chars().next();
+            if !(previous.is_some_and(is_cjk) && next.is_some_and(is_cjk)) {
+                normalized.push(' ');
+            }
+        }
+        normalized.push_str(word);
+    }
+    normalized
+}
+
+// Returns whether a character is in one of the main CJK blocks.
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1100..=0x11ff
+            | 0x2e80..=0x2fdf
+            | 0x3000..=0x31ff
+            | 0x3400..=0x4dbf
+            | 0x4e00..=0x9fff
+            | 0xa960..=0xa97f
+            | 0xac00..=0xd7ff
+            | 0xf900..=0xfaff
+            | 0xff00..=0xffef
+            | 0x20000..=0x2fa1f
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bip32::{
+            hdk::{ExtendedPrivateKey, ExtendedPublicKey},
+            path::Path,
+        },
+        utils::hash::hash160,
+    };
+    use k256::elliptic_curve::sec1::ToEncodedPoint as _;
+
+    const SEGWIT: &str =
+        "wild father tree among universe such mobile favorite target dynamic credit identify";
+
+    #[test]
+    fn segwit_vectors() {
+        let seed = ElectrumSeed::from_phrase(SEGWIT).unwrap();
+        assert_eq!(seed.version(), SeedVersion::Segwit);
+        assert_eq!(
+            hex::encode(seed.to_seed("")),
+            "aac2a6302e48577ab4b46f23dbae0774e2e62c796f797d0a1b5faeb528301e3064342dafb79069e7c4c6b8c38ae11d7a973bec0d4f70626f8cc5184a8d0b0756",
+        );
+        assert_eq!(
+            hex::encode(seed.to_seed("Did you ever hear the tragedy of Darth Plagueis the Wise?")),
+            "4aa29f2aeb0127efb55138ab9e7be83b36750358751906f86c662b21a1ea1370f949e6d1a12fa56d3d93cadda93038c76ac8118597364e46f5156fde6183c82f",
+        );
+    }
+
+    // Re-encodes an extended public key with other version bytes, such as
+    // the `zpub` prefix Electrum shows for native segwit wallets.
+    fn with_version(key: &ExtendedPublicKey, version: [u8; 4]) -> String {
+        let mut data = bs58::decode(key.to_string())
+            .with_check(None)
+            .into_vec()
+            .unwrap();
+        data[..4].copy_from_slice(&version);
+        bs58::encode(data).with_check().into_string()
+    }
+
+    #[test]
+    fn electrum_wallets() {
+        // The master public keys and addresses Electrum shows for these
+        // seeds, from its wallet tests.
+        let seed = ElectrumSeed::from_phrase(
+            "bitter grass shiver impose acquire brush forget axis eager alone wine silver",
+        )
+        .unwrap();
+        assert_eq!(seed.version(), SeedVersion::Segwit);
+        let master = ExtendedPrivateKey::new_master(seed.to_seed("")).unwrap();
+        let account = master.derive(&"m/0'".parse().unwrap()).unwrap();
+        assert_eq!(
+            with_version(&account.public_key(), [0x04, 0xb2, 0x47, 0x46]),
+            "zpub6nsHdRuY92FsMKdbn9BfjBCG6X8pyhCibNP6uDvpnw2cyrVhecvHRMa3Ne8kdJZxjxgwnpbHLkcR4bfnhHy6auHPJyDTQ3kianeuVLdkCYQ",
+        );
+
+        let seed = ElectrumSeed::from_phrase(
+            "cycle rocket west magnet parrot shuffle foot correct salt library feed song",
+        )
+        .unwrap();
+        assert_eq!(seed.version(), SeedVersion::Standard);
+        let master = ExtendedPrivateKey::new_master(seed.to_seed("")).unwrap();
+        assert_eq!(
+            master.public_key().to_string(),
+            "xpub661MyMwAqRbcFWohJWt7PHsFEJfZAvw9ZxwQoDa4SoMgsDDM1T7WK3u9E4edkC4ugRnZ8E4xDZRpk8Rnts3Nbt97dPwT52CwBdDWroaZf8U",
+        );
+
+        // The first receiving address is a P2PKH address.
+        let template = SeedVersion::Standard.derivation_template().unwrap();
+        let key = master
+            .derive(&Path::for_template(template, 0).unwrap())
+            .unwrap();
+        let mut data = vec![0x00];
+        data.extend_from_slice(&hash160(
+            key.public_key()
+                .public_key()
+                .to_encoded_point(true)
+                .as_bytes(),
+        ));
+        assert_eq!(
+            bs58::encode(data).with_check().into_string(),
+            "1NNkttn1YvVGdqBW4PR6zvc3Zx3H5owKRf",
+        );
+    }
+
+    #[test]
+    fn versions_and_normalization() {
+        let phrase = "since sick check reward swamp mind board moral cross bounce mutual equip";
+        assert_eq!(seed_version(phrase), Some(SeedVersion::Standard));
+        assert_eq!(
+            seed_version(&format!("  {}\n", phrase.to_uppercase())),
+            Some(SeedVersion::Standard),
+        );
+
+        let seed = ElectrumSeed::from_phrase(
+            "Wild Father   tree among universe such mobile favorite target dynamic credit identify",
+        )
+        .unwrap();
+        assert_eq!(seed.phrase(), SEGWIT);
+        assert_eq!(seed.to_seed("Caf\u{e9}"), seed.to_seed("cafe"));
+
+        assert!(ElectrumSeed::from_phrase(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+        )
+        .is_err());
+
+        assert_eq!(
+            normalize_text("Ünïcödé İSTANBUL Άλφα"),
+            "unicode istanbul αλφα"
+        );
+        assert_eq!(
+            normalize_text("\u{3042} \u{3044}  abc \u{3046}"),
+            "\u{3042}\u{3044} abc \u{3046}"
+        );
+    }
+}
@@ -0,0 +1,201 @@
+//! Module for collecting mnemonic entropy from physical sources such as dice
+//! and coins, for example during a cold storage ceremony.
+//!
+//! Dice rolls are converted to bits without bias: a roll of 1 to 4 yields two
+//! bits and a roll of 5 or 6 yields a single bit. Each branch is uniformly
+//! distributed, so every collected bit is uniform as long as the die is fair.
+
+use super::{
+    mnemonic::{mnemonic_to_byte_length, Mnemonic},
+    wordlist::Language,
+};
+use anyhow::{bail, ensure, Result};
+use std::ops::RangeInclusive;
+
+/// The physical source of the entropy.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EntropySource {
+    /// Rolls of a fair six-sided die.
+    Dice,
+    /// Flips of a fair coin.
+    CoinFlips,
+}
+
+/// Collects entropy bits from dice rolls or coin flips until there is enough
+/// entropy for a mnemonic of the requested length.
+#[derive(Clone, Debug)]
+pub struct EntropyCollector {
+    source: EntropySource,
+    bits: Vec<bool>,
+    target_bits: usize,
+}
+
+impl EntropyCollector {
+    /// Creates a collector for a mnemonic of `length` words (12, 15, 18, 21
+    /// or 24).
+    pub fn new(source: EntropySource, length: usize) -> Result<Self> {
+        ensure!(
+            matches!(length, 12 | 15 | 18 | 21 | 24),
+            "invalid mnemonic length {length}",
+        );
+        let target_bits = mnemonic_to_byte_length(length)? * 8;
+        Ok(Self {
+            source,
+            bits: Vec::with_capacity(target_bits + 1),
+            target_bits,
+        })
+    }
+
+    /// Creates a collector for dice rolls.
+    pub fn dice(length: usize) -> Result<Self> {
+        Self::new(EntropySource::Dice, length)
+    }
+
+    /// Creates a collector for coin flips.
+    pub fn coin_flips(length: usize) -> Result<Self> {
+        Self::new(EntropySource::CoinFlips, length)
+    }
+
+    pub fn source(&self) -> EntropySource {
+        self.source
+    }
+
+    /// Adds a die roll between 1 and 6.
+    pub fn push_roll(&mut self, roll: u8) -> Result<()> {
+        ensure!(
+            self.source == EntropySource::Dice,
+            "collector expects coin flips"
+        );
+        match roll {
+            1..=4 => {
+                let value = roll - 1;
+                self.push_bit(value & 0b10 != 0);
+                self.push_bit(value & 0b01 != 0);
+            }
+            5 | 6 => self.push_bit(roll == 6),
+            _ => bail!("invalid die roll {roll}"),
+        }
+        Ok(())
+    }
+
+    /// Adds a coin flip.
+    pub fn push_flip(&mut self, heads: bool) -> Result<()> {
+        ensure!(
+            self.source == EntropySource::CoinFlips,
+            "collector expects dice rolls"
+        );
+        self.push_bit(heads);
+        Ok(())
+    }
+
+    /// Adds rolls or flips from a string, such as `"3516"` for dice or
+    /// `"HTTH"` for coin flips (`1`/`0` are accepted as well). Whitespace is
+    /// ignored.
+    pub fn push_str(&mut self, input: &str) -> Result<()> {
+        for c in input.chars().filter(|c| !c.is_whitespace()) {
+            match (self.source, c) {
+                (EntropySource::Dice, '1'..='6') => self.push_roll(c as u8 - b'0')?,
+                (EntropySource::CoinFlips, 'H' | 'h' | '1') => self.push_flip(true)?,
+                (EntropySource::CoinFlips, 'T' | 't' | '0') => self.push_flip(false)?,
+                _ => bail!("invalid character '{c}' for {:?}", self.source),
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the number of entropy bits collected so far.
+    pub fn bits_collected(&self) -> usize {
+        self.bits.len().min(self.target_bits)
+    }
+
+    /// Returns the number of entropy bits still needed.
+    pub fn bits_needed(&self) -> usize {
+        self.target_bits - self.bits_collected()
+    }
+
+    /// Returns the range of rolls or flips still needed. A die roll yields
+    /// one or two bits, so the exact number of rolls is only known once the
+    /// collector is complete.
+    pub fn rolls_needed(&self) -> RangeInclusive<usize> {
+        let bits = self.bits_needed();
+        match self.source {
+            EntropySource::Dice => bits.div_ceil(2)..=bits,
+            EntropySource::CoinFlips => bits..=bits,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.bits_needed() == 0
+    }
+
+    /// Returns the collected entropy bytes.
+    pub fn entropy(&self) -> Result<Vec<u8>> {
+        ensure!(
+            self.is_complete(),
+            "{} more bits of entropy needed",
+            self.bits_needed(),
+        );
+        Ok(self.bits[..self.target_bits]
+            .chunks(8)
+            .map(|byte| byte.iter().fold(0, |acc, &bit| (acc << 1) | bit as u8))
+            .collect())
+    }
+
+    /// Creates the mnemonic from the collected entropy.
+    pub fn to_mnemonic(&self, language: Language) -> Result<Mnemonic> {
+        Mnemonic::from_entropy_in(self.entropy()?, language)
+    }
+
+    fn push_bit(&mut self, bit: bool) {
+        // NOTE: Bits beyond the target are dropped, which does not introduce
+        // any bias since every bit is independently uniform.
+        if self.bits.len() < self.target_bits {
+            self.bits.push(bit);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dice_and_coin_flips() {
+        let mut dice = EntropyCollector::dice(12).unwrap();
+        assert_eq!(dice.rolls_needed(), 64..=128);
+
+        // Rolls 1 to 4 map to the bit pairs 00, 01, 10 and 11, while 5 and 6
+        // map to the single bits 0 and 1.
+        dice.push_str("1 2 3 4 5 6").unwrap();
+        assert_eq!(dice.bits_collected(), 10);
+        assert_eq!(dice.rolls_needed(), 59..=118);
+        assert!(dice.push_roll(7).is_err());
+        assert!(dice.push_flip(true).is_err());
+        assert!(dice.to_mnemonic(Language::English).is_err());
+
+        let mut coins = EntropyCollector::coin_flips(12).unwrap();
+        coins.push_str("TTTH HTHH TH").unwrap();
+        assert!(coins.push_str("X").is_err());
+
+        while !dice.is_complete() {
+            dice.push_roll(4).unwrap();
+            coins.push_flip(true).unwrap();
+            coins.push_flip(true).unwrap();
+        }
+        assert!(coins.is_complete());
+        assert_eq!(coins.rolls_needed(), 0..=0);
+        assert_eq!(dice.entropy().unwrap(), coins.entropy().unwrap());
+        assert_eq!(dice.entropy().unwrap()[..2], [0b0001_1011, 0b0111_1111]);
+    }
+
+    #[test]
+    fn mnemonic_from_flips() {
+        let mut coins = EntropyCollector::coin_flips(24).unwrap();
+        assert_eq!(coins.bits_needed(), 256);
+        coins.push_str(&"H".repeat(300)).unwrap();
+        assert_eq!(
+            coins.to_mnemonic(Language::English).unwrap().to_phrase(),
+            "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+        );
+    }
+}
@@ -35,6 +35,7 @@ use unicode_normalization::UnicodeNormalization as _;
 pub struct Mnemonic {
     buffer: [u8; 64],
     entropy_len: usize,
+    language: Language,
 }
 
 /// Masking value for a mnemonic word.
@@ -44,6 +45,11 @@ const WORD_BITS: usize = WORD_MASK.count_ones() as _;
 
 impl Mnemonic {
     pub fn random(length: usize) -> Result<Self> {
+        Self::random_in(length, Language::default())
+    }
+
+    /// Creates a random mnemonic with words from the specified language.
+    pub fn random_in(length: usize, language: Language) -> Result<Self> {
         // the length of mnemonic can only be 12, 15, 18, 21, or 24
         assert!(length % 3 == 0);
         assert!(length >= 12 && length <= 24);
@@ -51,11 +57,41 @@ impl Mnemonic {
         // get entropy length given the mnemonic length. ie. 12 words = 128 bits
         let entropy_len = mnemonic_to_byte_length(length)?;
 
+        let mut entropy = [0; 32];
+        utils::fill_random_bytes(&mut entropy[..entropy_len])?;
+
+        Self::from_entropy_in(&entropy[..entropy_len], language)
+    }
+
+    /// Creates a mnemonic from raw entropy bytes. The entropy must be between
+    /// 16 and 32 bytes long, in 4 byte steps.
+    pub fn from_entropy(entropy: impl AsRef<[u8]>) -> Result<Self> {
+        Self::from_entropy_in(entropy, Language::default())
+    }
+
+    /// Creates a mnemonic from hex encoded entropy, with an optional `0x`
+    /// prefix.
+    pub fn from_hex(entropy: impl AsRef<str>) -> Result<Self> {
+        let entropy = entropy.as_ref();
+        let entropy = entropy.strip_prefix("0x").unwrap_or(entropy);
+        Self::from_entropy(hex::decode(entropy).context("invalid hex entropy")?)
+    }
+
+    /// Creates a mnemonic from raw entropy bytes with words from the
+    /// specified language.
+    pub fn from_entropy_in(entropy: impl AsRef<[u8]>, language: Language) -> Result<Self> {
+        let entropy = entropy.as_ref();
+        let entropy_len = entropy.len();
+        ensure!(
+            matches!(entropy_len, 16 | 20 | 24 | 28 | 32),
+            "invalid entropy length {entropy_len}",
+        );
+
         let buffer = {
             let mut buf = [0; 64];
             let (seed, hash) = buf.split_at_mut(entropy_len);
 
-            utils::fill_random_bytes(&mut *seed)?;
+            seed.copy_from_slice(entropy);
             hash[..32].copy_from_slice(&sha256(seed));
 
             buf
@@ -64,22 +100,68 @@ impl Mnemonic {
         Ok(Self {
             buffer,
             entropy_len,
+            language,
         })
     }
 
+    /// Parses a mnemonic phrase, detecting its language.
+    ///
+    /// Some words appear in more than one wordlist, so the first language
+    /// (in [`Language::ALL`] order) for which the checksum is valid is used.
     pub fn from_phrase(mnemonic: impl AsRef<str>) -> Result<Self> {
         let mut mnemonic = Cow::Borrowed(mnemonic.as_ref());
         normalize_utf8(&mut mnemonic);
+        let words = split_words(&mnemonic);
+
+        let candidates = Language::ALL
+            .into_iter()
+            .filter(|language| {
+                let wordlist = language.wordlist();
+                words.iter().all(|word| wordlist.expand(word).is_some())
+            })
+            .collect::<Vec<_>>();
+
+        if candidates.is_empty() {
+            // Report the unknown words against the language that knows most
+            // of the words in the phrase. `max_by_key` returns the last
+            // maximum, so iterate in reverse to prefer earlier languages.
+            let language = Language::ALL
+                .into_iter()
+                .rev()
+                .max_by_key(|language| {
+                    let wordlist = language.wordlist();
+                    words
+                        .iter()
+                        .filter(|word| wordlist.expand(word).is_some())
+                        .count()
+                })
+                .unwrap_or_default();
+            return Self::from_words(&words, language);
+        }
 
-        // split to words as a vector of normalized words
-        let words: Vec<&str> = mnemonic
-            .split_whitespace()
-            .filter(|word| !word.is_empty())
-            .collect();
+        let mut error = None;
+        for language in candidates {
+            match Self::from_words(&words, language) {
+                Ok(mnemonic) => return Ok(mnemonic),
+                Err(err) => {
+                    error.get_or_insert(err);
+                }
+            }
+        }
+        Err(error.unwrap())
+    }
+
+    /// Parses a mnemonic phrase with words from the specified language.
+    pub fn from_phrase_in(mnemonic: impl AsRef<str>, language: Language) -> Result<Self> {
+        let mut mnemonic = Cow::Borrowed(mnemonic.as_ref());
+        normalize_utf8(&mut mnemonic);
+        Self::from_words(&split_words(&mnemonic), language)
+    }
 
+    pub(super) fn from_words(words: &[&str], language: Language) -> Result<Self> {
         let len = mnemonic_to_byte_length(words.len())?;
         let buffer = {
-            let wordlist = Wordlist::new();
+            let wordlist = language.wordlist();
 
             let mut buf = [0; 64];
             let (seed, hash) = buf.split_at_mut(len);
@@ -87,10 +169,10 @@ impl Mnemonic {
             let mut acc = 0;
             let mut bit_offset = 0;
             let mut byte_offset = 0;
-            for word in &words {
+            for (position, word) in words.iter().enumerate() {
                 let index = wordlist
-                    .get_index(word)
-                    .with_context(|| format!("invalid BIP-0039 word '{word}'"))?;
+                    .expand(word)
+                    .with_context(|| invalid_word(wordlist, language, position, word))?;
                 acc = (acc << WORD_BITS) | index;
 
                 bit_offset += WORD_BITS;
@@ -120,17 +202,23 @@ impl Mnemonic {
         Ok(Self {
             buffer,
             entropy_len: len,
+            language,
         })
     }
 
+    /// Returns the language of the mnemonic words.
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
     pub fn get_length(&self) -> usize {
         ((self.entropy_len * 8) / WORD_BITS) + 1
     }
 
     /// Returns the BIP-0039 mnemonic phrase.
     pub fn to_phrase(&self) -> String {
-        let wordlist = Wordlist::new();
-        let separator = ' ';
+        let wordlist = self.language.wordlist();
+        let separator = self.language.separator();
 
         let mut buf = String::new();
         for i in 0..self.get_length() {
@@ -146,10 +234,10 @@ impl Mnemonic {
                 & WORD_MASK;
 
             buf.push_str(wordlist.get(index as _).unwrap());
-            buf.push(separator);
+            buf.push_str(separator);
         }
 
-        buf.pop();
+        buf.truncate(buf.len() - separator.len());
         buf
     }
 
@@ -162,8 +250,10 @@ impl Mnemonic {
         let mut seed = [0; PBKDF2_BYTES];
         let salt = format!("mnemonic{}", password.as_ref());
 
+        // NOTE: The phrase is hashed in its NFKD normalized form, which turns
+        // the ideographic space used by Japanese phrases into a plain space.
         pbkdf2::pbkdf2::<Hmac<Sha512>>(
-            self.to_phrase().as_bytes(),
+            self.to_phrase().nfkd().to_string().as_bytes(),
             salt.nfkd().to_string().as_bytes(),
             PBKDF2_ROUNDS,
             &mut seed,
@@ -199,7 +289,7 @@ impl FromStr for Mnemonic {
 
 /// A 64 byte seed derived from a BIP-0039 mnemonic.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Seed([u8; 64]);
+pub struct Seed(pub(super) [u8; 64]);
 
 impl AsRef<[u8]> for Seed {
     fn as_ref(&self) -> &[u8] {
@@ -232,14 +322,39 @@ impl Deref for Seed {
 // MS = (ENT + CS) / 11
 // ```
 // <https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki#generating-the-mnemonic>
-fn mnemonic_to_byte_length(len: usize) -> Result<usize> {
-    ensure!(matches!(len, 12..=24), "invalid mnemonic length {len}");
+pub(super) fn mnemonic_to_byte_length(len: usize) -> Result<usize> {
+    ensure!(
+        matches!(len, 12 | 15 | 18 | 21 | 24),
+        "invalid mnemonic length {len}"
+    );
 
     Ok((len * WORD_BITS * 32 / 33) / 8)
 }
 
+// Formats the error for an unknown word at a zero-based position, with
+// suggestions for likely typos.
+fn invalid_word(wordlist: &Wordlist, language: Language, position: usize, word: &str) -> String {
+    let mut message = format!(
+        "invalid BIP-0039 {language} word '{word}' at position {}",
+        position + 1,
+    );
+    let suggestions = wordlist.suggest(word, 3);
+    if !suggestions.is_empty() {
+        message.push_str(&format!(", did you mean '{}'?", suggestions.join("', '")));
+    }
+    message
+}
+
+// Splits a normalized mnemonic phrase into its words.
+pub(super) fn split_words(mnemonic: &str) -> Vec<&str> {
+    mnemonic
+        .split_whitespace()
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
 #[inline]
-fn normalize_utf8(s: &mut Cow<'_, str>) {
+pub(super) fn normalize_utf8(s: &mut Cow<'_, str>) {
     use unicode_normalization::{is_nfkd_quick, IsNormalized, UnicodeNormalization};
     if is_nfkd_quick(s.as_ref().chars()) != IsNormalized::Yes {
         *s = Cow::Owned(s.as_ref().nfkd().to_string())
@@ -265,6 +380,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn entropy_roundtrip() {
+        for &(entropy, phrase, _, _) in &TESTCASES {
+            let mnemonic = Mnemonic::from_entropy(hex::decode(entropy).unwrap()).unwrap();
+            assert_eq!(mnemonic.to_phrase(), phrase);
+        }
+        assert!(Mnemonic::from_entropy([0; 17]).is_err());
+
+        let mnemonic = Mnemonic::from_hex("0x7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f").unwrap();
+        assert_eq!(mnemonic.as_bytes(), [0x7f; 16]);
+        assert!(Mnemonic::from_hex("7f7f").is_err());
+        assert!(Mnemonic::from_hex("zz").is_err());
+    }
+
+    #[test]
+    fn seed_normalization() {
+        use unicode_normalization::UnicodeNormalization as _;
+
+        // The first word is "académie".
+        let mut entropy = [0; 16];
+        entropy[..2].copy_from_slice(&[0x02, 0x40]);
+        let mnemonic = Mnemonic::from_entropy_in(entropy, Language::French).unwrap();
+        let phrase = mnemonic.to_phrase();
+        let (composed, decomposed) = (phrase.nfc().to_string(), phrase.nfd().to_string());
+        assert_ne!(composed, decomposed);
+
+        let seed = mnemonic.to_seed("caf\u{e9}");
+        for phrase in [composed, decomposed] {
+            let mnemonic = Mnemonic::from_phrase_in(&phrase, Language::French).unwrap();
+            assert_eq!(mnemonic.to_seed("cafe\u{301}").as_ref(), seed.as_ref());
+        }
+        assert_ne!(mnemonic.to_seed("cafe").as_ref(), seed.as_ref());
+    }
+
     // (entropy, phrase, seed, extended_private_key)
     const TESTCASES: [(&str, &str, &str, &str); 26] = [
         (
@@ -436,5 +585,286 @@ mod tests {
             assert_eq!(hex::encode(mnemonic.to_seed("TREZOR")), seed);
             assert_eq!(mnemonic.as_bytes(), expected_entropy);
         }
+
+        for length in [11, 13, 14, 25] {
+            let phrase = vec!["abandon"; length].join(" ");
+            let err = Mnemonic::from_phrase(phrase).err().unwrap();
+            assert_eq!(err.to_string(), format!("invalid mnemonic length {length}"));
+        }
+    }
+
+    // (entropy, phrase, seed) with the passphrase "㍍ガバヴァぱばぐゞちぢ十人十色"
+    const JAPANESE_TESTCASES: [(&str, &str, &str); 24] = [
+        (
+            "00000000000000000000000000000000",
+            "あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あおぞら",
+            "a262d6fb6122ecf45be09c50492b31f92e9beb7d9a845987a02cefda57a15f9c467a17872029a9e92299b5cbdf306e3a0ee620245cbd508959b6cb7ca637bd55",
+        ),
+        (
+            "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+            "そつう　れきだい　ほんやく　わかす　りくつ　ばいか　ろせん　やちん　そつう　れきだい　ほんやく　わかめ",
+            "aee025cbe6ca256862f889e48110a6a382365142f7d16f2b9545285b3af64e542143a577e9c144e101a6bdca18f8d97ec3366ebf5b088b1c1af9bc31346e60d9",
+        ),
+        (
+            "80808080808080808080808080808080",
+            "そとづら　あまど　おおう　あこがれる　いくぶん　けいけん　あたえる　いよく　そとづら　あまど　おおう　あかちゃん",
+            "e51736736ebdf77eda23fa17e31475fa1d9509c78f1deb6b4aacfbd760a7e2ad769c714352c95143b5c1241985bcb407df36d64e75dd5a2b78ca5d2ba82a3544",
+        ),
+        (
+            "ffffffffffffffffffffffffffffffff",
+            "われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　ろんぶん",
+            "4cd2ef49b479af5e1efbbd1e0bdc117f6a29b1010211df4f78e2ed40082865793e57949236c43b9fe591ec70e5bb4298b8b71dc4b267bb96ed4ed282c8f7761c",
+        ),
+        (
+            "000000000000000000000000000000000000000000000000",
+            "あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あらいぐま",
+            "d99e8f1ce2d4288d30b9c815ae981edd923c01aa4ffdc5dee1ab5fe0d4a3e13966023324d119105aff266dac32e5cd11431eeca23bbd7202ff423f30d6776d69",
+        ),
+        (
+            "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+            "そつう　れきだい　ほんやく　わかす　りくつ　ばいか　ろせん　やちん　そつう　れきだい　ほんやく　わかす　りくつ　ばいか　ろせん　やちん　そつう　れいぎ",
+            "eaaf171efa5de4838c758a93d6c86d2677d4ccda4a064a7136344e975f91fe61340ec8a615464b461d67baaf12b62ab5e742f944c7bd4ab6c341fbafba435716",
+        ),
+        (
+            "808080808080808080808080808080808080808080808080",
+            "そとづら　あまど　おおう　あこがれる　いくぶん　けいけん　あたえる　いよく　そとづら　あまど　おおう　あこがれる　いくぶん　けいけん　あたえる　いよく　そとづら　いきなり",
+            "aec0f8d3167a10683374c222e6e632f2940c0826587ea0a73ac5d0493b6a632590179a6538287641a9fc9df8e6f24e01bf1be548e1f74fd7407ccd72ecebe425",
+        ),
+        (
+            "ffffffffffffffffffffffffffffffffffffffffffffffff",
+            "われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　りんご",
+            "f0f738128a65b8d1854d68de50ed97ac1831fc3a978c569e415bbcb431a6a671d4377e3b56abd518daa861676c4da75a19ccb41e00c37d086941e471a4374b95",
+        ),
+        (
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　あいこくしん　いってい",
+            "23f500eec4a563bf90cfda87b3e590b211b959985c555d17e88f46f7183590cd5793458b094a4dccc8f05807ec7bd2d19ce269e20568936a751f6f1ec7c14ddd",
+        ),
+        (
+            "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+            "そつう　れきだい　ほんやく　わかす　りくつ　ばいか　ろせん　やちん　そつう　れきだい　ほんやく　わかす　りくつ　ばいか　ろせん　やちん　そつう　れきだい　ほんやく　わかす　りくつ　ばいか　ろせん　まんきつ",
+            "cd354a40aa2e241e8f306b3b752781b70dfd1c69190e510bc1297a9c5738e833bcdc179e81707d57263fb7564466f73d30bf979725ff783fb3eb4baa86560b05",
+        ),
+        (
+            "8080808080808080808080808080808080808080808080808080808080808080",
+            "そとづら　あまど　おおう　あこがれる　いくぶん　けいけん　あたえる　いよく　そとづら　あまど　おおう　あこがれる　いくぶん　けいけん　あたえる　いよく　そとづら　あまど　おおう　あこがれる　いくぶん　けいけん　あたえる　うめる",
+            "6b7cd1b2cdfeeef8615077cadd6a0625f417f287652991c80206dbd82db17bf317d5c50a80bd9edd836b39daa1b6973359944c46d3fcc0129198dc7dc5cd0e68",
+        ),
+        (
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　われる　らいう",
+            "a44ba7054ac2f9226929d56505a51e13acdaa8a9097923ca07ea465c4c7e294c038f3f4e7e4b373726ba0057191aced6e48ac8d183f3a11569c426f0de414623",
+        ),
+        (
+            "77c2b00716cec7213839159e404db50d",
+            "せまい　うちがわ　あずき　かろう　めずらしい　だんち　ますく　おさめる　ていぼう　あたる　すあな　えしゃく",
+            "344cef9efc37d0cb36d89def03d09144dd51167923487eec42c487f7428908546fa31a3c26b7391a2b3afe7db81b9f8c5007336b58e269ea0bd10749a87e0193",
+        ),
+        (
+            "b63a9c59a6e641f288ebc103017f1da9f8290b3da6bdef7b",
+            "ぬすむ　ふっかつ　うどん　こうりつ　しつじ　りょうり　おたがい　せもたれ　あつめる　いちりゅう　はんしゃ　ごますり　そんけい　たいちょう　らしんばん　ぶんせき　やすみ　ほいく",
+            "b14e7d35904cb8569af0d6a016cee7066335a21c1c67891b01b83033cadb3e8a034a726e3909139ecd8b2eb9e9b05245684558f329b38480e262c1d6bc20ecc4",
+        ),
+        (
+            "3e141609b97933b66a060dcddc71fad1d91677db872031e85f4c015c5e7e8982",
+            "くのう　てぬぐい　そんかい　すろっと　ちきゅう　ほあん　とさか　はくしゅ　ひびく　みえる　そざい　てんすう　たんぴん　くしょう　すいようび　みけん　きさらぎ　げざん　ふくざつ　あつかう　はやい　くろう　おやゆび　こすう",
+            "32e78dce2aff5db25aa7a4a32b493b5d10b4089923f3320c8b287a77e512455443298351beb3f7eb2390c4662a2e566eec5217e1a37467af43b46668d515e41b",
+        ),
+        (
+            "0460ef47585604c5660618db2e6a7e7f",
+            "あみもの　いきおい　ふいうち　にげる　ざんしょ　じかん　ついか　はたん　ほあん　すんぽう　てちがい　わかめ",
+            "0acf902cd391e30f3f5cb0605d72a4c849342f62bd6a360298c7013d714d7e58ddf9c7fdf141d0949f17a2c9c37ced1d8cb2edabab97c4199b142c829850154b",
+        ),
+        (
+            "72f60ebac5dd8add8d2a25a797102c3ce21bc029c200076f",
+            "すろっと　にくしみ　なやむ　たとえる　へいこう　すくう　きない　けってい　とくべつ　ねっしん　いたみ　せんせい　おくりがな　まかい　とくい　けあな　いきおい　そそぐ",
+            "9869e220bec09b6f0c0011f46e1f9032b269f096344028f5006a6e69ea5b0b8afabbb6944a23e11ebd021f182dd056d96e4e3657df241ca40babda532d364f73",
+        ),
+        (
+            "2c85efc7f24ee4573d2b81a6ec66cee209b2dcbd09d8eddc51e0215b0b68e416",
+            "かほご　きうい　ゆたか　みすえる　もらう　がっこう　よそう　ずっと　ときどき　したうけ　にんか　はっこう　つみき　すうじつ　よけい　くげん　もくてき　まわり　せめる　げざい　にげる　にんたい　たんそく　ほそく",
+            "713b7e70c9fbc18c831bfd1f03302422822c3727a93a5efb9659bec6ad8d6f2c1b5c8ed8b0b77775feaf606e9d1cc0a84ac416a85514ad59f5541ff5e0382481",
+        ),
+        (
+            "eaebabb2383351fd31d703840b32e9e2",
+            "めいえん　さのう　めだつ　すてる　きぬごし　ろんぱ　はんこ　まける　たいおう　さかいし　ねんいり　はぶらし",
+            "06e1d5289a97bcc95cb4a6360719131a786aba057d8efd603a547bd254261c2a97fcd3e8a4e766d5416437e956b388336d36c7ad2dba4ee6796f0249b10ee961",
+        ),
+        (
+            "7ac45cfe7722ee6c7ba84fbc2d5bd61b45cb2fe5eb65aa78",
+            "せんぱい　おしえる　ぐんかん　もらう　きあい　きぼう　やおや　いせえび　のいず　じゅしん　よゆう　きみつ　さといも　ちんもく　ちわわ　しんせいじ　とめる　はちみつ",
+            "1fef28785d08cbf41d7a20a3a6891043395779ed74503a5652760ee8c24dfe60972105ee71d5168071a35ab7b5bd2f8831f75488078a90f0926c8e9171b2bc4a",
+        ),
+        (
+            "4fa1a8bc3e6d80ee1316050e862c1812031493212b7ec3f3bb1b08f168cabeef",
+            "こころ　いどう　きあつ　そうがんきょう　へいあん　せつりつ　ごうせい　はいち　いびき　きこく　あんい　おちつく　きこえる　けんとう　たいこ　すすめる　はっけん　ていど　はんおん　いんさつ　うなぎ　しねま　れいぼう　みつかる",
+            "43de99b502e152d4c198542624511db3007c8f8f126a30818e856b2d8a20400d29e7a7e3fdd21f909e23be5e3c8d9aee3a739b0b65041ff0b8637276703f65c2",
+        ),
+        (
+            "18ab19a9f54a9274f03e5209a2ac8a91",
+            "うりきれ　さいせい　じゆう　むろん　とどける　ぐうたら　はいれつ　ひけつ　いずれ　うちあわせ　おさめる　おたく",
+            "3d711f075ee44d8b535bb4561ad76d7d5350ea0b1f5d2eac054e869ff7963cdce9581097a477d697a2a9433a0c6884bea10a2193647677977c9820dd0921cbde",
+        ),
+        (
+            "18a2e1d81b8ecfb2a333adcb0c17a5b9eb76cc5d05db91a4",
+            "うりきれ　うねる　せっさたくま　きもち　めんきょ　へいたく　たまご　ぜっく　びじゅつかん　さんそ　むせる　せいじ　ねくたい　しはらい　せおう　ねんど　たんまつ　がいけん",
+            "753ec9e333e616e9471482b4b70a18d413241f1e335c65cd7996f32b66cf95546612c51dcf12ead6f805f9ee3d965846b894ae99b24204954be80810d292fcdd",
+        ),
+        (
+            "15da872c95a13dd738fbf50e427583ad61f18fd99f628c417a61cf8343c90419",
+            "うちゅう　ふそく　ひしょ　がちょう　うけもつ　めいそう　みかん　そざい　いばる　うけとる　さんま　さこつ　おうさま　ぱんつ　しひょう　めした　たはつ　いちぶ　つうじょう　てさぎょう　きつね　みすえる　いりぐち　かめれおん",
+            "346b7321d8c04f6f37b49fdf062a2fddc8e1bf8f1d33171b65074531ec546d1d3469974beccb1a09263440fc92e1042580a557fdce314e27ee4eabb25fa5e5fe",
+        ),
+    ];
+
+    #[test]
+    fn japanese_vectors() {
+        const PASSPHRASE: &str = "㍍ガバヴァぱばぐゞちぢ十人十色";
+
+        for &(entropy, phrase, seed) in &JAPANESE_TESTCASES {
+            let entropy = hex::decode(entropy).unwrap();
+            let mnemonic = Mnemonic::from_entropy_in(&entropy, Language::Japanese).unwrap();
+            assert_eq!(hex::encode(mnemonic.to_seed(PASSPHRASE)), seed);
+            assert_eq!(
+                mnemonic.to_phrase(),
+                phrase.nfkd().collect::<String>().replace(' ', "\u{3000}"),
+            );
+
+            let parsed = Mnemonic::from_phrase(phrase).unwrap();
+            assert_eq!(parsed.language(), Language::Japanese);
+            assert_eq!(parsed.as_bytes(), entropy);
+            assert_eq!(hex::encode(parsed.to_seed(PASSPHRASE)), seed);
+        }
+    }
+
+    const LANGUAGE_TESTCASES: [(Language, &str, &str, &str); 8] = [
+        (
+            Language::Spanish,
+            "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+            "ligero vista talar yogur venta queso yacer trozo ligero vista talar zafiro",
+            "1580aa5d5d67057b3a0a12253c283b93921851555529d0bbe9634349d641029216f791ddce3527819d44d833a0df3500b15fd8ba4cae7ca24e1464b9167de633",
+        ),
+        (
+            Language::French,
+            "80808080808080808080808080808080",
+            "indexer acompte bolide abrasif agréable dédale abusif appuyer indexer acompte bolide abolir",
+            "0c1ece83a464688d74744723d609e30e191d05ab8c082cf34bb2405bc4363dbcf6a9f83707b577d230728b3943920f876ec844e86dd0d117152c23802d25be3f",
+        ),
+        (
+            Language::Italian,
+            "ffffffffffffffffffffffffffffffff",
+            "zuppa zuppa zuppa zuppa zuppa zuppa zuppa zuppa zuppa zuppa zuppa zerbino",
+            "24182cf43f956410b5def9df90e3db0d6f3199c2ebd26e7ddef888ee3bece9101d132e449bb9e1c23dd9ccc6131d2f649c021ee591e88cef8d17cb434ef69efb",
+        ),
+        (
+            Language::Czech,
+            "9e885d952ad362caeb4efe34a8e91bd2",
+            "pokoj jogurt malovat kroupa holub malvice rachot uznat hnout kasa karamel potupa",
+            "f3922b8086d559436ba2d04bc2aae4174e6504d7d4d451f7282d0b41a1b8cc958b45a896985e0b9316ad09c62f7d62dac85bc3d3e2e2423bcad3336412fd33f8",
+        ),
+        (
+            Language::Portuguese,
+            "c0ba5a8e914111210f2bd131f3d5e08d",
+            "pote ringue muda benzer andaime levitar comando guloso carreira micro grilo atracar",
+            "556b77fc49cd57f7c9c92fafedac1c8341598666721b874db50da261c7cded491c22ecd3235e508822507212698f645bb198f0bb1aecd50d22339e77619a765e",
+        ),
+        (
+            Language::Korean,
+            "23db8160a31d3e97ca3688e7d9d2fac8",
+            "나들이 침대 분야 바이러스 첫날 방향 논문 창고 편의 찌꺼기 정오 연휴",
+            "fa7c7c0c8bece595867bbd5612d6f3a5e9a40d458081c34f2b6045b2a8a1c33c6dfe72c5c21baa2dc18f8326e212c59ba2c9a4d3bfc090afd549c26a1bad06a5",
+        ),
+        (
+            Language::ChineseSimplified,
+            "f30f8c1da665478f49b001d94c5fc452",
+            "昏 途 所 够 请 乃 风 一 雕 缺 垫 阀",
+            "aa7e38f64810007db63e31c479b9848cd5ffda839546749669bf53476dd036a33fd77d0a13d4418fb536ea78b028fc19533db4bc9e0e12a14a9432cb9fd112a2",
+        ),
+        (
+            Language::ChineseTraditional,
+            "0c1e24e5917779d297e14d45f14e1a1a",
+            "點 擋 眼 器 哥 舒 久 示 止 累 夏 便",
+            "3a59f75b80f1f6100fe40d8351bd1e21d9b9dbe402cce003e3d9c3705692025880fb9557a100b9f59508a6d1b7819698c0cfd267441767c06355c986ed0229e9",
+        ),
+    ];
+
+    #[test]
+    fn language_vectors() {
+        // Entropy from the English test vectors with the passphrase "TREZOR".
+        for &(language, entropy, phrase, seed) in &LANGUAGE_TESTCASES {
+            let entropy = hex::decode(entropy).unwrap();
+            let mnemonic = Mnemonic::from_entropy_in(&entropy, language).unwrap();
+            assert_eq!(
+                mnemonic.to_phrase(),
+                phrase.nfkd().collect::<String>(),
+                "{language}"
+            );
+            assert_eq!(hex::encode(mnemonic.to_seed("TREZOR")), seed, "{language}");
+
+            let parsed = Mnemonic::from_phrase(phrase).unwrap();
+            assert_eq!(parsed.language(), language);
+            assert_eq!(parsed.as_bytes(), entropy);
+        }
+    }
+
+    #[test]
+    fn language_detection() {
+        for language in Language::ALL {
+            let wordlist = language.wordlist();
+            let mnemonic = Mnemonic::from_entropy_in([0; 16], language).unwrap();
+
+            // All zero entropy encodes to the first word 11 times, followed
+            // by the word carrying the checksum bits.
+            let mut words = vec![wordlist.get(0).unwrap(); 11];
+            words.push(wordlist.get(3).unwrap());
+            assert_eq!(mnemonic.to_phrase(), words.join(language.separator()));
+
+            for _ in 0..4 {
+                let mnemonic = Mnemonic::random_in(24, language).unwrap();
+                let parsed = Mnemonic::from_phrase(mnemonic.to_phrase()).unwrap();
+                assert_eq!(parsed.language(), language);
+                assert_eq!(parsed.as_bytes(), mnemonic.as_bytes());
+            }
+        }
+
+        // Words shared by several wordlists resolve to a language with a
+        // valid checksum.
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        assert_eq!(
+            Mnemonic::from_phrase(phrase).unwrap().language(),
+            Language::English,
+        );
+        assert!(Mnemonic::from_phrase_in(phrase, Language::French).is_err());
+
+        let err = Mnemonic::from_phrase(
+            "ábaco ábaco ábaco ábaco ábaco ábaco ábaco ábaco ábaco ábaco ábaco xxxxxxxx",
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "invalid BIP-0039 Spanish word 'xxxxxxxx' at position 12",
+        );
+    }
+
+    #[test]
+    fn abbreviated_and_mistyped_words() {
+        let mnemonic =
+            Mnemonic::from_phrase("lega winn than year wave saus wort usef lega winn than yell")
+                .unwrap();
+        assert_eq!(
+            mnemonic.to_phrase(),
+            "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        );
+
+        let err = Mnemonic::from_phrase(
+            "legal winner thank year wave sausage wroth useful legal winner thank yellow",
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "invalid BIP-0039 English word 'wroth' at position 7, did you mean 'worth'?",
+        );
     }
 }
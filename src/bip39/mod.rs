@@ -1,2 +1,7 @@
+pub mod brainwallet;
+pub mod electrum;
+pub mod entropy;
 pub mod mnemonic;
-mod wordlist;
+pub mod recovery;
+pub mod slip39;
+pub mod wordlist;
@@ -0,0 +1,253 @@
+//! Module for recovering a mnemonic with a single missing or wrong word.
+//!
+//! Every word of a mnemonic carries 11 bits, but only a few checksum bits
+//! constrain it, so one unknown word leaves many valid candidates: for 12
+//! words about 1 in 16 guesses passes the checksum. Candidates can be
+//! narrowed down further with a known address derived from the wallet.
+
+use super::{
+    mnemonic::{mnemonic_to_byte_length, normalize_utf8, split_words, Mnemonic},
+    wordlist::{Language, WORD_COUNT},
+};
+use crate::address::Address;
+use crate::hdwallet::HDWallet;
+use anyhow::{ensure, Context as _, Result};
+use std::{borrow::Cow, collections::HashSet};
+
+/// The placeholder marking an unknown word in a phrase.
+pub const PLACEHOLDER: &str = "?";
+
+/// Returns every valid mnemonic for a phrase with one missing word.
+///
+/// The missing word is either marked with [`PLACEHOLDER`], or left out
+/// entirely in which case every position is tried. The language is detected
+/// from the known words unless specified.
+pub fn missing_word(phrase: &str, language: Option<Language>) -> Result<Vec<Mnemonic>> {
+    let mut phrase = Cow::Borrowed(phrase);
+    normalize_utf8(&mut phrase);
+    let words = split_words(&phrase);
+
+    let placeholders = words.iter().filter(|word| **word == PLACEHOLDER).count();
+    ensure!(placeholders <= 1, "only one word can be unknown");
+    let known = words.len() - placeholders;
+    mnemonic_to_byte_length(known + 1)
+        .with_context(|| format!("a phrase of {known} known words is not missing one word"))?;
+
+    let mut candidates = Vec::new();
+    for language in languages(&words, language, 0)? {
+        if placeholders == 1 {
+            let position = words.iter().position(|word| *word == PLACEHOLDER).unwrap();
+            try_position(&words, position, language, None, &mut candidates);
+        } else {
+            for position in 0..=words.len() {
+                let mut words = words.clone();
+                words.insert(position, PLACEHOLDER);
+                try_position(&words, position, language, None, &mut candidates);
+            }
+        }
+    }
+
+    // NOTE: Leaving out one of two equal adjacent words gives the same
+    // phrase for both positions.
+    let mut seen = HashSet::new();
+    candidates.retain(|mnemonic| seen.insert((mnemonic.language(), mnemonic.as_bytes().to_vec())));
+    Ok(candidates)
+}
+
+/// Returns every valid mnemonic that differs from the phrase in exactly one
+/// word. The zero-based `position` of the suspected word can be specified,
+/// otherwise every position is tried. The suspected word does not need to
+/// be in a wordlist; without a position, a single word that is in no
+/// wordlist is taken to be the wrong one.
+pub fn wrong_word(
+    phrase: &str,
+    position: Option<usize>,
+    language: Option<Language>,
+) -> Result<Vec<Mnemonic>> {
+    let mut phrase = Cow::Borrowed(phrase);
+    normalize_utf8(&mut phrase);
+    let mut words = split_words(&phrase);
+    mnemonic_to_byte_length(words.len())?;
+    if let Some(position) = position {
+        ensure!(position < words.len(), "invalid word position {position}");
+    }
+
+    let mut known = words.clone();
+    if let Some(position) = position {
+        known[position] = PLACEHOLDER;
+    }
+
+    let mut candidates = Vec::new();
+    for language in languages(&known, language, usize::from(position.is_none()))? {
+        let wordlist = language.wordlist();
+        let unknown = unknown_words(&known, language);
+        let positions = match (position, unknown.as_slice()) {
+            (Some(position), _) | (None, &[position]) => position..position + 1,
+            (None, _) => 0..words.len(),
+        };
+        for position in positions {
+            let original = wordlist.expand(words[position]);
+            let word = std::mem::replace(&mut words[position], PLACEHOLDER);
+            try_position(&words, position, language, original, &mut candidates);
+            words[position] = word;
+        }
+    }
+    Ok(candidates)
+}
+
+/// Keeps the candidates for which one of the first `accounts` addresses,
+/// derived with the default HD path template, is the specified address.
+pub fn filter_by_address(
+    candidates: Vec<Mnemonic>,
+    passphrase: Option<&str>,
+    address: &Address,
+    accounts: usize,
+) -> Result<Vec<Mnemonic>> {
+    let mut matches = Vec::new();
+    for mnemonic in candidates {
+        let mut hdwallet = HDWallet::new_from_mnemonic(&mnemonic, passphrase.map(str::to_owned))?;
+        if hdwallet.add_accounts(accounts)?.contains(address) {
+            matches.push(mnemonic);
+        }
+    }
+    Ok(matches)
+}
+
+// Returns the languages to search, the ones in which at most `max_unknown`
+// words other than the placeholder are not in the wordlist.
+fn languages(
+    words: &[&str],
+    language: Option<Language>,
+    max_unknown: usize,
+) -> Result<Vec<Language>> {
+    let languages = match language {
+        Some(language) => vec![language],
+        None => Language::ALL.to_vec(),
+    };
+    let languages = languages
+        .into_iter()
+        .filter(|language| unknown_words(words, *language).len() <= max_unknown)
+        .collect::<Vec<_>>();
+    ensure!(
+        !languages.is_empty(),
+        "phrase contains words from no single BIP-0039 wordlist"
+    );
+    Ok(languages)
+}
+
+// Returns the positions of the words that are not in the language's wordlist,
+// ignoring the placeholder.
+fn unknown_words(words: &[&str], language: Language) -> Vec<usize> {
+    let wordlist = language.wordlist();
+    (0..words.len())
+        .filter(|i| words[*i] != PLACEHOLDER && wordlist.expand(words[*i]).is_none())
+        .collect()
+}
+
+// Tries every word at the placeholder position, skipping the word at index
+// `skip`, and collects the mnemonics with a valid checksum.
+fn try_position(
+    words: &[&str],
+    position: usize,
+    language: Language,
+    skip: Option<usize>,
+    candidates: &mut Vec<Mnemonic>,
+) {
+    let wordlist = language.wordlist();
+    let mut words = words.to_vec();
+    for index in (0..WORD_COUNT).filter(|index| Some(*index) != skip) {
+        words[position] = wordlist.get(index).unwrap();
+        if let Ok(mnemonic) = Mnemonic::from_words(&words, language) {
+            candidates.push(mnemonic);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE: &str =
+        "legal winner thank year wave sausage worth useful legal winner thank yellow";
+
+    fn phrases(candidates: &[Mnemonic]) -> Vec<String> {
+        candidates.iter().map(Mnemonic::to_phrase).collect()
+    }
+
+    #[test]
+    fn missing_words() {
+        // The last word has 7 free bits and a 4 bit checksum.
+        let candidates = missing_word(
+            "legal winner thank year wave sausage worth useful legal winner thank ?",
+            None,
+        )
+        .unwrap();
+        assert_eq!(candidates.len(), 128);
+        assert!(phrases(&candidates).contains(&PHRASE.to_owned()));
+
+        let candidates = missing_word(
+            "legal winner thank year wave sausage useful legal winner thank yellow",
+            None,
+        )
+        .unwrap();
+        assert!(phrases(&candidates).contains(&PHRASE.to_owned()));
+        assert!(candidates
+            .iter()
+            .all(|mnemonic| mnemonic.get_length() == 12));
+
+        assert!(missing_word("legal ? thank ? wave", None).is_err());
+
+        // A complete phrase has no 13 word completions.
+        assert!(missing_word(PHRASE, None).is_err());
+        assert!(missing_word(&format!("{PHRASE} ?"), None).is_err());
+        assert!(missing_word(&format!("{PHRASE} legal"), None).is_err());
+
+        let candidates = missing_word(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            None,
+        )
+        .unwrap();
+        let mut unique = phrases(&candidates);
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), candidates.len());
+    }
+
+    #[test]
+    fn wrong_word_with_address() {
+        let expected = HDWallet::new_from_mnemonic_phrase(PHRASE, None)
+            .unwrap()
+            .add_accounts(1)
+            .unwrap()[0];
+
+        let phrase = "legal winner thank year vote sausage worth useful legal winner thank yellow";
+        assert!(Mnemonic::from_phrase(phrase).is_err());
+
+        let candidates = wrong_word(phrase, Some(4), None).unwrap();
+        assert!(phrases(&candidates).contains(&PHRASE.to_owned()));
+        assert!(!phrases(&candidates).contains(&phrase.to_owned()));
+
+        let candidates = filter_by_address(candidates, None, &expected, 1).unwrap();
+        assert_eq!(phrases(&candidates), [PHRASE]);
+
+        let candidates = wrong_word(phrase, None, Some(Language::English)).unwrap();
+        assert!(phrases(&candidates).contains(&PHRASE.to_owned()));
+    }
+
+    #[test]
+    fn wrong_word_not_in_wordlist() {
+        let phrase = "legal winner thank year xyzzy sausage worth useful legal winner thank yellow";
+        for position in [Some(4), None] {
+            let candidates = wrong_word(phrase, position, None).unwrap();
+            assert!(phrases(&candidates).contains(&PHRASE.to_owned()));
+            assert!(candidates
+                .iter()
+                .all(|mnemonic| mnemonic.to_phrase().split(' ').nth(5) == Some("sausage")));
+        }
+
+        // Only one word may be unknown.
+        let phrase = "legal winner thank year xyzzy sausage worth useful legal plugh thank yellow";
+        assert!(wrong_word(phrase, None, None).is_err());
+        assert!(wrong_word(phrase, Some(4), None).is_err());
+    }
+}
@@ -0,0 +1,858 @@
+//! Module implementing SLIP-0039, Shamir's secret sharing for mnemonic codes.
+//!
+//! A master secret is encrypted with a passphrase and split into groups, of
+//! which `group_threshold` are needed to recover it. Each group is in turn
+//! split into member shares with their own threshold. Every share is encoded
+//! as a mnemonic of 20 or more words from the SLIP-0039 wordlist.
+//!
+//! The recovered master secret is used directly as the BIP-0032 seed, for
+//! example with [`crate::bip32::hdk::derive`].
+//!
+//! <https://github.com/satoshilabs/slips/blob/master/slip-0039.md>
+
+mod shamir;
+mod wordlist;
+
+use crate::utils;
+use anyhow::{bail, ensure, Context as _, Result};
+use hmac::Hmac;
+use sha2::Sha256;
+use std::collections::BTreeMap;
+
+/// The number of bits represented by each word.
+const RADIX_BITS: usize = 10;
+/// The number of words in the share header: identifier, extendable flag,
+/// iteration exponent, group and member parameters.
+const HEADER_WORDS: usize = 4;
+/// The number of checksum words.
+const CHECKSUM_WORDS: usize = 3;
+/// The minimum length of a master secret in bytes.
+const MIN_SECRET_LEN: usize = 16;
+/// The minimum number of words of a share mnemonic.
+const MIN_MNEMONIC_WORDS: usize =
+    HEADER_WORDS + (MIN_SECRET_LEN * 8).div_ceil(RADIX_BITS) + CHECKSUM_WORDS;
+/// The maximum number of shares or groups.
+const MAX_SHARES: u8 = 16;
+/// The number of Feistel rounds of the master secret encryption.
+const ROUND_COUNT: u8 = 4;
+/// The total number of PBKDF2 iterations for iteration exponent 0.
+const BASE_ITERATION_COUNT: u32 = 10_000;
+
+/// The parameters of a group of member shares.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Group {
+    /// The number of member shares needed to recover the group secret.
+    pub threshold: u8,
+    /// The number of member shares.
+    pub count: u8,
+}
+
+/// A single SLIP-0039 share.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Share {
+    identifier: u16,
+    extendable: bool,
+    iteration_exponent: u8,
+    group_index: u8,
+    group_threshold: u8,
+    group_count: u8,
+    member_index: u8,
+    member_threshold: u8,
+    value: Vec<u8>,
+}
+
+impl Share {
+    /// Parses a share mnemonic and verifies its checksum.
+    pub fn from_phrase(phrase: impl AsRef<str>) -> Result<Self> {
+        let phrase = phrase.as_ref().to_lowercase();
+        let indices = phrase
+            .split_whitespace()
+            .enumerate()
+            .map(|(position, word)| {
+                wordlist::get_index(word)
+                    .map(|index| index as u16)
+                    .with_context(|| {
+                        format!(
+                            "invalid SLIP-0039 word '{word}' at position {}",
+                            position + 1
+                        )
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        ensure!(
+            indices.len() >= MIN_MNEMONIC_WORDS,
+            "invalid share length {}",
+            indices.len(),
+        );
+
+        let header = indices[..HEADER_WORDS]
+            .iter()
+            .fold(0_u64, |acc, &index| (acc << RADIX_BITS) | index as u64);
+        let extendable = (header >> 24) & 1 == 1;
+        ensure!(
+            rs1024_polymod(customization(extendable), &indices) == 1,
+            "invalid share checksum",
+        );
+
+        let field = |shift: u32| ((header >> shift) & 0xf) as u8;
+        let share = Self {
+            identifier: (header >> 25) as u16,
+            extendable,
+            iteration_exponent: field(20),
+            group_index: field(16),
+            group_threshold: field(12) + 1,
+            group_count: field(8) + 1,
+            member_index: field(4),
+            member_threshold: field(0) + 1,
+            value: words_to_value(&indices[HEADER_WORDS..indices.len() - CHECKSUM_WORDS])?,
+        };
+        ensure!(
+            share.group_threshold <= share.group_count,
+            "group threshold {} exceeds group count {}",
+            share.group_threshold,
+            share.group_count,
+        );
+        Ok(share)
+    }
+
+    /// Returns the share mnemonic.
+    pub fn to_phrase(&self) -> String {
+        let header = (self.identifier as u64) << 25
+            | (self.extendable as u64) << 24
+            | (self.iteration_exponent as u64) << 20
+            | (self.group_index as u64) << 16
+            | (self.group_threshold as u64 - 1) << 12
+            | (self.group_count as u64 - 1) << 8
+            | (self.member_index as u64) << 4
+            | (self.member_threshold as u64 - 1);
+
+        let mut indices = (0..HEADER_WORDS)
+            .rev()
+            .map(|i| ((header >> (i * RADIX_BITS)) & 0x3ff) as u16)
+            .collect::<Vec<_>>();
+        indices.extend(value_to_words(&self.value));
+
+        let mut checksum_input = indices.clone();
+        checksum_input.extend([0; CHECKSUM_WORDS]);
+        let checksum = rs1024_polymod(customization(self.extendable), &checksum_input) ^ 1;
+        indices.extend(
+            (0..CHECKSUM_WORDS)
+                .rev()
+                .map(|i| ((checksum >> (i * RADIX_BITS)) & 0x3ff) as u16),
+        );
+
+        indices
+            .iter()
+            .map(|&index| wordlist::WORDS[index as usize])
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Returns the random identifier shared by all shares of a secret.
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    pub fn is_extendable(&self) -> bool {
+        self.extendable
+    }
+
+    pub fn iteration_exponent(&self) -> u8 {
+        self.iteration_exponent
+    }
+
+    pub fn group_index(&self) -> u8 {
+        self.group_index
+    }
+
+    pub fn group_threshold(&self) -> u8 {
+        self.group_threshold
+    }
+
+    pub fn group_count(&self) -> u8 {
+        self.group_count
+    }
+
+    pub fn member_index(&self) -> u8 {
+        self.member_index
+    }
+
+    pub fn member_threshold(&self) -> u8 {
+        self.member_threshold
+    }
+}
+
+/// Splits a master secret into shares, returned per group.
+///
+/// The master secret must be at least 16 bytes long with an even length.
+/// The passphrase may only contain printable ASCII characters. Each PBKDF2
+/// iteration exponent step doubles the work needed to decrypt the secret.
+pub fn generate_shares(
+    master_secret: &[u8],
+    passphrase: &str,
+    group_threshold: u8,
+    groups: &[Group],
+    iteration_exponent: u8,
+    extendable: bool,
+) -> Result<Vec<Vec<Share>>> {
+    ensure!(
+        master_secret.len() >= MIN_SECRET_LEN && master_secret.len() & 1 == 0,
+        "master secret must be at least {MIN_SECRET_LEN} bytes long with an even length",
+    );
+    ensure!(
+        iteration_exponent < 16,
+        "iteration exponent {iteration_exponent} exceeds 15"
+    );
+    ensure!(
+        groups.len() <= MAX_SHARES as usize,
+        "group count {} exceeds {MAX_SHARES}",
+        groups.len(),
+    );
+    ensure!(
+        group_threshold > 0 && group_threshold as usize <= groups.len(),
+        "group threshold {group_threshold} must be between 1 and the group count {}",
+        groups.len(),
+    );
+    for group in groups {
+        ensure!(
+            group.threshold > 0 && group.threshold <= group.count && group.count <= MAX_SHARES,
+            "invalid {}-of-{} member group",
+            group.threshold,
+            group.count,
+        );
+        ensure!(
+            group.threshold > 1 || group.count == 1,
+            "multiple member shares with member threshold 1 are not allowed, use 1-of-1 instead",
+        );
+    }
+
+    let identifier = {
+        let mut bytes = [0; 2];
+        utils::fill_random_bytes(&mut bytes)?;
+        u16::from_be_bytes(bytes) & 0x7fff
+    };
+    let encrypted_secret = encrypt(
+        master_secret,
+        passphrase,
+        iteration_exponent,
+        identifier,
+        extendable,
+    )?;
+
+    let group_shares = shamir::split(group_threshold, groups.len() as u8, &encrypted_secret)?;
+    groups
+        .iter()
+        .zip(group_shares)
+        .map(|(group, (group_index, group_secret))| {
+            let member_shares = shamir::split(group.threshold, group.count, &group_secret)?;
+            Ok(member_shares
+                .into_iter()
+                .map(|(member_index, value)| Share {
+                    identifier,
+                    extendable,
+                    iteration_exponent,
+                    group_index,
+                    group_threshold,
+                    group_count: groups.len() as u8,
+                    member_index,
+                    member_threshold: group.threshold,
+                    value,
+                })
+                .collect())
+        })
+        .collect()
+}
+
+/// Recovers the master secret from shares. Like the reference
+/// implementation, the shares must be from exactly the threshold number of
+/// groups, with exactly the member threshold number of shares in each group.
+pub fn combine_shares(shares: &[Share], passphrase: &str) -> Result<Vec<u8>> {
+    let first = shares.first().context("no shares to combine")?;
+    for share in shares {
+        ensure!(
+            (share.identifier, share.extendable, share.iteration_exponent)
+                == (first.identifier, first.extendable, first.iteration_exponent),
+            "shares do not belong to the same secret",
+        );
+        ensure!(
+            (share.group_threshold, share.group_count)
+                == (first.group_threshold, first.group_count),
+            "shares have inconsistent group parameters",
+        );
+        ensure!(
+            share.value.len() == first.value.len(),
+            "shares have inconsistent lengths",
+        );
+    }
+
+    let mut groups = BTreeMap::<u8, Vec<&Share>>::new();
+    for share in shares {
+        let group = groups.entry(share.group_index).or_default();
+        ensure!(
+            group
+                .iter()
+                .all(|s| s.member_threshold == share.member_threshold),
+            "shares of group {} have inconsistent member thresholds",
+            share.group_index,
+        );
+        match group.iter().find(|s| s.member_index == share.member_index) {
+            None => group.push(share),
+            Some(existing) if *existing != share => bail!(
+                "conflicting shares for member {} of group {}",
+                share.member_index,
+                share.group_index,
+            ),
+            Some(_) => {}
+        }
+    }
+
+    ensure!(
+        groups.len() >= first.group_threshold as usize,
+        "{} of {} required groups are provided",
+        groups.len(),
+        first.group_threshold,
+    );
+    ensure!(
+        groups.len() == first.group_threshold as usize,
+        "shares of {} groups are provided, but the group threshold is {}",
+        groups.len(),
+        first.group_threshold,
+    );
+
+    let group_secrets = groups
+        .into_iter()
+        .map(|(group_index, members)| {
+            let threshold = members[0].member_threshold;
+            ensure!(
+                members.len() == threshold as usize,
+                "{} shares of group {group_index} are provided, but its threshold is {threshold}",
+                members.len(),
+            );
+            let members = members
+                .iter()
+                .map(|share| (share.member_index, share.value.clone()))
+                .collect::<Vec<_>>();
+            Ok((group_index, shamir::recover(threshold, &members)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let encrypted_secret = shamir::recover(first.group_threshold, &group_secrets)?;
+    decrypt(
+        &encrypted_secret,
+        passphrase,
+        first.iteration_exponent,
+        first.identifier,
+        first.extendable,
+    )
+}
+
+/// Recovers the master secret from share mnemonics.
+pub fn combine_phrases<S>(phrases: &[S], passphrase: &str) -> Result<Vec<u8>>
+where
+    S: AsRef<str>,
+{
+    let shares = phrases
+        .iter()
+        .map(Share::from_phrase)
+        .collect::<Result<Vec<_>>>()?;
+    combine_shares(&shares, passphrase)
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Encryption
+///////////////////////////////////////////////////////////////////////////////
+
+// Encrypts the master secret with a four round Feistel network.
+fn encrypt(
+    master_secret: &[u8],
+    passphrase: &str,
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+) -> Result<Vec<u8>> {
+    let passphrase = validate_passphrase(passphrase)?;
+    let salt = salt(identifier, extendable);
+    let (mut left, mut right) = split_halves(master_secret);
+    for round in 0..ROUND_COUNT {
+        let f = round_function(round, passphrase, iteration_exponent, &salt, &right);
+        let next = xor(&left, &f);
+        left = right;
+        right = next;
+    }
+    Ok([right, left].concat())
+}
+
+// Decrypts the master secret by running the Feistel rounds in reverse.
+fn decrypt(
+    encrypted_secret: &[u8],
+    passphrase: &str,
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+) -> Result<Vec<u8>> {
+    let passphrase = validate_passphrase(passphrase)?;
+    let salt = salt(identifier, extendable);
+    let (mut left, mut right) = split_halves(encrypted_secret);
+    for round in (0..ROUND_COUNT).rev() {
+        let f = round_function(round, passphrase, iteration_exponent, &salt, &right);
+        let next = xor(&left, &f);
+        left = right;
+        right = next;
+    }
+    Ok([right, left].concat())
+}
+
+fn round_function(
+    round: u8,
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    salt: &[u8],
+    right: &[u8],
+) -> Vec<u8> {
+    let iterations = (BASE_ITERATION_COUNT << iteration_exponent) / ROUND_COUNT as u32;
+    let password = [&[round][..], passphrase].concat();
+    let salt = [salt, right].concat();
+
+    let mut output = vec![0; right.len()];
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(&password, &salt, iterations, &mut output)
+        .expect("invalid length");
+    output
+}
+
+fn validate_passphrase(passphrase: &str) -> Result<&[u8]> {
+    ensure!(
+        passphrase.bytes().all(|b| (32..=126).contains(&b)),
+        "passphrase must only contain printable ASCII characters",
+    );
+    Ok(passphrase.as_bytes())
+}
+
+fn salt(identifier: u16, extendable: bool) -> Vec<u8> {
+    if extendable {
+        Vec::new()
+    } else {
+        [&b"shamir"[..], &identifier.to_be_bytes()].concat()
+    }
+}
+
+fn split_halves(secret: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    let (left, right) = secret.split_at(secret.len() / 2);
+    (left.to_vec(), right.to_vec())
+}
+
+fn xor(a: &[u8], b: &[u8]) -> Vec<u8> {
+    a.iter().zip(b).map(|(a, b)| a ^ b).collect()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Encoding
+///////////////////////////////////////////////////////////////////////////////
+
+fn customization(extendable: bool) -> &'static [u8] {
+    if extendable {
+        b"shamir_extendable"
+    } else {
+        b"shamir"
+    }
+}
+
+// Computes the RS1024 checksum polynomial over GF(1024).
+fn rs1024_polymod(customization: &[u8], values: &[u16]) -> u32 {
+    const GEN: [u32; 10] = [
+        0x00e0_e040,
+        0x01c1_c080,
+        0x0383_8100,
+        0x0707_0200,
+        0x0e0e_0009,
+        0x1c0c_2412,
+        0x3808_6c24,
+        0x3090_fc48,
+        0x21b1_f890,
+        0x03f3_f120,
+    ];
+
+    let mut chk = 1_u32;
+    for value in customization
+        .iter()
+        .map(|&b| b as u32)
+        .chain(values.iter().map(|&v| v as u32))
+    {
+        let b = chk >> 20;
+        chk = ((chk & 0xf_ffff) << 10) ^ value;
+        for (i, generator) in GEN.iter().enumerate() {
+            if (b >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+    }
+    chk
+}
+
+// Encodes a share value as 10 bit words, left padded with zero bits.
+fn value_to_words(value: &[u8]) -> Vec<u16> {
+    let word_count = (value.len() * 8).div_ceil(RADIX_BITS);
+    let padding = word_count * RADIX_BITS - value.len() * 8;
+
+    let mut words = Vec::with_capacity(word_count);
+    let mut acc = 0_u32;
+    let mut bits = padding;
+    for &byte in value {
+        acc = (acc << 8) | byte as u32;
+        bits += 8;
+        if bits >= RADIX_BITS {
+            bits -= RADIX_BITS;
+            words.push(((acc >> bits) & 0x3ff) as u16);
+        }
+    }
+    debug_assert_eq!(bits, 0);
+    words
+}
+
+// Decodes a share value from 10 bit words, checking the padding.
+fn words_to_value(words: &[u16]) -> Result<Vec<u8>> {
+    let len = words.len() * RADIX_BITS / 16 * 2;
+    let padding = words.len() * RADIX_BITS - len * 8;
+    ensure!(padding <= 8, "invalid share value length");
+
+    let mut value = Vec::with_capacity(len);
+    let mut acc = 0_u32;
+    let mut bits = 0;
+    for (i, &word) in words.iter().enumerate() {
+        acc = (acc << RADIX_BITS) | word as u32;
+        bits += RADIX_BITS;
+        if i == 0 {
+            ensure!(acc >> (RADIX_BITS - padding) == 0, "invalid share padding");
+            bits -= padding;
+            acc &= (1 << bits) - 1;
+        }
+        while bits >= 8 {
+            bits -= 8;
+            value.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bip32::hdk;
+
+    #[test]
+    fn official_vectors() {
+        // (mnemonics, master secret, BIP-0032 master key), all with the
+        // passphrase "TREZOR". Invalid mnemonics have no master secret.
+        let vectors: [(&[&str], &str, &str); 4] = [
+            // Valid mnemonic without sharing (128 bits)
+            (
+                &["duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard"],
+                "bb54aac4b89dc868ba37d9cc21b2cece",
+                "xprv9s21ZrQH143K4QViKpwKCpS2zVbz8GrZgpEchMDg6KME9HZtjfL7iThE9w5muQA4YPHKN1u5VM1w8D4pvnjxa2BmpGMfXr7hnRrRHZ93awZ",
+            ),
+            // Mnemonic with invalid checksum (128 bits)
+            (
+                &["duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision kidney"],
+                "",
+                "",
+            ),
+            // Basic sharing 2-of-3 (128 bits)
+            (
+                &[
+                    "shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed",
+                    "shadow pistol academic acid actress prayer class unknown daughter sweater depict flip twice unkind craft early superior advocate guest smoking",
+                ],
+                "b43ceb7e57a0ea8766221624d01b0864",
+                "xprv9s21ZrQH143K2nNuAbfWPHBtfiSCS14XQgb3otW4pX655q58EEZeC8zmjEUwucBu9dPnxdpbZLCn57yx45RBkwJHnwHFjZK4XPJ8SyeYjYg",
+            ),
+            // Valid mnemonic without sharing (256 bits)
+            (
+                &["theory painting academic academic armed sweater year military elder discuss acne wildlife boring employer fused large satoshi bundle carbon diagnose anatomy hamster leaves tracks paces beyond phantom capital marvel lips brave detect luck"],
+                "989baf9dcaad5b10ca33dfd8cc75e42477025dce88ae83e75a230086a0e00e92",
+                "xprv9s21ZrQH143K41mrxxMT2FpiheQ9MFNmWVK4tvX2s28KLZAhuXWskJCKVRQprq9TnjzzzEYePpt764csiCxTt22xwGPiRmUjYUUdjaut8RM",
+            ),
+        ];
+
+        for (mnemonics, secret, xprv) in vectors {
+            let result = combine_phrases(mnemonics, "TREZOR");
+            if secret.is_empty() {
+                assert!(result.is_err());
+                continue;
+            }
+            let result = result.unwrap();
+            assert_eq!(hex::encode(&result), secret);
+            assert_eq!(
+                hdk::ExtendedPrivateKey::new_master(&result)
+                    .unwrap()
+                    .to_string(),
+                xprv
+            );
+            for mnemonic in mnemonics {
+                assert_eq!(Share::from_phrase(mnemonic).unwrap().to_phrase(), *mnemonic);
+            }
+        }
+
+        // A single share of a 2-of-3 sharing is not enough.
+        assert!(combine_phrases(&vectors[2].0[..1], "TREZOR").is_err());
+    }
+
+    // Re-encodes a share after changing its fields, with a valid checksum.
+    fn modify(phrase: &str, f: impl FnOnce(&mut Share)) -> String {
+        let mut share = Share::from_phrase(phrase).unwrap();
+        f(&mut share);
+        share.to_phrase()
+    }
+
+    // Re-encodes a share after changing its words other than the checksum,
+    // with a valid checksum.
+    fn modify_words(phrase: &str, f: impl FnOnce(&mut Vec<u16>)) -> String {
+        let mut indices = phrase
+            .split(' ')
+            .map(|word| wordlist::get_index(word).unwrap() as u16)
+            .collect::<Vec<_>>();
+        indices.truncate(indices.len() - CHECKSUM_WORDS);
+        f(&mut indices);
+
+        let mut checksum_input = indices.clone();
+        checksum_input.extend([0; CHECKSUM_WORDS]);
+        let checksum = rs1024_polymod(customization(false), &checksum_input) ^ 1;
+        indices.extend(
+            (0..CHECKSUM_WORDS)
+                .rev()
+                .map(|i| (checksum >> (i * 10)) as u16 & 0x3ff),
+        );
+        indices
+            .iter()
+            .map(|&index| wordlist::WORDS[index as usize])
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn invalid_shares() {
+        const SINGLE: &str = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard";
+        const SHARES: [&str; 2] = [
+            "shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed",
+            "shadow pistol academic acid actress prayer class unknown daughter sweater depict flip twice unkind craft early superior advocate guest smoking",
+        ];
+        let error = |phrases: &[&str]| combine_phrases(phrases, "TREZOR").unwrap_err().to_string();
+
+        // The helpers re-encode valid shares unchanged.
+        assert_eq!(modify(SINGLE, |_| {}), SINGLE);
+        assert_eq!(modify_words(SINGLE, |_| {}), SINGLE);
+
+        // Invalid checksum.
+        assert_eq!(
+            error(&[&SINGLE.replace("enlarge academic", "enlarge acid")]),
+            "invalid share checksum"
+        );
+        // Invalid padding: 128 bits are encoded in 13 words with 2 padding
+        // bits which must be zero.
+        assert_eq!(
+            error(&[&modify_words(SINGLE, |words| words[4] |= 0x200)]),
+            "invalid share padding"
+        );
+        // Insufficient share length and invalid master secret length.
+        assert_eq!(
+            error(&[&modify_words(SINGLE, |words| {
+                words.pop();
+            })]),
+            "invalid share length 19"
+        );
+        assert_eq!(
+            error(&[&modify_words(SINGLE, |words| words.push(0))]),
+            "invalid share value length"
+        );
+        // Group threshold greater than the group count.
+        assert_eq!(
+            error(&[&modify(SINGLE, |share| share.group_threshold = 2)]),
+            "group threshold 2 exceeds group count 1"
+        );
+
+        // Shares of different secrets or with different parameters.
+        for (f, message) in [
+            (
+                (|share: &mut Share| share.identifier ^= 1) as fn(&mut Share),
+                "shares do not belong to the same secret",
+            ),
+            (
+                |share| share.iteration_exponent += 1,
+                "shares do not belong to the same secret",
+            ),
+            (
+                |share| share.extendable = true,
+                "shares do not belong to the same secret",
+            ),
+            (
+                |share| (share.group_threshold, share.group_count) = (2, 2),
+                "shares have inconsistent group parameters",
+            ),
+            (
+                |share| share.group_count = 2,
+                "shares have inconsistent group parameters",
+            ),
+            (
+                |share| share.member_threshold = 3,
+                "shares of group 0 have inconsistent member thresholds",
+            ),
+            (
+                |share| share.value.extend([0, 0]),
+                "shares have inconsistent lengths",
+            ),
+        ] {
+            assert_eq!(error(&[SHARES[0], &modify(SHARES[1], f)]), message);
+        }
+
+        // Different shares with the same member index.
+        let member_index = Share::from_phrase(SHARES[0]).unwrap().member_index;
+        assert_eq!(
+            error(&[
+                SHARES[0],
+                &modify(SHARES[1], |share| share.member_index = member_index)
+            ]),
+            format!("conflicting shares for member {member_index} of group 0")
+        );
+
+        // Too few shares, and shares with a corrupted value that does not
+        // match the digest.
+        assert_eq!(
+            error(&SHARES[..1]),
+            "1 shares of group 0 are provided, but its threshold is 2"
+        );
+        assert!(
+            error(&[SHARES[0], &modify(SHARES[1], |share| share.value[0] ^= 1)]).contains("digest")
+        );
+        assert_eq!(
+            hex::encode(combine_phrases(&[SHARES[1], SHARES[0], SHARES[1]], "TREZOR").unwrap()),
+            "b43ceb7e57a0ea8766221624d01b0864"
+        );
+    }
+
+    #[test]
+    fn extendable_shares() {
+        let secret = hex::decode("bb54aac4b89dc868ba37d9cc21b2cece").unwrap();
+        let single = [Group {
+            threshold: 1,
+            count: 1,
+        }];
+        for extendable in [false, true] {
+            let share =
+                &generate_shares(&secret, "TREZOR", 1, &single, 0, extendable).unwrap()[0][0];
+            assert_eq!(share.is_extendable(), extendable);
+
+            // The identifier only salts the encryption of non-extendable
+            // backups, so extendable backups of a secret can share a
+            // passphrase across different identifiers.
+            let other = modify(&share.to_phrase(), |share| share.identifier ^= 1);
+            let recovered = combine_phrases(&[other], "TREZOR").unwrap();
+            assert_eq!(recovered == secret, extendable);
+        }
+
+        // Extendable shares use a different checksum customization, so
+        // setting the extendable bit invalidates the checksum.
+        let share = &generate_shares(&secret, "TREZOR", 1, &single, 0, false).unwrap()[0][0];
+        let phrase = modify_words(&share.to_phrase(), |words| words[1] |= 1 << 4);
+        assert_eq!(
+            Share::from_phrase(phrase).unwrap_err().to_string(),
+            "invalid share checksum"
+        );
+    }
+
+    #[test]
+    fn group_shares_roundtrip() {
+        let secret = hex::decode("989baf9dcaad5b10ca33dfd8cc75e424").unwrap();
+        let groups = [
+            Group {
+                threshold: 1,
+                count: 1,
+            },
+            Group {
+                threshold: 2,
+                count: 3,
+            },
+            Group {
+                threshold: 3,
+                count: 5,
+            },
+        ];
+
+        for extendable in [false, true] {
+            let shares = generate_shares(&secret, "officer", 2, &groups, 0, extendable).unwrap();
+            let phrases = shares
+                .iter()
+                .map(|group| group.iter().map(Share::to_phrase).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            assert!(phrases.iter().flatten().all(|p| p.split(' ').count() == 20));
+
+            let recovered = combine_phrases(
+                &[
+                    &phrases[1][2],
+                    &phrases[2][4],
+                    &phrases[1][0],
+                    &phrases[2][0],
+                    &phrases[2][2],
+                ],
+                "officer",
+            )
+            .unwrap();
+            assert_eq!(recovered, secret);
+
+            let recovered =
+                combine_phrases(&[&phrases[0][0], &phrases[1][1], &phrases[1][2]], "officer")
+                    .unwrap();
+            assert_eq!(recovered, secret);
+
+            // Incomplete groups, extra groups, extra members and wrong
+            // passphrases do not yield the secret.
+            let error =
+                |phrases: &[&String]| combine_phrases(phrases, "officer").unwrap_err().to_string();
+            assert_eq!(
+                error(&[&phrases[0][0], &phrases[1][1]]),
+                "1 shares of group 1 are provided, but its threshold is 2"
+            );
+            assert_eq!(
+                error(&[&phrases[1][1]]),
+                "1 of 2 required groups are provided"
+            );
+            assert_eq!(
+                error(&[
+                    &phrases[0][0],
+                    &phrases[1][1],
+                    &phrases[1][2],
+                    &phrases[2][0]
+                ]),
+                "shares of 3 groups are provided, but the group threshold is 2"
+            );
+            assert_eq!(
+                error(&[
+                    &phrases[0][0],
+                    &phrases[1][0],
+                    &phrases[1][1],
+                    &phrases[1][2]
+                ]),
+                "3 shares of group 1 are provided, but its threshold is 2"
+            );
+            assert_ne!(
+                combine_phrases(&[&phrases[0][0], &phrases[1][1], &phrases[1][2]], "other")
+                    .unwrap(),
+                secret,
+            );
+        }
+
+        assert!(generate_shares(
+            &secret,
+            "",
+            1,
+            &[Group {
+                threshold: 1,
+                count: 2
+            }],
+            0,
+            false
+        )
+        .is_err());
+        assert!(generate_shares(&secret, "", 3, &groups[..2], 0, false).is_err());
+        assert!(generate_shares(&secret[..15], "", 1, &groups[..1], 0, false).is_err());
+        assert!(generate_shares(&secret, "ü", 1, &groups[..1], 0, false).is_err());
+    }
+}
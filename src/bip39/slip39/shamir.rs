@@ -0,0 +1,144 @@
+//! Shamir's secret sharing over GF(256) as specified by SLIP-0039.
+//!
+//! The field uses the Rijndael polynomial `x^8 + x^4 + x^3 + x + 1`. The
+//! secret is stored at x = 255 and a digest of it at x = 254, so a wrong
+//! combination of shares is detected when recovering.
+
+use crate::utils;
+use anyhow::{ensure, Result};
+use hmac::{Hmac, Mac as _};
+use sha2::Sha256;
+
+/// The x coordinate of the shared secret.
+const SECRET_INDEX: u8 = 255;
+/// The x coordinate of the digest share.
+const DIGEST_INDEX: u8 = 254;
+/// The length of the secret digest.
+const DIGEST_LEN: usize = 4;
+
+/// Logarithm and exponent tables for GF(256) with generator 3.
+struct Tables {
+    log: [u8; 256],
+    exp: [u8; 255],
+}
+
+const TABLES: Tables = {
+    let mut log = [0; 256];
+    let mut exp = [0; 255];
+    let mut poly: u16 = 1;
+    let mut i = 0;
+    while i < 255 {
+        exp[i] = poly as u8;
+        log[poly as usize] = i as u8;
+        // multiply by the generator `x + 1` and reduce
+        poly = (poly << 1) ^ poly;
+        if poly & 0x100 != 0 {
+            poly ^= 0x11b;
+        }
+        i += 1;
+    }
+    Tables { log, exp }
+};
+
+/// Splits a secret into `count` shares, any `threshold` of which recover it.
+/// Shares are returned with their x coordinate.
+pub fn split(threshold: u8, count: u8, secret: &[u8]) -> Result<Vec<(u8, Vec<u8>)>> {
+    ensure!(threshold > 0, "threshold must be positive");
+    ensure!(
+        threshold <= count,
+        "threshold {threshold} exceeds share count {count}"
+    );
+    ensure!(count <= 16, "share count {count} exceeds 16");
+    ensure!(secret.len() >= 16, "secret must be at least 16 bytes long");
+
+    if threshold == 1 {
+        return Ok((0..count).map(|i| (i, secret.to_vec())).collect());
+    }
+
+    let random_count = threshold - 2;
+    let mut shares = (0..random_count)
+        .map(|i| {
+            let mut value = vec![0; secret.len()];
+            utils::fill_random_bytes(&mut value)?;
+            Ok((i, value))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut digest_share = vec![0; secret.len()];
+    utils::fill_random_bytes(&mut digest_share[DIGEST_LEN..])?;
+    let digest = digest(&digest_share[DIGEST_LEN..], secret);
+    digest_share[..DIGEST_LEN].copy_from_slice(&digest);
+
+    let mut base = shares.clone();
+    base.push((DIGEST_INDEX, digest_share));
+    base.push((SECRET_INDEX, secret.to_vec()));
+
+    for i in random_count..count {
+        shares.push((i, interpolate(&base, i)?));
+    }
+    Ok(shares)
+}
+
+/// Recovers a secret from `threshold` shares and verifies its digest.
+pub fn recover(threshold: u8, shares: &[(u8, Vec<u8>)]) -> Result<Vec<u8>> {
+    ensure!(!shares.is_empty(), "no shares to recover the secret from");
+    if threshold == 1 {
+        return Ok(shares[0].1.clone());
+    }
+
+    let secret = interpolate(shares, SECRET_INDEX)?;
+    let digest_share = interpolate(shares, DIGEST_INDEX)?;
+    ensure!(
+        digest_share[..DIGEST_LEN] == digest(&digest_share[DIGEST_LEN..], &secret),
+        "invalid digest of the shared secret",
+    );
+    Ok(secret)
+}
+
+// Evaluates the polynomial through the shares at `x` with Lagrange
+// interpolation.
+fn interpolate(shares: &[(u8, Vec<u8>)], x: u8) -> Result<Vec<u8>> {
+    let len = shares[0].1.len();
+    ensure!(
+        shares.iter().all(|(_, value)| value.len() == len),
+        "all share values must have the same length",
+    );
+    for (i, (xi, _)) in shares.iter().enumerate() {
+        ensure!(
+            shares[i + 1..].iter().all(|(xj, _)| xi != xj),
+            "share indices must be unique"
+        );
+    }
+    if let Some((_, value)) = shares.iter().find(|(xi, _)| *xi == x) {
+        return Ok(value.clone());
+    }
+
+    let log = |v: u8| TABLES.log[v as usize] as usize;
+    let log_product = shares.iter().map(|(xi, _)| log(xi ^ x)).sum::<usize>();
+
+    let mut result = vec![0; len];
+    for (xi, value) in shares {
+        let log_denominator = shares
+            .iter()
+            .filter(|(xj, _)| xj != xi)
+            .map(|(xj, _)| log(xi ^ xj))
+            .sum::<usize>();
+        let log_basis = (log_product + 255 * shares.len() - log(xi ^ x) - log_denominator) % 255;
+
+        for (r, v) in result.iter_mut().zip(value) {
+            if *v != 0 {
+                *r ^= TABLES.exp[(log(*v) + log_basis) % 255];
+            }
+        }
+    }
+    Ok(result)
+}
+
+// Returns the first bytes of HMAC-SHA256 keyed with the random part.
+fn digest(random: &[u8], secret: &[u8]) -> [u8; DIGEST_LEN] {
+    let mut hmac = Hmac::<Sha256>::new_from_slice(random).expect("HMAC accepts any key length");
+    hmac.update(secret);
+    hmac.finalize().into_bytes()[..DIGEST_LEN]
+        .try_into()
+        .unwrap()
+}
@@ -1,28 +1,264 @@
-pub struct Wordlist<'a>(&'a [&'a str; 2048]);
+//! Module containing the official BIP-0039 wordlists.
+//!
+//! All words are stored in their NFKD normalized form, so they can be
+//! compared against normalized mnemonic phrases.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+
+pub struct Wordlist<'a> {
+    words: &'a [&'a str; 2048],
+    sorted: bool,
+}
 pub const WORD_COUNT: usize = 2048;
 
+mod chinese_simplified;
+mod chinese_traditional;
+mod czech;
 mod english;
+mod french;
+mod italian;
+mod japanese;
+mod korean;
+mod portuguese;
+mod spanish;
+
+// NOTE: Only some of the official wordlists are in byte order, the others
+// need a linear search.
+static ENGLISH: Wordlist = Wordlist {
+    words: &english::WORDS,
+    sorted: true,
+};
+static CHINESE_SIMPLIFIED: Wordlist = Wordlist {
+    words: &chinese_simplified::WORDS,
+    sorted: false,
+};
+static CHINESE_TRADITIONAL: Wordlist = Wordlist {
+    words: &chinese_traditional::WORDS,
+    sorted: false,
+};
+static CZECH: Wordlist = Wordlist {
+    words: &czech::WORDS,
+    sorted: false,
+};
+static FRENCH: Wordlist = Wordlist {
+    words: &french::WORDS,
+    sorted: false,
+};
+static ITALIAN: Wordlist = Wordlist {
+    words: &italian::WORDS,
+    sorted: true,
+};
+static JAPANESE: Wordlist = Wordlist {
+    words: &japanese::WORDS,
+    sorted: false,
+};
+static KOREAN: Wordlist = Wordlist {
+    words: &korean::WORDS,
+    sorted: true,
+};
+static PORTUGUESE: Wordlist = Wordlist {
+    words: &portuguese::WORDS,
+    sorted: true,
+};
+static SPANISH: Wordlist = Wordlist {
+    words: &spanish::WORDS,
+    sorted: false,
+};
+
+/// A language with an official BIP-0039 wordlist.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Language {
+    #[default]
+    English,
+    ChineseSimplified,
+    ChineseTraditional,
+    Czech,
+    French,
+    Italian,
+    Japanese,
+    Korean,
+    Portuguese,
+    Spanish,
+}
+
+impl Language {
+    /// All supported languages, in the order used for language detection.
+    pub const ALL: [Language; 10] = [
+        Language::English,
+        Language::ChineseSimplified,
+        Language::ChineseTraditional,
+        Language::Czech,
+        Language::French,
+        Language::Italian,
+        Language::Japanese,
+        Language::Korean,
+        Language::Portuguese,
+        Language::Spanish,
+    ];
+
+    /// Returns the wordlist for the language.
+    pub fn wordlist(self) -> &'static Wordlist<'static> {
+        match self {
+            Language::English => &ENGLISH,
+            Language::ChineseSimplified => &CHINESE_SIMPLIFIED,
+            Language::ChineseTraditional => &CHINESE_TRADITIONAL,
+            Language::Czech => &CZECH,
+            Language::French => &FRENCH,
+            Language::Italian => &ITALIAN,
+            Language::Japanese => &JAPANESE,
+            Language::Korean => &KOREAN,
+            Language::Portuguese => &PORTUGUESE,
+            Language::Spanish => &SPANISH,
+        }
+    }
+
+    /// Returns the separator used between words of a mnemonic phrase.
+    /// Japanese phrases use an ideographic space.
+    pub fn separator(self) -> &'static str {
+        match self {
+            Language::Japanese => "\u{3000}",
+            _ => " ",
+        }
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Language::English => "English",
+            Language::ChineseSimplified => "Chinese (Simplified)",
+            Language::ChineseTraditional => "Chinese (Traditional)",
+            Language::Czech => "Czech",
+            Language::French => "French",
+            Language::Italian => "Italian",
+            Language::Japanese => "Japanese",
+            Language::Korean => "Korean",
+            Language::Portuguese => "Portuguese",
+            Language::Spanish => "Spanish",
+        })
+    }
+}
 
 impl<'a> Wordlist<'a> {
     /// Create a new wordlist.
-    pub fn new() -> &'a Self {
-        &Wordlist(&english::WORDS)
+    pub fn new() -> &'static Wordlist<'static> {
+        Language::English.wordlist()
     }
 
     /// Get a word from the wordlist.
     pub fn get(&self, index: usize) -> Option<&'a str> {
-        self.0.get(index).copied()
+        self.words.get(index).copied()
     }
 
-    /// Search for a word in the wordlist.
+    /// Search for a word in the wordlist. The word must be NFKD normalized.
     pub fn get_index(&self, word: impl AsRef<str>) -> Option<usize> {
-        self.0.binary_search(&word.as_ref()).ok()
+        let word = word.as_ref();
+        if self.sorted {
+            self.words.binary_search(&word).ok()
+        } else {
+            self.words.iter().position(|w| *w == word)
+        }
+    }
+
+    /// Returns all words starting with the specified prefix, for
+    /// autocompletion.
+    pub fn complete(&self, prefix: impl AsRef<str>) -> Vec<&'a str> {
+        let prefix = prefix.as_ref();
+        self.words
+            .iter()
+            .copied()
+            .filter(|word| word.starts_with(prefix))
+            .collect()
+    }
+
+    /// Searches for a word, also accepting a prefix of at least
+    /// [`PREFIX_LEN`] characters that is unique in the wordlist. BIP-0039
+    /// wordlists are chosen so that the first four letters identify a word.
+    pub fn expand(&self, word: impl AsRef<str>) -> Option<usize> {
+        let word = word.as_ref();
+        if let Some(index) = self.get_index(word) {
+            return Some(index);
+        }
+        if word.chars().count() < PREFIX_LEN {
+            return None;
+        }
+
+        let mut matches = self
+            .words
+            .iter()
+            .enumerate()
+            .filter(|(_, w)| w.starts_with(word))
+            .map(|(index, _)| index);
+        match (matches.next(), matches.next()) {
+            (Some(index), None) => Some(index),
+            _ => None,
+        }
+    }
+
+    /// Returns up to `limit` of the words closest to a mistyped word. Only
+    /// words at the smallest edit distance found, and at most
+    /// [`MAX_SUGGESTION_DISTANCE`] edits away, are suggested.
+    pub fn suggest(&self, word: impl AsRef<str>, limit: usize) -> Vec<&'a str> {
+        let word = word.as_ref().chars().collect::<Vec<_>>();
+        let distances = self
+            .words
+            .iter()
+            .map(|candidate| (edit_distance(&word, candidate), *candidate))
+            .collect::<Vec<_>>();
+
+        let Some(closest) = distances.iter().map(|(distance, _)| *distance).min() else {
+            return Vec::new();
+        };
+        if closest > MAX_SUGGESTION_DISTANCE {
+            return Vec::new();
+        }
+        distances
+            .into_iter()
+            .filter(|(distance, _)| *distance == closest)
+            .take(limit)
+            .map(|(_, candidate)| candidate)
+            .collect()
     }
 }
 
+/// The minimum length of an abbreviated word accepted by [`Wordlist::expand`].
+pub const PREFIX_LEN: usize = 4;
+/// The maximum edit distance of words suggested by [`Wordlist::suggest`].
+pub const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+// Computes the edit distance between two words, counting insertions,
+// deletions, substitutions and transpositions of adjacent characters (the
+// optimal string alignment distance).
+fn edit_distance(a: &[char], b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use unicode_normalization::UnicodeNormalization as _;
 
     #[test]
     fn test_wordlist() {
@@ -35,6 +271,107 @@ mod tests {
         assert_eq!(retrieved, "abandon");
 
         // assert the wordlist is 2048 words long
-        assert_eq!(wordlist.0.len(), WORD_COUNT);
+        assert_eq!(wordlist.words.len(), WORD_COUNT);
+    }
+
+    #[test]
+    fn prefixes_and_suggestions() {
+        let wordlist = Wordlist::new();
+
+        assert_eq!(
+            wordlist.complete("abs"),
+            ["absent", "absorb", "abstract", "absurd"]
+        );
+        assert_eq!(wordlist.expand("aban"), wordlist.get_index("abandon"));
+        assert_eq!(wordlist.expand("abando"), wordlist.get_index("abandon"));
+        assert_eq!(wordlist.expand("zoo"), Some(2047));
+        assert_eq!(wordlist.expand("aba"), None);
+        assert_eq!(wordlist.expand("abso"), wordlist.get_index("absorb"));
+        assert_eq!(wordlist.expand("abcd"), None);
+
+        assert_eq!(wordlist.suggest("abandn", 3), ["abandon"]);
+        assert_eq!(wordlist.suggest("actoin", 1), ["action"]);
+        assert!(wordlist.suggest("qqqqqqqq", 3).is_empty());
+
+        assert_eq!(edit_distance(&['k', 'i', 't', 't', 'e', 'n'], "sitting"), 3);
+        assert_eq!(edit_distance(&[], "abc"), 3);
+        assert_eq!(edit_distance(&['w', 'r', 'o', 't', 'h'], "worth"), 1);
+    }
+
+    #[test]
+    fn all_wordlists() {
+        for language in Language::ALL {
+            let wordlist = language.wordlist();
+            let mut words = wordlist.words.to_vec();
+            assert_eq!(wordlist.sorted, words.windows(2).all(|w| w[0] < w[1]));
+
+            for (index, word) in wordlist.words.iter().enumerate() {
+                assert_eq!(word.nfkd().collect::<String>(), *word, "{language}");
+                assert_eq!(wordlist.get_index(word), Some(index), "{language}");
+            }
+
+            words.sort_unstable();
+            words.dedup();
+            assert_eq!(words.len(), WORD_COUNT, "{language} has duplicate words");
+        }
+    }
+
+    #[test]
+    fn official_wordlists() {
+        // SHA-256 of the wordlist files in the BIP-0039 repository, one word
+        // per line.
+        for (language, checksum) in [
+            (
+                Language::English,
+                "2f5eed53a4727b4bf8880d8f3f199efc90e58503646d9ff8eff3a2ed3b24dbda",
+            ),
+            (
+                Language::ChineseSimplified,
+                "5c5942792bd8340cb8b27cd592f1015edf56a8c5b26276ee18a482428e7c5726",
+            ),
+            (
+                Language::ChineseTraditional,
+                "417b26b3d8500a4ae3d59717d7011952db6fc2fb84b807f3f94ac734e89c1b5f",
+            ),
+            (
+                Language::Czech,
+                "7e80e161c3e93d9554c2efb78d4e3cebf8fc727e9c52e03b83b94406bdcc95fc",
+            ),
+            (
+                Language::French,
+                "ebc3959ab7801a1df6bac4fa7d970652f1df76b683cd2f4003c941c63d517e59",
+            ),
+            (
+                Language::Italian,
+                "d392c49fdb700a24cd1fceb237c1f65dcc128f6b34a8aacb58b59384b5c648c2",
+            ),
+            (
+                Language::Japanese,
+                "2eed0aef492291e061633d7ad8117f1a2b03eb80a29d0e4e3117ac2528d05ffd",
+            ),
+            (
+                Language::Korean,
+                "9e95f86c167de88f450f0aaf89e87f6624a57f973c67b516e338e8e8b8897f60",
+            ),
+            (
+                Language::Portuguese,
+                "2685e9c194c82ae67e10ba59d9ea5345a23dc093e92276fc5361f6667d79cd3f",
+            ),
+            (
+                Language::Spanish,
+                "46846a5a0139d1e3cb77293e521c2865f7bcdb82c44e8d0a06a2cd0ecba48c0b",
+            ),
+        ] {
+            let file = language
+                .wordlist()
+                .words
+                .iter()
+                .fold(String::new(), |file, word| file + word + "\n");
+            assert_eq!(
+                hex::encode(crate::utils::hash::sha256(file)),
+                checksum,
+                "{language}"
+            );
+        }
     }
 }
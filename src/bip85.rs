@@ -0,0 +1,270 @@
+//! Module implementing BIP-0085, deterministic entropy from BIP-0032 keys.
+//!
+//! Child mnemonics, keys and raw entropy are derived from a single root key,
+//! so only the root needs to be backed up while every child wallet is
+//! independent: a leaked child reveals nothing about the root or its
+//! siblings.
+//!
+//! <https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki>
+
+use crate::{
+    bip32::{
+        hdk::ExtendedPrivateKey,
+        path::{Component, Path},
+    },
+    bip39::{mnemonic::Mnemonic, wordlist::Language},
+};
+use anyhow::{bail, ensure, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use hmac::{Hmac, Mac as _};
+use sha2::Sha512;
+
+/// The purpose of all BIP-0085 derivation paths, "BIPE" in ASCII.
+const PURPOSE: u32 = 83696968;
+/// The HMAC key used to turn a derived key into entropy.
+const HMAC_KEY: &[u8] = b"bip-entropy-from-k";
+/// The RFC 1924 base85 alphabet used for passwords.
+const BASE85_ALPHABET: &[u8; 85] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~";
+
+/// Deterministic entropy derived from a root extended private key.
+pub struct Bip85 {
+    root: ExtendedPrivateKey,
+}
+
+impl Bip85 {
+    pub fn new(root: ExtendedPrivateKey) -> Self {
+        Self { root }
+    }
+
+    /// Creates the root key from a BIP-0039 or SLIP-0039 seed.
+    pub fn from_seed(seed: impl AsRef<[u8]>) -> Result<Self> {
+        Ok(Self::new(ExtendedPrivateKey::new_master(seed)?))
+    }
+
+    /// Returns the 64 bytes of entropy for a derivation path. All BIP-0085
+    /// paths are fully hardened.
+    pub fn entropy(&self, path: &Path) -> Result<[u8; 64]> {
+        let key = self.root.derive(path)?;
+        Ok(entropy_from_key(&key.secret()))
+    }
+
+    /// Derives a child BIP-0039 mnemonic of 12, 18 or 24 words.
+    pub fn mnemonic(&self, language: Language, words: usize, index: u32) -> Result<Mnemonic> {
+        ensure!(
+            matches!(words, 12 | 18 | 24),
+            "invalid BIP-0085 mnemonic length {words}",
+        );
+        let path = path(&[39, language_code(language)?, words as u32, index])?;
+        mnemonic_from_entropy(&self.entropy(&path)?, language, words)
+    }
+
+    /// Derives between 16 and 64 bytes of raw entropy.
+    pub fn hex(&self, num_bytes: usize, index: u32) -> Result<Vec<u8>> {
+        ensure!(
+            (16..=64).contains(&num_bytes),
+            "invalid BIP-0085 entropy length {num_bytes}",
+        );
+        let path = path(&[128169, num_bytes as u32, index])?;
+        Ok(self.entropy(&path)?[..num_bytes].to_vec())
+    }
+
+    /// Derives a private key in compressed mainnet WIF encoding.
+    pub fn wif(&self, index: u32) -> Result<String> {
+        Ok(wif_from_entropy(&self.entropy(&path(&[2, index])?)?))
+    }
+
+    /// Derives a new root extended private key. The first half of the
+    /// entropy is the chain code and the second half the secret key.
+    pub fn xprv(&self, index: u32) -> Result<ExtendedPrivateKey> {
+        xprv_from_entropy(&self.entropy(&path(&[32, index])?)?)
+    }
+
+    /// Derives a password of 20 to 86 characters from base64 encoded
+    /// entropy.
+    pub fn password_base64(&self, length: usize, index: u32) -> Result<String> {
+        ensure!(
+            (20..=86).contains(&length),
+            "invalid BIP-0085 base64 password length {length}",
+        );
+        let entropy = self.entropy(&path(&[707764, length as u32, index])?)?;
+        Ok(BASE64.encode(entropy)[..length].to_owned())
+    }
+
+    /// Derives a password of 10 to 80 characters from base85 encoded
+    /// entropy.
+    pub fn password_base85(&self, length: usize, index: u32) -> Result<String> {
+        ensure!(
+            (10..=80).contains(&length),
+            "invalid BIP-0085 base85 password length {length}",
+        );
+        let entropy = self.entropy(&path(&[707785, length as u32, index])?)?;
+        Ok(base85_encode(&entropy)[..length].to_owned())
+    }
+}
+
+/// Returns the BIP-0085 code of a wordlist language. BIP-0085 defines no
+/// code for Portuguese.
+pub fn language_code(language: Language) -> Result<u32> {
+    Ok(match language {
+        Language::English => 0,
+        Language::Japanese => 1,
+        Language::Korean => 2,
+        Language::Spanish => 3,
+        Language::ChineseSimplified => 4,
+        Language::ChineseTraditional => 5,
+        Language::French => 6,
+        Language::Italian => 7,
+        Language::Czech => 8,
+        Language::Portuguese => bail!("BIP-0085 has no code for {language} mnemonics"),
+    })
+}
+
+// Turns the secret of a derived key into entropy.
+fn entropy_from_key(secret: &[u8]) -> [u8; 64] {
+    let mut hmac = Hmac::<Sha512>::new_from_slice(HMAC_KEY).expect("HMAC accepts any key length");
+    hmac.update(secret);
+    hmac.finalize().into_bytes().into()
+}
+
+fn mnemonic_from_entropy(entropy: &[u8; 64], language: Language, words: usize) -> Result<Mnemonic> {
+    Mnemonic::from_entropy_in(&entropy[..words * 4 / 3], language)
+}
+
+fn wif_from_entropy(entropy: &[u8; 64]) -> String {
+    let mut data = Vec::with_capacity(34);
+    data.push(0x80);
+    data.extend_from_slice(&entropy[..32]);
+    data.push(0x01);
+    bs58::encode(data).with_check().into_string()
+}
+
+fn xprv_from_entropy(entropy: &[u8; 64]) -> Result<ExtendedPrivateKey> {
+    ExtendedPrivateKey::from_parts(&entropy[32..], &entropy[..32])
+}
+
+// Encodes every 4 bytes as 5 base85 digits, most significant first. The
+// entropy is always a multiple of 4 bytes long, so there is no padding.
+fn base85_encode(bytes: &[u8]) -> String {
+    bytes
+        .chunks_exact(4)
+        .flat_map(|chunk| {
+            let value = u32::from_be_bytes(chunk.try_into().unwrap());
+            (0..5)
+                .rev()
+                .map(move |i| BASE85_ALPHABET[(value / 85_u32.pow(i) % 85) as usize] as char)
+        })
+        .collect()
+}
+
+// Builds the hardened BIP-0085 path for an application.
+fn path(components: &[u32]) -> Result<Path> {
+    Path::from_components(
+        [PURPOSE]
+            .iter()
+            .chain(components)
+            .map(|value| Component::Hardened(*value)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The root key of the BIP test vectors.
+    const ROOT: &str = "xprv9s21ZrQH143K2LBWUUQRFXhucrQqBpKdRRxNVq2zBqsx8HVqFk2uYo8kmbaLLHRdqtQpUm98uKfu3vca1LqdGhUtyoFnCNkfmXRyPXLjbKb";
+
+    fn bip85() -> Bip85 {
+        Bip85::new(ROOT.parse().unwrap())
+    }
+
+    #[test]
+    fn entropy_vectors() {
+        let bip85 = bip85();
+        for (path, key, entropy) in [
+            (
+                "m/83696968'/0'/0'",
+                "cca20ccb0e9a90feb0912870c3323b24874b0ca3d8018c4b96d0b97c0e82ded0",
+                "efecfbccffea313214232d29e71563d941229afb4338c21f9517c41aaa0d16f00b83d2a09ef747e7a64e8e2bd5a14869e693da66ce94ac2da570ab7ee48618f7",
+            ),
+            (
+                "m/83696968'/0'/1'",
+                "503776919131758bb7de7beb6c0ae24894f4ec042c26032890c29359216e21ba",
+                "70c6e3e8ebee8dc4c0dbba66076819bb8c09672527c4277ca8729532ad711872218f826919f6b67218adde99018a6df9095ab2b58d803b5b93ec9802085a690e",
+            ),
+        ] {
+            let path = path.parse::<Path>().unwrap();
+            assert_eq!(hex::encode(bip85.root.derive(&path).unwrap().secret()), key);
+            assert_eq!(hex::encode(bip85.entropy(&path).unwrap()), entropy);
+        }
+    }
+
+    #[test]
+    fn application_vectors() {
+        let bip85 = bip85();
+        for (words, phrase) in [
+            (
+                12,
+                "girl mad pet galaxy egg matter matrix prison refuse sense ordinary nose",
+            ),
+            (
+                18,
+                "near account window bike charge season chef number sketch tomorrow excuse sniff circle vital hockey outdoor supply token",
+            ),
+            (
+                24,
+                "puppy ocean match cereal symbol another shed magic wrap hammer bulb intact gadget divorce twin tonight reason outdoor destroy simple truth cigar social volcano",
+            ),
+        ] {
+            let mnemonic = bip85.mnemonic(Language::English, words, 0).unwrap();
+            assert_eq!(mnemonic.to_phrase(), phrase);
+        }
+
+        assert_eq!(
+            bip85.wif(0).unwrap(),
+            "Kzyv4uF39d4Jrw2W7UryTHwZr1zQVNk4dAFyqE6BuMrMh1Za7uhp",
+        );
+        assert_eq!(
+            bip85.xprv(0).unwrap().to_string(),
+            "xprv9s21ZrQH143K2srSbCSg4m4kLvPMzcWydgmKEnMmoZUurYuBuYG46c6P71UGXMzmriLzCCBvKQWBUv3vPB3m1SATMhp3uEjXHJ42jFg7myX",
+        );
+        assert_eq!(
+            hex::encode(bip85.hex(64, 0).unwrap()),
+            "492db4698cf3b73a5a24998aa3e9d7fa96275d85724a91e71aa2d645442f878555d078fd1f1f67e368976f04137b1f7a0d19232136ca50c44614af72b5582a5c",
+        );
+        assert_eq!(
+            bip85.password_base64(21, 0).unwrap(),
+            "dKLoepugzdVJvdL56ogNV"
+        );
+        assert_eq!(bip85.password_base85(12, 0).unwrap(), "_s`{TW89)i4`");
+    }
+
+    #[test]
+    fn derivation() {
+        let bip85 = bip85();
+
+        let english = bip85.mnemonic(Language::English, 12, 0).unwrap();
+        assert_ne!(
+            english.as_bytes(),
+            bip85.mnemonic(Language::English, 12, 1).unwrap().as_bytes(),
+        );
+        let japanese = bip85.mnemonic(Language::Japanese, 12, 0).unwrap();
+        assert_eq!(japanese.language(), Language::Japanese);
+        assert_ne!(japanese.as_bytes(), english.as_bytes());
+        assert!(bip85.mnemonic(Language::English, 15, 0).is_err());
+        assert_eq!(language_code(Language::Czech).unwrap(), 8);
+        assert!(bip85.mnemonic(Language::Portuguese, 12, 0).is_err());
+
+        assert_eq!(
+            bip85.hex(32, 0).unwrap(),
+            bip85
+                .entropy(&"m/83696968'/128169'/32'/0'".parse().unwrap())
+                .unwrap()[..32],
+        );
+        assert!(bip85.hex(15, 0).is_err());
+        assert!(bip85.hex(65, 0).is_err());
+        assert!(bip85.wif(1 << 31).is_err());
+        assert!(bip85.password_base64(19, 0).is_err());
+        assert!(bip85.password_base85(81, 0).is_err());
+    }
+}
@@ -0,0 +1,474 @@
+//! Module implementing Ethereum Name Service (ENS) name utilities: name
+//! normalization, `namehash`, DNS wire encoding and reverse record names.
+//!
+//! Normalization is modeled on ENSIP-15 but is not a conforming
+//! implementation, as it does not use the ENSIP-15 data tables. Labels are
+//! mapped with Unicode compatibility normalization and case folding, ignored
+//! characters are removed, and the result is validated: emoji sequences may
+//! contain zero width joiners and skin tone modifiers, combining marks may
+//! not lead a label or follow an emoji, at most four distinct non-spacing
+//! marks may be stacked, a label may only use characters of one script (or
+//! of the Japanese, Korean or Chinese script combinations), and Greek,
+//! Cyrillic or Armenian labels made only of look-alikes of Latin letters are
+//! rejected.
+//!
+//! The character tables in the `tables` module are generated from the Unicode
+//! character database, and the look-alikes are a partial list. Names with
+//! non-ASCII characters can therefore normalize differently than in ENS, so
+//! their namehashes should be checked against ENS before relying on them.
+//!
+//! <https://docs.ens.domains/ensip/15>
+
+mod tables;
+
+use crate::{address::Address, utils::hash::keccak256};
+use anyhow::{bail, ensure, Result};
+use std::collections::BTreeSet;
+use tables::{CHARACTERS, COMMON, INHERITED, SCRIPTS};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization as _;
+
+/// Normalizes an ENS name, returning an error if it is invalid.
+pub fn normalize(name: &str) -> Result<String> {
+    if name.is_empty() {
+        return Ok(String::new());
+    }
+    Ok(name
+        .split('.')
+        .map(normalize_label)
+        .collect::<Result<Vec<_>>>()?
+        .join("."))
+}
+
+/// Returns the `namehash` of a name, after normalizing it. The empty name
+/// is the root, whose hash is zero.
+pub fn namehash(name: &str) -> Result<[u8; 32]> {
+    let name = normalize(name)?;
+    if name.is_empty() {
+        return Ok([0; 32]);
+    }
+    Ok(name.rsplit('.').fold([0; 32], |node, label| {
+        keccak256([node, label_hash(label)].concat())
+    }))
+}
+
+/// Returns the `labelhash` of a single label, after normalizing it.
+pub fn labelhash(label: &str) -> Result<[u8; 32]> {
+    ensure!(!label.contains('.'), "ENS label '{label}' contains a dot");
+    Ok(label_hash(&normalize_label(label)?))
+}
+
+/// Encodes a name in the DNS wire format used for ENSIP-10 wildcard
+/// resolution: every label prefixed with its length, followed by a zero
+/// byte. Labels longer than 255 bytes are replaced by their encoded
+/// labelhash `[hash]`.
+pub fn dns_encode(name: &str) -> Result<Vec<u8>> {
+    let name = normalize(name)?;
+    let mut encoded = Vec::new();
+    if !name.is_empty() {
+        for label in name.split('.') {
+            let label = match label.len() {
+                0..=255 => label.to_owned(),
+                _ => format!("[{}]", hex::encode(label_hash(label))),
+            };
+            encoded.push(label.len() as u8);
+            encoded.extend_from_slice(label.as_bytes());
+        }
+    }
+    encoded.push(0);
+    Ok(encoded)
+}
+
+/// Returns the name of the reverse record of an address on Ethereum
+/// mainnet, `<address>.addr.reverse`.
+pub fn reverse_name(address: Address) -> String {
+    format!("{}.addr.reverse", hex::encode(address.0))
+}
+
+/// Returns the ENSIP-19 name of the reverse record of an address on an EVM
+/// chain, `<address>.<coin type>.reverse` with the ENSIP-11 coin type of the
+/// chain. Ethereum mainnet uses [`reverse_name`].
+pub fn reverse_name_for_chain(address: Address, chain_id: u64) -> Result<String> {
+    if chain_id == 1 {
+        return Ok(reverse_name(address));
+    }
+    ensure!(
+        chain_id < 0x8000_0000,
+        "chain ID {chain_id} has no ENS coin type"
+    );
+    Ok(format!(
+        "{}.{:x}.reverse",
+        hex::encode(address.0),
+        0x8000_0000 | chain_id
+    ))
+}
+
+// Script combinations that are allowed in one label.
+const SCRIPT_COMBINATIONS: [&[&str]; 3] = [
+    &["Han", "Hiragana", "Katakana"],
+    &["Han", "Hangul"],
+    &["Han", "Bopomofo"],
+];
+
+// Scripts whose labels are checked for being made only of look-alikes of
+// Latin letters.
+const CONFUSABLE_SCRIPTS: [&str; 3] = ["Greek", "Cyrillic", "Armenian"];
+
+// Greek, Cyrillic and Armenian letters that look like Latin letters, taken
+// from the Unicode confusables data.
+const LATIN_LOOKALIKES: &str = "αγιονρυϲϳасԁеһіјӏорԛѕԝхуүզհոսցօ";
+
+fn normalize_label(label: &str) -> Result<String> {
+    ensure!(!label.is_empty(), "ENS name has an empty label");
+    if encoded_label_hash(label).is_some() {
+        return Ok(label.to_ascii_lowercase());
+    }
+
+    let normalized = label
+        .nfkc()
+        .flat_map(char::to_lowercase)
+        .map(cherokee_uppercase)
+        .filter(|c| !is_ignored(*c))
+        .map(|c| if c == '\'' { '\u{2019}' } else { c })
+        .nfc()
+        .collect::<String>();
+    ensure!(
+        !normalized.is_empty(),
+        "ENS label '{label}' only has ignored characters"
+    );
+
+    let chars = normalized.chars().collect::<Vec<_>>();
+    ensure!(
+        !(chars.get(2) == Some(&'-') && chars.get(3) == Some(&'-')),
+        "ENS label '{label}' has hyphens in the third and fourth position"
+    );
+    ensure!(
+        !is_combining_mark(chars[0]),
+        "ENS label '{label}' starts with a combining mark"
+    );
+
+    let mut scripts = BTreeSet::new();
+    for (i, c) in chars.iter().copied().enumerate() {
+        let previous = i.checked_sub(1).map(|i| chars[i]);
+        let next = chars.get(i + 1).copied();
+        match c {
+            'a'..='z' => {
+                scripts.insert("Latin");
+            }
+            '0'..='9' | '-' | '$' => {}
+            '_' => ensure!(
+                chars[..i].iter().all(|c| *c == '_'),
+                "underscore in the middle of ENS label '{label}'"
+            ),
+            '\u{2019}' => ensure!(
+                i > 0 && next.is_some() && previous != Some(c) && next != Some(c),
+                "misplaced apostrophe in ENS label '{label}'"
+            ),
+            '\u{200d}' => ensure!(
+                previous.is_some_and(is_emoji) && next.is_some_and(is_emoji),
+                "zero width joiner outside of an emoji in ENS label '{label}'"
+            ),
+            '\u{1f3fb}'..='\u{1f3ff}' => ensure!(
+                previous.is_some_and(is_emoji),
+                "skin tone modifier outside of an emoji in ENS label '{label}'"
+            ),
+            '\u{e0020}'..='\u{e007f}' => ensure!(
+                matches!(previous, Some('\u{1f3f4}' | '\u{e0020}'..='\u{e007e}')),
+                "tag character outside of a flag in ENS label '{label}'"
+            ),
+            _ if c.is_ascii() => bail!("disallowed character {c:?} in ENS label '{label}'"),
+            _ => {
+                let Some((script, _)) = character(c) else {
+                    bail!("disallowed character {c:?} in ENS label '{label}'");
+                };
+                ensure!(
+                    !(is_combining_mark(c) && previous.is_some_and(is_emoji)),
+                    "combining mark after an emoji in ENS label '{label}'"
+                );
+                if script != COMMON && script != INHERITED {
+                    scripts.insert(SCRIPTS[script as usize]);
+                }
+            }
+        }
+    }
+
+    ensure!(
+        scripts.len() <= 1
+            || SCRIPT_COMBINATIONS
+                .iter()
+                .any(|allowed| scripts.iter().all(|script| allowed.contains(script))),
+        "ENS label '{label}' mixes the {} scripts",
+        scripts.into_iter().collect::<Vec<_>>().join(" and ")
+    );
+    if CONFUSABLE_SCRIPTS
+        .iter()
+        .any(|script| scripts.contains(script))
+    {
+        ensure!(
+            !chars
+                .iter()
+                .filter(|c| character(**c).is_some_and(|(script, _)| script != COMMON))
+                .all(|c| LATIN_LOOKALIKES.contains(*c)),
+            "ENS label '{label}' is confusable with a Latin label"
+        );
+    }
+
+    // NOTE: Non-spacing marks are checked on the decomposed label, where
+    // precomposed letters contribute their marks.
+    let mut marks = Vec::new();
+    for c in normalized.nfd() {
+        if !character(c).is_some_and(|(_, nsm)| nsm) {
+            marks.clear();
+            continue;
+        }
+        ensure!(
+            !marks.contains(&c),
+            "repeated non-spacing mark in ENS label '{label}'"
+        );
+        marks.push(c);
+        ensure!(
+            marks.len() <= 4,
+            "too many non-spacing marks in ENS label '{label}'"
+        );
+    }
+    Ok(normalized)
+}
+
+// Maps lowercase Cherokee letters to uppercase, as UTS-46 folds Cherokee to
+// its original uppercase letters.
+fn cherokee_uppercase(c: char) -> char {
+    match c {
+        '\u{13f8}'..='\u{13fd}' | '\u{ab70}'..='\u{abbf}' => c.to_uppercase().next().unwrap_or(c),
+        _ => c,
+    }
+}
+
+// Returns the script index of a valid character and whether it is a
+// non-spacing mark.
+fn character(c: char) -> Option<(u8, bool)> {
+    let c = c as u32;
+    let i = CHARACTERS
+        .binary_search_by(|(first, last, _, _)| {
+            if *last < c {
+                std::cmp::Ordering::Less
+            } else if *first > c {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .ok()?;
+    let (_, _, script, nsm) = CHARACTERS[i];
+    Some((script, nsm))
+}
+
+// Returns whether a character is removed during mapping: the soft hyphen,
+// the combining grapheme joiner and variation selectors.
+fn is_ignored(c: char) -> bool {
+    matches!(
+        c,
+        '\u{ad}'
+            | '\u{34f}'
+            | '\u{180b}'..='\u{180d}'
+            | '\u{180f}'
+            | '\u{fe00}'..='\u{fe0f}'
+            | '\u{1bca0}'..='\u{1bca3}'
+            | '\u{e0100}'..='\u{e01ef}'
+    )
+}
+
+// Returns whether a character is a pictographic emoji, which can be joined
+// with a zero width joiner and take a skin tone modifier.
+fn is_emoji(c: char) -> bool {
+    matches!(
+        c,
+        '\u{a9}'
+            | '\u{ae}'
+            | '\u{203c}'
+            | '\u{2049}'
+            | '\u{2122}'
+            | '\u{2139}'
+            | '\u{2194}'..='\u{21ff}'
+            | '\u{2300}'..='\u{23ff}'
+            | '\u{24c2}'
+            | '\u{25a0}'..='\u{27bf}'
+            | '\u{2934}'..='\u{2935}'
+            | '\u{2b00}'..='\u{2bff}'
+            | '\u{3030}'
+            | '\u{303d}'
+            | '\u{3297}'
+            | '\u{3299}'
+            | '\u{1f000}'..='\u{1f3fa}'
+            | '\u{1f400}'..='\u{1faff}'
+    )
+}
+
+fn label_hash(label: &str) -> [u8; 32] {
+    encoded_label_hash(label).unwrap_or_else(|| keccak256(label))
+}
+
+// Returns the hash of an encoded label, `[<64 hex digits>]`, which stands for
+// a label of which only the hash is known.
+fn encoded_label_hash(label: &str) -> Option<[u8; 32]> {
+    let hex = label.strip_prefix('[')?.strip_suffix(']')?;
+    let mut hash = [0; 32];
+    hex::decode_to_slice(hex, &mut hash).ok()?;
+    Some(hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    #[test]
+    fn namehashes() {
+        for (name, hash) in [
+            (
+                "",
+                hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+            ),
+            (
+                "eth",
+                hex!("93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae"),
+            ),
+            (
+                "foo.eth",
+                hex!("de9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f"),
+            ),
+            (
+                "vitalik.eth",
+                hex!("ee6c4522aab0003e8d14cd40a6af439055fd2577951148c14b6cea9a53475835"),
+            ),
+            (
+                "addr.reverse",
+                hex!("91d1777781884d03a6757a803996e38de2a42967fb37eeaca72729271025a9e2"),
+            ),
+            (
+                "Vitalik.ETH",
+                hex!("ee6c4522aab0003e8d14cd40a6af439055fd2577951148c14b6cea9a53475835"),
+            ),
+            (
+                "vitalik.[4f5b812789fc606be1b3b16908db13fc7a9adf7ca72641f84d75b47069d3d7f0]",
+                hex!("ee6c4522aab0003e8d14cd40a6af439055fd2577951148c14b6cea9a53475835"),
+            ),
+        ] {
+            assert_eq!(namehash(name).unwrap(), hash, "{name}");
+        }
+        assert_eq!(
+            labelhash("eth").unwrap(),
+            hex!("4f5b812789fc606be1b3b16908db13fc7a9adf7ca72641f84d75b47069d3d7f0"),
+        );
+        assert!(labelhash("foo.eth").is_err());
+    }
+
+    #[test]
+    fn normalization() {
+        for (name, normalized) in [
+            ("VITALIK.eth", "vitalik.eth"),
+            ("ＡＢＣ.eth", "abc.eth"),
+            ("ⓐⓑⓒ.eth", "abc.eth"),
+            ("Ünïcödé.eth", "ünïcödé.eth"),
+            ("❤\u{fe0f}.eth", "❤.eth"),
+            ("__abc.eth", "__abc.eth"),
+            ("$money.eth", "$money.eth"),
+            ("a-b-c.eth", "a-b-c.eth"),
+            ("so\u{ad}ft.eth", "soft.eth"),
+            ("o'neil.eth", "o\u{2019}neil.eth"),
+            ("привет.eth", "привет.eth"),
+            ("αβγ.eth", "αβγ.eth"),
+            ("\u{13aa}\u{13f4}.eth", "\u{13aa}\u{13f4}.eth"),
+            ("\u{ab7a}\u{13fc}.eth", "\u{13aa}\u{13f4}.eth"),
+            ("բարեւ.eth", "բարեւ.eth"),
+            ("日本ひらがなカタカナ.eth", "日本ひらがなカタカナ.eth"),
+            ("한국어漢字.eth", "한국어漢字.eth"),
+            (
+                "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}.eth",
+                "\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}.eth",
+            ),
+            ("\u{1f44d}\u{1f3fd}.eth", "\u{1f44d}\u{1f3fd}.eth"),
+            ("1\u{fe0f}\u{20e3}.eth", "1\u{20e3}.eth"),
+            (
+                "\u{1f3f4}\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}.eth",
+                "\u{1f3f4}\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}.eth",
+            ),
+            (
+                "e\u{301}\u{302}\u{303}\u{304}.eth",
+                "\u{e9}\u{302}\u{303}\u{304}.eth",
+            ),
+        ] {
+            assert_eq!(normalize(name).unwrap(), normalized, "{name}");
+        }
+        for name in [
+            "a..eth",
+            ".eth",
+            "eth.",
+            "a b.eth",
+            "a_b.eth",
+            "ab--c.eth",
+            "xn--ls8h.eth",
+            "hello!.eth",
+            "zero\u{200b}width.eth",
+            "\u{ad}.eth",
+            "αβ--γ.eth",
+            // Mixed scripts and whole-script confusables.
+            "vit\u{430}lik.eth",
+            "раура.eth",
+            "ορ.eth",
+            "օօ.eth",
+            // Combining marks.
+            "\u{301}abc.eth",
+            "e\u{301}\u{302}\u{303}\u{304}\u{305}.eth",
+            "a\u{301}\u{301}.eth",
+            "\u{1f44d}\u{301}.eth",
+            // Emoji sequence characters outside of emoji.
+            "a\u{200d}b.eth",
+            "\u{200d}.eth",
+            "\u{1f3fd}.eth",
+            "\u{e0067}.eth",
+            // Misplaced apostrophes.
+            "'abc.eth",
+            "abc'.eth",
+            "a''b.eth",
+            // Invisible and private use characters.
+            "\u{2800}.eth",
+            "\u{e000}.eth",
+        ] {
+            assert!(normalize(name).is_err(), "{name}");
+        }
+    }
+
+    #[test]
+    fn dns_encoding() {
+        assert_eq!(dns_encode("").unwrap(), [0]);
+        assert_eq!(dns_encode("Foo.eth").unwrap(), b"\x03foo\x03eth\x00");
+
+        let long = "a".repeat(300);
+        let encoded = dns_encode(&format!("{long}.eth")).unwrap();
+        assert_eq!(encoded[0], 66);
+        assert_eq!(
+            &encoded[1..67],
+            format!("[{}]", hex::encode(keccak256(&long))).as_bytes()
+        );
+    }
+
+    #[test]
+    fn reverse_names() {
+        let address = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+            .parse::<Address>()
+            .unwrap();
+        assert_eq!(
+            reverse_name(address),
+            "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed.addr.reverse"
+        );
+        assert_eq!(
+            reverse_name_for_chain(address, 10).unwrap(),
+            "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed.8000000a.reverse"
+        );
+        assert_eq!(
+            reverse_name_for_chain(address, 1).unwrap(),
+            reverse_name(address)
+        );
+        assert!(reverse_name_for_chain(address, 0x8000_0000).is_err());
+    }
+}
@@ -0,0 +1,1565 @@
+//! Character tables for ENS label validation, generated from the Unicode 14.0.0
+//! character database rather than the ENSIP-15 data. Every valid character
+//! belongs to a script, named after the prefix of its Unicode character name,
+//! or to the common or inherited pseudo-scripts; other characters are disallowed.
+
+/// Index of the script of characters shared by every script.
+pub const COMMON: u8 = 0;
+/// Index of the script of combining marks that take the script of their base.
+pub const INHERITED: u8 = 1;
+
+pub static SCRIPTS: [&str; 159] = [
+    "Common",
+    "Inherited",
+    "Latin",
+    "Greek",
+    "Coptic",
+    "Cyrillic",
+    "Armenian",
+    "Hebrew",
+    "Arabic",
+    "Syriac",
+    "Thaana",
+    "Nko",
+    "Samaritan",
+    "Mandaic",
+    "Devanagari",
+    "Bengali",
+    "Gurmukhi",
+    "Gujarati",
+    "Oriya",
+    "Tamil",
+    "Telugu",
+    "Kannada",
+    "Malayalam",
+    "Sinhala",
+    "Thai",
+    "Lao",
+    "Tibetan",
+    "Myanmar",
+    "Georgian",
+    "Hangul",
+    "Ethiopic",
+    "Cherokee",
+    "Canadian Syllabics",
+    "Ogham",
+    "Runic",
+    "Tagalog",
+    "Hanunoo",
+    "Buhid",
+    "Tagbanwa",
+    "Khmer",
+    "Mongolian",
+    "Limbu",
+    "Tai Le",
+    "New Tai Lue",
+    "Buginese",
+    "Tai Tham",
+    "Balinese",
+    "Sundanese",
+    "Batak",
+    "Lepcha",
+    "Ol Chiki",
+    "Glagolitic",
+    "Tifinagh",
+    "Han",
+    "Hiragana",
+    "Katakana",
+    "Bopomofo",
+    "Yi",
+    "Lisu",
+    "Vai",
+    "Bamum",
+    "Syloti Nagri",
+    "Phags Pa",
+    "Saurashtra",
+    "Kayah Li",
+    "Rejang",
+    "Javanese",
+    "Cham",
+    "Tai Viet",
+    "Meetei Mayek",
+    "Linear B",
+    "Lycian",
+    "Carian",
+    "Old Italic",
+    "Gothic",
+    "Old Permic",
+    "Ugaritic",
+    "Old Persian",
+    "Deseret",
+    "Shavian",
+    "Osmanya",
+    "Osage",
+    "Elbasan",
+    "Caucasian Albanian",
+    "Vithkuqi",
+    "Linear A",
+    "Cypriot",
+    "Imperial Aramaic",
+    "Palmyrene",
+    "Nabataean",
+    "Hatran",
+    "Phoenician",
+    "Lydian",
+    "Meroitic",
+    "Kharoshthi",
+    "Old South Arabian",
+    "Old North Arabian",
+    "Manichaean",
+    "Avestan",
+    "Inscriptional Parthian",
+    "Inscriptional Pahlavi",
+    "Psalter Pahlavi",
+    "Old Turkic",
+    "Old Hungarian",
+    "Hanifi Rohingya",
+    "Yezidi",
+    "Old Sogdian",
+    "Sogdian",
+    "Old Uyghur",
+    "Chorasmian",
+    "Elymaic",
+    "Brahmi",
+    "Kaithi",
+    "Sora",
+    "Chakma",
+    "Mahajani",
+    "Sharada",
+    "Khojki",
+    "Multani",
+    "Khudawadi",
+    "Grantha",
+    "Newa",
+    "Tirhuta",
+    "Siddham",
+    "Modi",
+    "Takri",
+    "Ahom",
+    "Dogra",
+    "Warang Citi",
+    "Dives Akuru",
+    "Nandinagari",
+    "Zanabazar Square",
+    "Soyombo",
+    "Pau Cin Hau",
+    "Bhaiksuki",
+    "Marchen",
+    "Masaram Gondi",
+    "Gunjala Gondi",
+    "Makasar",
+    "Cuneiform",
+    "Cypro Minoan",
+    "Egyptian",
+    "Anatolian",
+    "Mro",
+    "Tangsa",
+    "Bassa Vah",
+    "Pahawh Hmong",
+    "Medefaidrin",
+    "Miao",
+    "Tangut",
+    "Nushu",
+    "Khitan",
+    "Duployan",
+    "SignWriting",
+    "Nyiakeng Puachue Hmong",
+    "Toto",
+    "Wancho",
+    "Mende",
+    "Adlam",
+];
+
+/// Ranges of valid characters: first and last character, script index and
+/// whether the characters are non-spacing marks.
+pub static CHARACTERS: [(u32, u32, u8, bool); 1389] = [
+    (0x0024, 0x0024, 0, false),
+    (0x002B, 0x002B, 0, false),
+    (0x0030, 0x0039, 0, false),
+    (0x003C, 0x003E, 0, false),
+    (0x0041, 0x005A, 2, false),
+    (0x0061, 0x007A, 2, false),
+    (0x007C, 0x007C, 0, false),
+    (0x007E, 0x007E, 0, false),
+    (0x00A2, 0x00A6, 0, false),
+    (0x00A9, 0x00AA, 0, false),
+    (0x00AC, 0x00AC, 0, false),
+    (0x00AE, 0x00AE, 0, false),
+    (0x00B0, 0x00B3, 0, false),
+    (0x00B5, 0x00B5, 0, false),
+    (0x00B9, 0x00BA, 0, false),
+    (0x00BC, 0x00BE, 0, false),
+    (0x00C0, 0x00D6, 2, false),
+    (0x00D7, 0x00D7, 0, false),
+    (0x00D8, 0x00F6, 2, false),
+    (0x00F7, 0x00F7, 0, false),
+    (0x00F8, 0x02AF, 2, false),
+    (0x02B0, 0x02C1, 0, false),
+    (0x02C6, 0x02D1, 0, false),
+    (0x02E0, 0x02E4, 0, false),
+    (0x02EC, 0x02EC, 0, false),
+    (0x02EE, 0x02EE, 0, false),
+    (0x0300, 0x036F, 1, true),
+    (0x0370, 0x0374, 3, false),
+    (0x0376, 0x0377, 3, false),
+    (0x037A, 0x037D, 3, false),
+    (0x037F, 0x037F, 3, false),
+    (0x0386, 0x0386, 3, false),
+    (0x0388, 0x038A, 3, false),
+    (0x038C, 0x038C, 3, false),
+    (0x038E, 0x03A1, 3, false),
+    (0x03A3, 0x03E1, 3, false),
+    (0x03E2, 0x03EF, 4, false),
+    (0x03F0, 0x03F5, 3, false),
+    (0x03F6, 0x03F6, 0, false),
+    (0x03F7, 0x03FF, 3, false),
+    (0x0400, 0x0481, 5, false),
+    (0x0482, 0x0482, 0, false),
+    (0x0483, 0x0489, 1, true),
+    (0x048A, 0x052F, 5, false),
+    (0x0531, 0x0556, 6, false),
+    (0x0559, 0x0559, 6, false),
+    (0x0560, 0x0588, 6, false),
+    (0x058D, 0x058F, 0, false),
+    (0x0591, 0x05BD, 7, true),
+    (0x05BF, 0x05BF, 7, true),
+    (0x05C1, 0x05C2, 7, true),
+    (0x05C4, 0x05C5, 7, true),
+    (0x05C7, 0x05C7, 7, true),
+    (0x05D0, 0x05EA, 7, false),
+    (0x05EF, 0x05F2, 7, false),
+    (0x0606, 0x0608, 0, false),
+    (0x060B, 0x060B, 0, false),
+    (0x060E, 0x060F, 0, false),
+    (0x0610, 0x061A, 8, true),
+    (0x0620, 0x064A, 8, false),
+    (0x064B, 0x065F, 8, true),
+    (0x0660, 0x0669, 8, false),
+    (0x066E, 0x066F, 8, false),
+    (0x0670, 0x0670, 8, true),
+    (0x0671, 0x06D3, 8, false),
+    (0x06D5, 0x06D5, 8, false),
+    (0x06D6, 0x06DC, 8, true),
+    (0x06DE, 0x06DE, 0, false),
+    (0x06DF, 0x06E4, 8, true),
+    (0x06E5, 0x06E6, 8, false),
+    (0x06E7, 0x06E8, 8, true),
+    (0x06E9, 0x06E9, 0, false),
+    (0x06EA, 0x06ED, 8, true),
+    (0x06EE, 0x06FC, 8, false),
+    (0x06FD, 0x06FE, 0, false),
+    (0x06FF, 0x06FF, 8, false),
+    (0x0710, 0x0710, 9, false),
+    (0x0711, 0x0711, 9, true),
+    (0x0712, 0x072F, 9, false),
+    (0x0730, 0x074A, 9, true),
+    (0x074D, 0x074F, 9, false),
+    (0x0750, 0x077F, 8, false),
+    (0x0780, 0x07A5, 10, false),
+    (0x07A6, 0x07B0, 10, true),
+    (0x07B1, 0x07B1, 10, false),
+    (0x07C0, 0x07EA, 11, false),
+    (0x07EB, 0x07F3, 1, true),
+    (0x07F4, 0x07F5, 11, false),
+    (0x07F6, 0x07F6, 0, false),
+    (0x07FA, 0x07FA, 11, false),
+    (0x07FD, 0x07FD, 11, true),
+    (0x07FE, 0x07FF, 0, false),
+    (0x0800, 0x0815, 12, false),
+    (0x0816, 0x0819, 12, true),
+    (0x081A, 0x081A, 12, false),
+    (0x081B, 0x0823, 12, true),
+    (0x0824, 0x0824, 12, false),
+    (0x0825, 0x0827, 12, true),
+    (0x0828, 0x0828, 12, false),
+    (0x0829, 0x082D, 12, true),
+    (0x0840, 0x0858, 13, false),
+    (0x0859, 0x085B, 13, true),
+    (0x0860, 0x086A, 9, false),
+    (0x0870, 0x0887, 8, false),
+    (0x0889, 0x088E, 8, false),
+    (0x0898, 0x089F, 8, true),
+    (0x08A0, 0x08C9, 8, false),
+    (0x08CA, 0x08E1, 8, true),
+    (0x08E3, 0x08FF, 8, true),
+    (0x0900, 0x0902, 14, true),
+    (0x0903, 0x0939, 14, false),
+    (0x093A, 0x093A, 14, true),
+    (0x093B, 0x093B, 14, false),
+    (0x093C, 0x093C, 14, true),
+    (0x093D, 0x0940, 14, false),
+    (0x0941, 0x0948, 14, true),
+    (0x0949, 0x094C, 14, false),
+    (0x094D, 0x094D, 14, true),
+    (0x094E, 0x0950, 14, false),
+    (0x0951, 0x0957, 14, true),
+    (0x0958, 0x0961, 14, false),
+    (0x0962, 0x0963, 14, true),
+    (0x0966, 0x096F, 14, false),
+    (0x0971, 0x097F, 14, false),
+    (0x0980, 0x0980, 15, false),
+    (0x0981, 0x0981, 15, true),
+    (0x0982, 0x0983, 15, false),
+    (0x0985, 0x098C, 15, false),
+    (0x098F, 0x0990, 15, false),
+    (0x0993, 0x09A8, 15, false),
+    (0x09AA, 0x09B0, 15, false),
+    (0x09B2, 0x09B2, 15, false),
+    (0x09B6, 0x09B9, 15, false),
+    (0x09BC, 0x09BC, 15, true),
+    (0x09BD, 0x09C0, 15, false),
+    (0x09C1, 0x09C4, 15, true),
+    (0x09C7, 0x09C8, 15, false),
+    (0x09CB, 0x09CC, 15, false),
+    (0x09CD, 0x09CD, 15, true),
+    (0x09CE, 0x09CE, 15, false),
+    (0x09D7, 0x09D7, 15, false),
+    (0x09DC, 0x09DD, 15, false),
+    (0x09DF, 0x09E1, 15, false),
+    (0x09E2, 0x09E3, 15, true),
+    (0x09E6, 0x09F1, 15, false),
+    (0x09F2, 0x09F3, 0, false),
+    (0x09F4, 0x09F9, 15, false),
+    (0x09FA, 0x09FB, 0, false),
+    (0x09FC, 0x09FC, 15, false),
+    (0x09FE, 0x09FE, 15, true),
+    (0x0A01, 0x0A02, 16, true),
+    (0x0A03, 0x0A03, 16, false),
+    (0x0A05, 0x0A0A, 16, false),
+    (0x0A0F, 0x0A10, 16, false),
+    (0x0A13, 0x0A28, 16, false),
+    (0x0A2A, 0x0A30, 16, false),
+    (0x0A32, 0x0A33, 16, false),
+    (0x0A35, 0x0A36, 16, false),
+    (0x0A38, 0x0A39, 16, false),
+    (0x0A3C, 0x0A3C, 16, true),
+    (0x0A3E, 0x0A40, 16, false),
+    (0x0A41, 0x0A42, 16, true),
+    (0x0A47, 0x0A48, 16, true),
+    (0x0A4B, 0x0A4D, 16, true),
+    (0x0A51, 0x0A51, 16, true),
+    (0x0A59, 0x0A5C, 16, false),
+    (0x0A5E, 0x0A5E, 16, false),
+    (0x0A66, 0x0A6F, 16, false),
+    (0x0A70, 0x0A71, 16, true),
+    (0x0A72, 0x0A74, 16, false),
+    (0x0A75, 0x0A75, 16, true),
+    (0x0A81, 0x0A82, 17, true),
+    (0x0A83, 0x0A83, 17, false),
+    (0x0A85, 0x0A8D, 17, false),
+    (0x0A8F, 0x0A91, 17, false),
+    (0x0A93, 0x0AA8, 17, false),
+    (0x0AAA, 0x0AB0, 17, false),
+    (0x0AB2, 0x0AB3, 17, false),
+    (0x0AB5, 0x0AB9, 17, false),
+    (0x0ABC, 0x0ABC, 17, true),
+    (0x0ABD, 0x0AC0, 17, false),
+    (0x0AC1, 0x0AC5, 17, true),
+    (0x0AC7, 0x0AC8, 17, true),
+    (0x0AC9, 0x0AC9, 17, false),
+    (0x0ACB, 0x0ACC, 17, false),
+    (0x0ACD, 0x0ACD, 17, true),
+    (0x0AD0, 0x0AD0, 17, false),
+    (0x0AE0, 0x0AE1, 17, false),
+    (0x0AE2, 0x0AE3, 17, true),
+    (0x0AE6, 0x0AEF, 17, false),
+    (0x0AF1, 0x0AF1, 0, false),
+    (0x0AF9, 0x0AF9, 17, false),
+    (0x0AFA, 0x0AFF, 17, true),
+    (0x0B01, 0x0B01, 18, true),
+    (0x0B02, 0x0B03, 18, false),
+    (0x0B05, 0x0B0C, 18, false),
+    (0x0B0F, 0x0B10, 18, false),
+    (0x0B13, 0x0B28, 18, false),
+    (0x0B2A, 0x0B30, 18, false),
+    (0x0B32, 0x0B33, 18, false),
+    (0x0B35, 0x0B39, 18, false),
+    (0x0B3C, 0x0B3C, 18, true),
+    (0x0B3D, 0x0B3E, 18, false),
+    (0x0B3F, 0x0B3F, 18, true),
+    (0x0B40, 0x0B40, 18, false),
+    (0x0B41, 0x0B44, 18, true),
+    (0x0B47, 0x0B48, 18, false),
+    (0x0B4B, 0x0B4C, 18, false),
+    (0x0B4D, 0x0B4D, 18, true),
+    (0x0B55, 0x0B56, 18, true),
+    (0x0B57, 0x0B57, 18, false),
+    (0x0B5C, 0x0B5D, 18, false),
+    (0x0B5F, 0x0B61, 18, false),
+    (0x0B62, 0x0B63, 18, true),
+    (0x0B66, 0x0B6F, 18, false),
+    (0x0B70, 0x0B70, 0, false),
+    (0x0B71, 0x0B77, 18, false),
+    (0x0B82, 0x0B82, 19, true),
+    (0x0B83, 0x0B83, 19, false),
+    (0x0B85, 0x0B8A, 19, false),
+    (0x0B8E, 0x0B90, 19, false),
+    (0x0B92, 0x0B95, 19, false),
+    (0x0B99, 0x0B9A, 19, false),
+    (0x0B9C, 0x0B9C, 19, false),
+    (0x0B9E, 0x0B9F, 19, false),
+    (0x0BA3, 0x0BA4, 19, false),
+    (0x0BA8, 0x0BAA, 19, false),
+    (0x0BAE, 0x0BB9, 19, false),
+    (0x0BBE, 0x0BBF, 19, false),
+    (0x0BC0, 0x0BC0, 19, true),
+    (0x0BC1, 0x0BC2, 19, false),
+    (0x0BC6, 0x0BC8, 19, false),
+    (0x0BCA, 0x0BCC, 19, false),
+    (0x0BCD, 0x0BCD, 19, true),
+    (0x0BD0, 0x0BD0, 19, false),
+    (0x0BD7, 0x0BD7, 19, false),
+    (0x0BE6, 0x0BF2, 19, false),
+    (0x0BF3, 0x0BFA, 0, false),
+    (0x0C00, 0x0C00, 1, true),
+    (0x0C01, 0x0C03, 20, false),
+    (0x0C04, 0x0C04, 1, true),
+    (0x0C05, 0x0C0C, 20, false),
+    (0x0C0E, 0x0C10, 20, false),
+    (0x0C12, 0x0C28, 20, false),
+    (0x0C2A, 0x0C39, 20, false),
+    (0x0C3C, 0x0C3C, 20, true),
+    (0x0C3D, 0x0C3D, 20, false),
+    (0x0C3E, 0x0C40, 20, true),
+    (0x0C41, 0x0C44, 20, false),
+    (0x0C46, 0x0C48, 20, true),
+    (0x0C4A, 0x0C4D, 20, true),
+    (0x0C55, 0x0C56, 20, true),
+    (0x0C58, 0x0C5A, 20, false),
+    (0x0C5D, 0x0C5D, 20, false),
+    (0x0C60, 0x0C61, 20, false),
+    (0x0C62, 0x0C63, 20, true),
+    (0x0C66, 0x0C6F, 20, false),
+    (0x0C78, 0x0C7E, 20, false),
+    (0x0C7F, 0x0C7F, 0, false),
+    (0x0C80, 0x0C80, 21, false),
+    (0x0C81, 0x0C81, 21, true),
+    (0x0C82, 0x0C83, 21, false),
+    (0x0C85, 0x0C8C, 21, false),
+    (0x0C8E, 0x0C90, 21, false),
+    (0x0C92, 0x0CA8, 21, false),
+    (0x0CAA, 0x0CB3, 21, false),
+    (0x0CB5, 0x0CB9, 21, false),
+    (0x0CBC, 0x0CBC, 21, true),
+    (0x0CBD, 0x0CBE, 21, false),
+    (0x0CBF, 0x0CBF, 21, true),
+    (0x0CC0, 0x0CC4, 21, false),
+    (0x0CC6, 0x0CC6, 21, true),
+    (0x0CC7, 0x0CC8, 21, false),
+    (0x0CCA, 0x0CCB, 21, false),
+    (0x0CCC, 0x0CCD, 21, true),
+    (0x0CD5, 0x0CD6, 21, false),
+    (0x0CDD, 0x0CDE, 21, false),
+    (0x0CE0, 0x0CE1, 21, false),
+    (0x0CE2, 0x0CE3, 21, true),
+    (0x0CE6, 0x0CEF, 21, false),
+    (0x0CF1, 0x0CF2, 21, false),
+    (0x0D00, 0x0D00, 1, true),
+    (0x0D01, 0x0D01, 22, true),
+    (0x0D02, 0x0D0C, 22, false),
+    (0x0D0E, 0x0D10, 22, false),
+    (0x0D12, 0x0D3A, 22, false),
+    (0x0D3B, 0x0D3C, 22, true),
+    (0x0D3D, 0x0D40, 22, false),
+    (0x0D41, 0x0D44, 22, true),
+    (0x0D46, 0x0D48, 22, false),
+    (0x0D4A, 0x0D4C, 22, false),
+    (0x0D4D, 0x0D4D, 22, true),
+    (0x0D4E, 0x0D4E, 22, false),
+    (0x0D4F, 0x0D4F, 0, false),
+    (0x0D54, 0x0D61, 22, false),
+    (0x0D62, 0x0D63, 22, true),
+    (0x0D66, 0x0D78, 22, false),
+    (0x0D79, 0x0D79, 0, false),
+    (0x0D7A, 0x0D7F, 22, false),
+    (0x0D81, 0x0D81, 23, true),
+    (0x0D82, 0x0D83, 23, false),
+    (0x0D85, 0x0D96, 23, false),
+    (0x0D9A, 0x0DB1, 23, false),
+    (0x0DB3, 0x0DBB, 23, false),
+    (0x0DBD, 0x0DBD, 23, false),
+    (0x0DC0, 0x0DC6, 23, false),
+    (0x0DCA, 0x0DCA, 23, true),
+    (0x0DCF, 0x0DD1, 23, false),
+    (0x0DD2, 0x0DD4, 23, true),
+    (0x0DD6, 0x0DD6, 23, true),
+    (0x0DD8, 0x0DDF, 23, false),
+    (0x0DE6, 0x0DEF, 23, false),
+    (0x0DF2, 0x0DF3, 23, false),
+    (0x0E01, 0x0E30, 24, false),
+    (0x0E31, 0x0E31, 24, true),
+    (0x0E32, 0x0E33, 24, false),
+    (0x0E34, 0x0E3A, 24, true),
+    (0x0E3F, 0x0E3F, 0, false),
+    (0x0E40, 0x0E46, 24, false),
+    (0x0E47, 0x0E4E, 24, true),
+    (0x0E50, 0x0E59, 24, false),
+    (0x0E81, 0x0E82, 25, false),
+    (0x0E84, 0x0E84, 25, false),
+    (0x0E86, 0x0E8A, 25, false),
+    (0x0E8C, 0x0EA3, 25, false),
+    (0x0EA5, 0x0EA5, 25, false),
+    (0x0EA7, 0x0EB0, 25, false),
+    (0x0EB1, 0x0EB1, 25, true),
+    (0x0EB2, 0x0EB3, 25, false),
+    (0x0EB4, 0x0EBC, 25, true),
+    (0x0EBD, 0x0EBD, 25, false),
+    (0x0EC0, 0x0EC4, 25, false),
+    (0x0EC6, 0x0EC6, 25, false),
+    (0x0EC8, 0x0ECD, 25, true),
+    (0x0ED0, 0x0ED9, 25, false),
+    (0x0EDC, 0x0EDF, 25, false),
+    (0x0F00, 0x0F00, 26, false),
+    (0x0F01, 0x0F03, 0, false),
+    (0x0F13, 0x0F13, 0, false),
+    (0x0F15, 0x0F17, 0, false),
+    (0x0F18, 0x0F19, 26, true),
+    (0x0F1A, 0x0F1F, 0, false),
+    (0x0F20, 0x0F33, 26, false),
+    (0x0F34, 0x0F34, 0, false),
+    (0x0F35, 0x0F35, 26, true),
+    (0x0F36, 0x0F36, 0, false),
+    (0x0F37, 0x0F37, 26, true),
+    (0x0F38, 0x0F38, 0, false),
+    (0x0F39, 0x0F39, 26, true),
+    (0x0F3E, 0x0F47, 26, false),
+    (0x0F49, 0x0F6C, 26, false),
+    (0x0F71, 0x0F7E, 26, true),
+    (0x0F7F, 0x0F7F, 26, false),
+    (0x0F80, 0x0F84, 26, true),
+    (0x0F86, 0x0F87, 26, true),
+    (0x0F88, 0x0F8C, 26, false),
+    (0x0F8D, 0x0F97, 26, true),
+    (0x0F99, 0x0FBC, 26, true),
+    (0x0FBE, 0x0FC5, 0, false),
+    (0x0FC6, 0x0FC6, 26, true),
+    (0x0FC7, 0x0FCC, 0, false),
+    (0x0FCE, 0x0FCF, 0, false),
+    (0x0FD5, 0x0FD8, 0, false),
+    (0x1000, 0x102C, 27, false),
+    (0x102D, 0x1030, 27, true),
+    (0x1031, 0x1031, 27, false),
+    (0x1032, 0x1037, 27, true),
+    (0x1038, 0x1038, 27, false),
+    (0x1039, 0x103A, 27, true),
+    (0x103B, 0x103C, 27, false),
+    (0x103D, 0x103E, 27, true),
+    (0x103F, 0x1049, 27, false),
+    (0x1050, 0x1057, 27, false),
+    (0x1058, 0x1059, 27, true),
+    (0x105A, 0x105D, 27, false),
+    (0x105E, 0x1060, 27, true),
+    (0x1061, 0x1070, 27, false),
+    (0x1071, 0x1074, 27, true),
+    (0x1075, 0x1081, 27, false),
+    (0x1082, 0x1082, 27, true),
+    (0x1083, 0x1084, 27, false),
+    (0x1085, 0x1086, 27, true),
+    (0x1087, 0x108C, 27, false),
+    (0x108D, 0x108D, 27, true),
+    (0x108E, 0x109C, 27, false),
+    (0x109D, 0x109D, 27, true),
+    (0x109E, 0x109F, 0, false),
+    (0x10A0, 0x10C5, 28, false),
+    (0x10C7, 0x10C7, 28, false),
+    (0x10CD, 0x10CD, 28, false),
+    (0x10D0, 0x10FA, 28, false),
+    (0x10FC, 0x10FC, 0, false),
+    (0x10FD, 0x10FF, 28, false),
+    (0x1100, 0x115E, 29, false),
+    (0x1161, 0x11FF, 29, false),
+    (0x1200, 0x1248, 30, false),
+    (0x124A, 0x124D, 30, false),
+    (0x1250, 0x1256, 30, false),
+    (0x1258, 0x1258, 30, false),
+    (0x125A, 0x125D, 30, false),
+    (0x1260, 0x1288, 30, false),
+    (0x128A, 0x128D, 30, false),
+    (0x1290, 0x12B0, 30, false),
+    (0x12B2, 0x12B5, 30, false),
+    (0x12B8, 0x12BE, 30, false),
+    (0x12C0, 0x12C0, 30, false),
+    (0x12C2, 0x12C5, 30, false),
+    (0x12C8, 0x12D6, 30, false),
+    (0x12D8, 0x1310, 30, false),
+    (0x1312, 0x1315, 30, false),
+    (0x1318, 0x135A, 30, false),
+    (0x135D, 0x135F, 1, true),
+    (0x1369, 0x137C, 30, false),
+    (0x1380, 0x138F, 30, false),
+    (0x1390, 0x1399, 0, false),
+    (0x13A0, 0x13F5, 31, false),
+    (0x13F8, 0x13FD, 31, false),
+    (0x1401, 0x166C, 32, false),
+    (0x166D, 0x166D, 0, false),
+    (0x166F, 0x167F, 32, false),
+    (0x1681, 0x169A, 33, false),
+    (0x16A0, 0x16EA, 34, false),
+    (0x16EE, 0x16F8, 34, false),
+    (0x1700, 0x1711, 35, false),
+    (0x1712, 0x1714, 35, true),
+    (0x1715, 0x1715, 35, false),
+    (0x171F, 0x171F, 35, false),
+    (0x1720, 0x1731, 36, false),
+    (0x1732, 0x1733, 36, true),
+    (0x1734, 0x1734, 36, false),
+    (0x1740, 0x1751, 37, false),
+    (0x1752, 0x1753, 37, true),
+    (0x1760, 0x176C, 38, false),
+    (0x176E, 0x1770, 38, false),
+    (0x1772, 0x1773, 38, true),
+    (0x1780, 0x17B3, 39, false),
+    (0x17B6, 0x17B6, 39, false),
+    (0x17B7, 0x17BD, 39, true),
+    (0x17BE, 0x17C5, 39, false),
+    (0x17C6, 0x17C6, 39, true),
+    (0x17C7, 0x17C8, 39, false),
+    (0x17C9, 0x17D3, 39, true),
+    (0x17D7, 0x17D7, 39, false),
+    (0x17DB, 0x17DB, 0, false),
+    (0x17DC, 0x17DC, 39, false),
+    (0x17DD, 0x17DD, 39, true),
+    (0x17E0, 0x17E9, 39, false),
+    (0x17F0, 0x17F9, 39, false),
+    (0x180B, 0x180D, 40, true),
+    (0x180F, 0x180F, 40, true),
+    (0x1810, 0x1819, 40, false),
+    (0x1820, 0x1878, 40, false),
+    (0x1880, 0x1884, 40, false),
+    (0x1885, 0x1886, 40, true),
+    (0x1887, 0x18A8, 40, false),
+    (0x18A9, 0x18A9, 40, true),
+    (0x18AA, 0x18AA, 40, false),
+    (0x18B0, 0x18F5, 32, false),
+    (0x1900, 0x191E, 41, false),
+    (0x1920, 0x1922, 41, true),
+    (0x1923, 0x1926, 41, false),
+    (0x1927, 0x1928, 41, true),
+    (0x1929, 0x192B, 41, false),
+    (0x1930, 0x1931, 41, false),
+    (0x1932, 0x1932, 41, true),
+    (0x1933, 0x1938, 41, false),
+    (0x1939, 0x193B, 41, true),
+    (0x1940, 0x1940, 0, false),
+    (0x1946, 0x194F, 41, false),
+    (0x1950, 0x196D, 42, false),
+    (0x1970, 0x1974, 42, false),
+    (0x1980, 0x19AB, 43, false),
+    (0x19B0, 0x19C9, 43, false),
+    (0x19D0, 0x19DA, 43, false),
+    (0x19DE, 0x19FF, 0, false),
+    (0x1A00, 0x1A16, 44, false),
+    (0x1A17, 0x1A18, 44, true),
+    (0x1A19, 0x1A1A, 44, false),
+    (0x1A1B, 0x1A1B, 44, true),
+    (0x1A20, 0x1A55, 45, false),
+    (0x1A56, 0x1A56, 45, true),
+    (0x1A57, 0x1A57, 45, false),
+    (0x1A58, 0x1A5E, 45, true),
+    (0x1A60, 0x1A60, 45, true),
+    (0x1A61, 0x1A61, 45, false),
+    (0x1A62, 0x1A62, 45, true),
+    (0x1A63, 0x1A64, 45, false),
+    (0x1A65, 0x1A6C, 45, true),
+    (0x1A6D, 0x1A72, 45, false),
+    (0x1A73, 0x1A7C, 45, true),
+    (0x1A7F, 0x1A7F, 1, true),
+    (0x1A80, 0x1A89, 45, false),
+    (0x1A90, 0x1A99, 45, false),
+    (0x1AA7, 0x1AA7, 45, false),
+    (0x1AB0, 0x1ACE, 1, true),
+    (0x1B00, 0x1B03, 46, true),
+    (0x1B04, 0x1B33, 46, false),
+    (0x1B34, 0x1B34, 46, true),
+    (0x1B35, 0x1B35, 46, false),
+    (0x1B36, 0x1B3A, 46, true),
+    (0x1B3B, 0x1B3B, 46, false),
+    (0x1B3C, 0x1B3C, 46, true),
+    (0x1B3D, 0x1B41, 46, false),
+    (0x1B42, 0x1B42, 46, true),
+    (0x1B43, 0x1B4C, 46, false),
+    (0x1B50, 0x1B59, 46, false),
+    (0x1B61, 0x1B6A, 0, false),
+    (0x1B6B, 0x1B73, 1, true),
+    (0x1B74, 0x1B7C, 0, false),
+    (0x1B80, 0x1B81, 47, true),
+    (0x1B82, 0x1BA1, 47, false),
+    (0x1BA2, 0x1BA5, 47, true),
+    (0x1BA6, 0x1BA7, 47, false),
+    (0x1BA8, 0x1BA9, 47, true),
+    (0x1BAA, 0x1BAA, 47, false),
+    (0x1BAB, 0x1BAD, 47, true),
+    (0x1BAE, 0x1BBF, 47, false),
+    (0x1BC0, 0x1BE5, 48, false),
+    (0x1BE6, 0x1BE6, 48, true),
+    (0x1BE7, 0x1BE7, 48, false),
+    (0x1BE8, 0x1BE9, 48, true),
+    (0x1BEA, 0x1BEC, 48, false),
+    (0x1BED, 0x1BED, 48, true),
+    (0x1BEE, 0x1BEE, 48, false),
+    (0x1BEF, 0x1BF1, 48, true),
+    (0x1BF2, 0x1BF3, 48, false),
+    (0x1C00, 0x1C2B, 49, false),
+    (0x1C2C, 0x1C33, 49, true),
+    (0x1C34, 0x1C35, 49, false),
+    (0x1C36, 0x1C37, 49, true),
+    (0x1C40, 0x1C49, 49, false),
+    (0x1C4D, 0x1C4F, 49, false),
+    (0x1C50, 0x1C7D, 50, false),
+    (0x1C80, 0x1C88, 5, false),
+    (0x1C90, 0x1CBA, 28, false),
+    (0x1CBD, 0x1CBF, 28, false),
+    (0x1CD0, 0x1CD2, 1, true),
+    (0x1CD4, 0x1CE0, 1, true),
+    (0x1CE1, 0x1CE1, 1, false),
+    (0x1CE2, 0x1CE8, 1, true),
+    (0x1CE9, 0x1CEC, 1, false),
+    (0x1CED, 0x1CED, 1, true),
+    (0x1CEE, 0x1CF3, 1, false),
+    (0x1CF4, 0x1CF4, 1, true),
+    (0x1CF5, 0x1CF7, 1, false),
+    (0x1CF8, 0x1CF9, 1, true),
+    (0x1CFA, 0x1CFA, 1, false),
+    (0x1D00, 0x1D25, 2, false),
+    (0x1D26, 0x1D2A, 3, false),
+    (0x1D2B, 0x1D2B, 5, false),
+    (0x1D2C, 0x1D61, 0, false),
+    (0x1D62, 0x1D65, 2, false),
+    (0x1D66, 0x1D6A, 3, false),
+    (0x1D6B, 0x1D77, 2, false),
+    (0x1D78, 0x1D78, 0, false),
+    (0x1D79, 0x1D9A, 2, false),
+    (0x1D9B, 0x1DBF, 0, false),
+    (0x1DC0, 0x1DFF, 1, true),
+    (0x1E00, 0x1EFF, 2, false),
+    (0x1F00, 0x1F15, 3, false),
+    (0x1F18, 0x1F1D, 3, false),
+    (0x1F20, 0x1F45, 3, false),
+    (0x1F48, 0x1F4D, 3, false),
+    (0x1F50, 0x1F57, 3, false),
+    (0x1F59, 0x1F59, 3, false),
+    (0x1F5B, 0x1F5B, 3, false),
+    (0x1F5D, 0x1F5D, 3, false),
+    (0x1F5F, 0x1F7D, 3, false),
+    (0x1F80, 0x1FB4, 3, false),
+    (0x1FB6, 0x1FBC, 3, false),
+    (0x1FBE, 0x1FBE, 3, false),
+    (0x1FC2, 0x1FC4, 3, false),
+    (0x1FC6, 0x1FCC, 3, false),
+    (0x1FD0, 0x1FD3, 3, false),
+    (0x1FD6, 0x1FDB, 3, false),
+    (0x1FE0, 0x1FEC, 3, false),
+    (0x1FF2, 0x1FF4, 3, false),
+    (0x1FF6, 0x1FFC, 3, false),
+    (0x2044, 0x2044, 0, false),
+    (0x2052, 0x2052, 0, false),
+    (0x2070, 0x2071, 0, false),
+    (0x2074, 0x207C, 0, false),
+    (0x207F, 0x208C, 0, false),
+    (0x2090, 0x209C, 2, false),
+    (0x20A0, 0x20C0, 0, false),
+    (0x20D0, 0x20F0, 1, true),
+    (0x2100, 0x2183, 0, false),
+    (0x2184, 0x2184, 2, false),
+    (0x2185, 0x218B, 0, false),
+    (0x2190, 0x2307, 0, false),
+    (0x230C, 0x2328, 0, false),
+    (0x232B, 0x2426, 0, false),
+    (0x2440, 0x244A, 0, false),
+    (0x2460, 0x2767, 0, false),
+    (0x2776, 0x27C4, 0, false),
+    (0x27C7, 0x27E5, 0, false),
+    (0x27F0, 0x27FF, 0, false),
+    (0x2801, 0x2982, 0, false),
+    (0x2999, 0x29D7, 0, false),
+    (0x29DC, 0x29FB, 0, false),
+    (0x29FE, 0x2B73, 0, false),
+    (0x2B76, 0x2B95, 0, false),
+    (0x2B97, 0x2BFF, 0, false),
+    (0x2C00, 0x2C5F, 51, false),
+    (0x2C60, 0x2C7C, 2, false),
+    (0x2C7D, 0x2C7D, 0, false),
+    (0x2C7E, 0x2C7F, 2, false),
+    (0x2C80, 0x2CE4, 4, false),
+    (0x2CE5, 0x2CEA, 0, false),
+    (0x2CEB, 0x2CEE, 4, false),
+    (0x2CEF, 0x2CF1, 1, true),
+    (0x2CF2, 0x2CF3, 4, false),
+    (0x2CFD, 0x2CFD, 4, false),
+    (0x2D00, 0x2D25, 28, false),
+    (0x2D27, 0x2D27, 28, false),
+    (0x2D2D, 0x2D2D, 28, false),
+    (0x2D30, 0x2D67, 52, false),
+    (0x2D6F, 0x2D6F, 52, false),
+    (0x2D7F, 0x2D7F, 52, true),
+    (0x2D80, 0x2D96, 30, false),
+    (0x2DA0, 0x2DA6, 30, false),
+    (0x2DA8, 0x2DAE, 30, false),
+    (0x2DB0, 0x2DB6, 30, false),
+    (0x2DB8, 0x2DBE, 30, false),
+    (0x2DC0, 0x2DC6, 30, false),
+    (0x2DC8, 0x2DCE, 30, false),
+    (0x2DD0, 0x2DD6, 30, false),
+    (0x2DD8, 0x2DDE, 30, false),
+    (0x2DE0, 0x2DFF, 1, true),
+    (0x2E2F, 0x2E2F, 0, false),
+    (0x2E50, 0x2E51, 0, false),
+    (0x2E80, 0x2E99, 0, false),
+    (0x2E9B, 0x2EF3, 0, false),
+    (0x2F00, 0x2FD5, 0, false),
+    (0x2FF0, 0x2FFB, 0, false),
+    (0x3004, 0x3004, 0, false),
+    (0x3005, 0x3007, 53, false),
+    (0x3012, 0x3013, 0, false),
+    (0x3020, 0x3020, 0, false),
+    (0x3021, 0x3029, 53, false),
+    (0x302A, 0x302D, 53, true),
+    (0x302E, 0x302F, 29, false),
+    (0x3031, 0x3037, 0, false),
+    (0x3038, 0x303A, 53, false),
+    (0x303B, 0x303C, 0, false),
+    (0x303E, 0x303F, 0, false),
+    (0x3041, 0x3096, 54, false),
+    (0x3099, 0x309A, 1, true),
+    (0x309D, 0x309F, 54, false),
+    (0x30A1, 0x30FA, 55, false),
+    (0x30FC, 0x30FF, 55, false),
+    (0x3105, 0x312F, 56, false),
+    (0x3131, 0x3163, 29, false),
+    (0x3165, 0x318E, 29, false),
+    (0x3190, 0x3191, 0, false),
+    (0x3192, 0x3195, 53, false),
+    (0x3196, 0x319F, 0, false),
+    (0x31A0, 0x31BF, 56, false),
+    (0x31C0, 0x31E3, 0, false),
+    (0x31F0, 0x31FF, 55, false),
+    (0x3200, 0x321E, 0, false),
+    (0x3220, 0x33FF, 0, false),
+    (0x3400, 0x4DBF, 53, false),
+    (0x4DC0, 0x4DFF, 0, false),
+    (0x4E00, 0x9FFF, 53, false),
+    (0xA000, 0xA48C, 57, false),
+    (0xA490, 0xA4C6, 0, false),
+    (0xA4D0, 0xA4FD, 58, false),
+    (0xA500, 0xA60C, 59, false),
+    (0xA610, 0xA62B, 59, false),
+    (0xA640, 0xA66E, 5, false),
+    (0xA66F, 0xA672, 1, true),
+    (0xA674, 0xA67D, 1, true),
+    (0xA67F, 0xA69B, 5, false),
+    (0xA69C, 0xA69D, 0, false),
+    (0xA69E, 0xA69F, 1, true),
+    (0xA6A0, 0xA6EF, 60, false),
+    (0xA6F0, 0xA6F1, 1, true),
+    (0xA717, 0xA71F, 0, false),
+    (0xA722, 0xA76F, 2, false),
+    (0xA770, 0xA770, 0, false),
+    (0xA771, 0xA787, 2, false),
+    (0xA788, 0xA788, 0, false),
+    (0xA78B, 0xA7CA, 2, false),
+    (0xA7D0, 0xA7D1, 2, false),
+    (0xA7D3, 0xA7D3, 2, false),
+    (0xA7D5, 0xA7D9, 2, false),
+    (0xA7F2, 0xA7F4, 0, false),
+    (0xA7F5, 0xA7F7, 2, false),
+    (0xA7F8, 0xA7F9, 0, false),
+    (0xA7FA, 0xA7FF, 2, false),
+    (0xA800, 0xA801, 61, false),
+    (0xA802, 0xA802, 61, true),
+    (0xA803, 0xA805, 61, false),
+    (0xA806, 0xA806, 61, true),
+    (0xA807, 0xA80A, 61, false),
+    (0xA80B, 0xA80B, 61, true),
+    (0xA80C, 0xA824, 61, false),
+    (0xA825, 0xA826, 61, true),
+    (0xA827, 0xA827, 61, false),
+    (0xA828, 0xA82B, 0, false),
+    (0xA82C, 0xA82C, 61, true),
+    (0xA830, 0xA839, 0, false),
+    (0xA840, 0xA873, 62, false),
+    (0xA880, 0xA8C3, 63, false),
+    (0xA8C4, 0xA8C5, 63, true),
+    (0xA8D0, 0xA8D9, 63, false),
+    (0xA8E0, 0xA8F1, 1, true),
+    (0xA8F2, 0xA8F7, 14, false),
+    (0xA8FB, 0xA8FB, 14, false),
+    (0xA8FD, 0xA8FE, 14, false),
+    (0xA8FF, 0xA8FF, 14, true),
+    (0xA900, 0xA925, 64, false),
+    (0xA926, 0xA92D, 64, true),
+    (0xA930, 0xA946, 65, false),
+    (0xA947, 0xA951, 65, true),
+    (0xA952, 0xA953, 65, false),
+    (0xA960, 0xA97C, 29, false),
+    (0xA980, 0xA982, 66, true),
+    (0xA983, 0xA9B2, 66, false),
+    (0xA9B3, 0xA9B3, 66, true),
+    (0xA9B4, 0xA9B5, 66, false),
+    (0xA9B6, 0xA9B9, 66, true),
+    (0xA9BA, 0xA9BB, 66, false),
+    (0xA9BC, 0xA9BD, 66, true),
+    (0xA9BE, 0xA9C0, 66, false),
+    (0xA9CF, 0xA9D9, 66, false),
+    (0xA9E0, 0xA9E4, 27, false),
+    (0xA9E5, 0xA9E5, 27, true),
+    (0xA9E6, 0xA9FE, 27, false),
+    (0xAA00, 0xAA28, 67, false),
+    (0xAA29, 0xAA2E, 67, true),
+    (0xAA2F, 0xAA30, 67, false),
+    (0xAA31, 0xAA32, 67, true),
+    (0xAA33, 0xAA34, 67, false),
+    (0xAA35, 0xAA36, 67, true),
+    (0xAA40, 0xAA42, 67, false),
+    (0xAA43, 0xAA43, 67, true),
+    (0xAA44, 0xAA4B, 67, false),
+    (0xAA4C, 0xAA4C, 67, true),
+    (0xAA4D, 0xAA4D, 67, false),
+    (0xAA50, 0xAA59, 67, false),
+    (0xAA60, 0xAA76, 27, false),
+    (0xAA77, 0xAA79, 0, false),
+    (0xAA7A, 0xAA7B, 27, false),
+    (0xAA7C, 0xAA7C, 27, true),
+    (0xAA7D, 0xAA7F, 27, false),
+    (0xAA80, 0xAAAF, 68, false),
+    (0xAAB0, 0xAAB0, 68, true),
+    (0xAAB1, 0xAAB1, 68, false),
+    (0xAAB2, 0xAAB4, 68, true),
+    (0xAAB5, 0xAAB6, 68, false),
+    (0xAAB7, 0xAAB8, 68, true),
+    (0xAAB9, 0xAABD, 68, false),
+    (0xAABE, 0xAABF, 68, true),
+    (0xAAC0, 0xAAC0, 68, false),
+    (0xAAC1, 0xAAC1, 68, true),
+    (0xAAC2, 0xAAC2, 68, false),
+    (0xAADB, 0xAADD, 68, false),
+    (0xAAE0, 0xAAEB, 69, false),
+    (0xAAEC, 0xAAED, 69, true),
+    (0xAAEE, 0xAAEF, 69, false),
+    (0xAAF2, 0xAAF5, 69, false),
+    (0xAAF6, 0xAAF6, 69, true),
+    (0xAB01, 0xAB06, 30, false),
+    (0xAB09, 0xAB0E, 30, false),
+    (0xAB11, 0xAB16, 30, false),
+    (0xAB20, 0xAB26, 30, false),
+    (0xAB28, 0xAB2E, 30, false),
+    (0xAB30, 0xAB5A, 2, false),
+    (0xAB5C, 0xAB5F, 0, false),
+    (0xAB60, 0xAB64, 2, false),
+    (0xAB65, 0xAB65, 3, false),
+    (0xAB66, 0xAB68, 2, false),
+    (0xAB69, 0xAB69, 0, false),
+    (0xAB70, 0xABBF, 31, false),
+    (0xABC0, 0xABE4, 69, false),
+    (0xABE5, 0xABE5, 69, true),
+    (0xABE6, 0xABE7, 69, false),
+    (0xABE8, 0xABE8, 69, true),
+    (0xABE9, 0xABEA, 69, false),
+    (0xABEC, 0xABEC, 69, false),
+    (0xABED, 0xABED, 69, true),
+    (0xABF0, 0xABF9, 69, false),
+    (0xAC00, 0xD7A3, 29, false),
+    (0xD7B0, 0xD7C6, 29, false),
+    (0xD7CB, 0xD7FB, 29, false),
+    (0xF900, 0xFA6D, 53, false),
+    (0xFA70, 0xFAD9, 53, false),
+    (0xFB00, 0xFB06, 2, false),
+    (0xFB13, 0xFB17, 6, false),
+    (0xFB1D, 0xFB1D, 7, false),
+    (0xFB1E, 0xFB1E, 7, true),
+    (0xFB1F, 0xFB28, 7, false),
+    (0xFB29, 0xFB29, 0, false),
+    (0xFB2A, 0xFB36, 7, false),
+    (0xFB38, 0xFB3C, 7, false),
+    (0xFB3E, 0xFB3E, 7, false),
+    (0xFB40, 0xFB41, 7, false),
+    (0xFB43, 0xFB44, 7, false),
+    (0xFB46, 0xFB4F, 7, false),
+    (0xFB50, 0xFBB1, 8, false),
+    (0xFBD3, 0xFD3D, 8, false),
+    (0xFD40, 0xFD4F, 0, false),
+    (0xFD50, 0xFD8F, 8, false),
+    (0xFD92, 0xFDC7, 8, false),
+    (0xFDCF, 0xFDCF, 0, false),
+    (0xFDF0, 0xFDFB, 8, false),
+    (0xFDFC, 0xFDFF, 0, false),
+    (0xFE00, 0xFE0F, 1, true),
+    (0xFE20, 0xFE2F, 1, true),
+    (0xFE62, 0xFE62, 0, false),
+    (0xFE64, 0xFE66, 0, false),
+    (0xFE69, 0xFE69, 0, false),
+    (0xFE70, 0xFE74, 8, false),
+    (0xFE76, 0xFEFC, 8, false),
+    (0xFF04, 0xFF04, 0, false),
+    (0xFF0B, 0xFF0B, 0, false),
+    (0xFF10, 0xFF19, 0, false),
+    (0xFF1C, 0xFF1E, 0, false),
+    (0xFF21, 0xFF3A, 0, false),
+    (0xFF41, 0xFF5A, 0, false),
+    (0xFF5C, 0xFF5C, 0, false),
+    (0xFF5E, 0xFF5E, 0, false),
+    (0xFF66, 0xFF9F, 55, false),
+    (0xFFA1, 0xFFBE, 29, false),
+    (0xFFC2, 0xFFC7, 29, false),
+    (0xFFCA, 0xFFCF, 29, false),
+    (0xFFD2, 0xFFD7, 29, false),
+    (0xFFDA, 0xFFDC, 29, false),
+    (0xFFE0, 0xFFE2, 0, false),
+    (0xFFE4, 0xFFE6, 0, false),
+    (0xFFE8, 0xFFEE, 0, false),
+    (0xFFFC, 0xFFFD, 0, false),
+    (0x10000, 0x1000B, 70, false),
+    (0x1000D, 0x10026, 70, false),
+    (0x10028, 0x1003A, 70, false),
+    (0x1003C, 0x1003D, 70, false),
+    (0x1003F, 0x1004D, 70, false),
+    (0x10050, 0x1005D, 70, false),
+    (0x10080, 0x100FA, 70, false),
+    (0x10107, 0x10133, 0, false),
+    (0x10137, 0x1013F, 0, false),
+    (0x10140, 0x10178, 3, false),
+    (0x10179, 0x10189, 0, false),
+    (0x1018A, 0x1018B, 3, false),
+    (0x1018C, 0x1018E, 0, false),
+    (0x10190, 0x1019C, 0, false),
+    (0x101A0, 0x101A0, 0, false),
+    (0x101D0, 0x101FC, 0, false),
+    (0x101FD, 0x101FD, 1, true),
+    (0x10280, 0x1029C, 71, false),
+    (0x102A0, 0x102D0, 72, false),
+    (0x102E0, 0x102E0, 4, true),
+    (0x102E1, 0x102FB, 4, false),
+    (0x10300, 0x10323, 73, false),
+    (0x1032D, 0x1032F, 73, false),
+    (0x10330, 0x1034A, 74, false),
+    (0x10350, 0x10375, 75, false),
+    (0x10376, 0x1037A, 1, true),
+    (0x10380, 0x1039D, 76, false),
+    (0x103A0, 0x103C3, 77, false),
+    (0x103C8, 0x103CF, 77, false),
+    (0x103D1, 0x103D5, 77, false),
+    (0x10400, 0x1044F, 78, false),
+    (0x10450, 0x1047F, 79, false),
+    (0x10480, 0x1049D, 80, false),
+    (0x104A0, 0x104A9, 80, false),
+    (0x104B0, 0x104D3, 81, false),
+    (0x104D8, 0x104FB, 81, false),
+    (0x10500, 0x10527, 82, false),
+    (0x10530, 0x10563, 83, false),
+    (0x10570, 0x1057A, 84, false),
+    (0x1057C, 0x1058A, 84, false),
+    (0x1058C, 0x10592, 84, false),
+    (0x10594, 0x10595, 84, false),
+    (0x10597, 0x105A1, 84, false),
+    (0x105A3, 0x105B1, 84, false),
+    (0x105B3, 0x105B9, 84, false),
+    (0x105BB, 0x105BC, 84, false),
+    (0x10600, 0x10736, 85, false),
+    (0x10740, 0x10755, 85, false),
+    (0x10760, 0x10767, 85, false),
+    (0x10780, 0x10785, 0, false),
+    (0x10787, 0x107B0, 0, false),
+    (0x107B2, 0x107BA, 0, false),
+    (0x10800, 0x10805, 86, false),
+    (0x10808, 0x10808, 86, false),
+    (0x1080A, 0x10835, 86, false),
+    (0x10837, 0x10838, 86, false),
+    (0x1083C, 0x1083C, 86, false),
+    (0x1083F, 0x1083F, 86, false),
+    (0x10840, 0x10855, 87, false),
+    (0x10858, 0x1085F, 87, false),
+    (0x10860, 0x10876, 88, false),
+    (0x10877, 0x10878, 0, false),
+    (0x10879, 0x1087F, 88, false),
+    (0x10880, 0x1089E, 89, false),
+    (0x108A7, 0x108AF, 89, false),
+    (0x108E0, 0x108F2, 90, false),
+    (0x108F4, 0x108F5, 90, false),
+    (0x108FB, 0x108FF, 90, false),
+    (0x10900, 0x1091B, 91, false),
+    (0x10920, 0x10939, 92, false),
+    (0x10980, 0x109B7, 93, false),
+    (0x109BC, 0x109CF, 93, false),
+    (0x109D2, 0x109FF, 93, false),
+    (0x10A00, 0x10A00, 94, false),
+    (0x10A01, 0x10A03, 94, true),
+    (0x10A05, 0x10A06, 94, true),
+    (0x10A0C, 0x10A0F, 94, true),
+    (0x10A10, 0x10A13, 94, false),
+    (0x10A15, 0x10A17, 94, false),
+    (0x10A19, 0x10A35, 94, false),
+    (0x10A38, 0x10A3A, 94, true),
+    (0x10A3F, 0x10A3F, 94, true),
+    (0x10A40, 0x10A48, 94, false),
+    (0x10A60, 0x10A7E, 95, false),
+    (0x10A80, 0x10A9F, 96, false),
+    (0x10AC0, 0x10AC7, 97, false),
+    (0x10AC8, 0x10AC8, 0, false),
+    (0x10AC9, 0x10AE4, 97, false),
+    (0x10AE5, 0x10AE6, 97, true),
+    (0x10AEB, 0x10AEF, 97, false),
+    (0x10B00, 0x10B35, 98, false),
+    (0x10B40, 0x10B55, 99, false),
+    (0x10B58, 0x10B5F, 99, false),
+    (0x10B60, 0x10B72, 100, false),
+    (0x10B78, 0x10B7F, 100, false),
+    (0x10B80, 0x10B91, 101, false),
+    (0x10BA9, 0x10BAF, 101, false),
+    (0x10C00, 0x10C48, 102, false),
+    (0x10C80, 0x10CB2, 103, false),
+    (0x10CC0, 0x10CF2, 103, false),
+    (0x10CFA, 0x10CFF, 103, false),
+    (0x10D00, 0x10D23, 104, false),
+    (0x10D24, 0x10D27, 104, true),
+    (0x10D30, 0x10D39, 104, false),
+    (0x10E60, 0x10E7E, 0, false),
+    (0x10E80, 0x10EA9, 105, false),
+    (0x10EAB, 0x10EAC, 1, true),
+    (0x10EB0, 0x10EB1, 105, false),
+    (0x10F00, 0x10F27, 106, false),
+    (0x10F30, 0x10F45, 107, false),
+    (0x10F46, 0x10F50, 1, true),
+    (0x10F51, 0x10F54, 107, false),
+    (0x10F70, 0x10F81, 108, false),
+    (0x10F82, 0x10F85, 1, true),
+    (0x10FB0, 0x10FCB, 109, false),
+    (0x10FE0, 0x10FF6, 110, false),
+    (0x11000, 0x11000, 111, false),
+    (0x11001, 0x11001, 111, true),
+    (0x11002, 0x11037, 111, false),
+    (0x11038, 0x11046, 111, true),
+    (0x11052, 0x1106F, 111, false),
+    (0x11070, 0x11070, 111, true),
+    (0x11071, 0x11072, 111, false),
+    (0x11073, 0x11074, 111, true),
+    (0x11075, 0x11075, 111, false),
+    (0x1107F, 0x1107F, 111, true),
+    (0x11080, 0x11081, 112, true),
+    (0x11082, 0x110B2, 112, false),
+    (0x110B3, 0x110B6, 112, true),
+    (0x110B7, 0x110B8, 112, false),
+    (0x110B9, 0x110BA, 112, true),
+    (0x110C2, 0x110C2, 112, true),
+    (0x110D0, 0x110E8, 113, false),
+    (0x110F0, 0x110F9, 113, false),
+    (0x11100, 0x11102, 114, true),
+    (0x11103, 0x11126, 114, false),
+    (0x11127, 0x1112B, 114, true),
+    (0x1112C, 0x1112C, 114, false),
+    (0x1112D, 0x11134, 114, true),
+    (0x11136, 0x1113F, 114, false),
+    (0x11144, 0x11147, 114, false),
+    (0x11150, 0x11172, 115, false),
+    (0x11173, 0x11173, 115, true),
+    (0x11176, 0x11176, 115, false),
+    (0x11180, 0x11181, 116, true),
+    (0x11182, 0x111B5, 116, false),
+    (0x111B6, 0x111BE, 116, true),
+    (0x111BF, 0x111C4, 116, false),
+    (0x111C9, 0x111CC, 116, true),
+    (0x111CE, 0x111CE, 116, false),
+    (0x111CF, 0x111CF, 116, true),
+    (0x111D0, 0x111DA, 116, false),
+    (0x111DC, 0x111DC, 116, false),
+    (0x111E1, 0x111F4, 23, false),
+    (0x11200, 0x11211, 117, false),
+    (0x11213, 0x1122E, 117, false),
+    (0x1122F, 0x11231, 117, true),
+    (0x11232, 0x11233, 117, false),
+    (0x11234, 0x11234, 117, true),
+    (0x11235, 0x11235, 117, false),
+    (0x11236, 0x11237, 117, true),
+    (0x1123E, 0x1123E, 117, true),
+    (0x11280, 0x11286, 118, false),
+    (0x11288, 0x11288, 118, false),
+    (0x1128A, 0x1128D, 118, false),
+    (0x1128F, 0x1129D, 118, false),
+    (0x1129F, 0x112A8, 118, false),
+    (0x112B0, 0x112DE, 119, false),
+    (0x112DF, 0x112DF, 119, true),
+    (0x112E0, 0x112E2, 119, false),
+    (0x112E3, 0x112EA, 119, true),
+    (0x112F0, 0x112F9, 119, false),
+    (0x11300, 0x11300, 1, true),
+    (0x11301, 0x11301, 120, true),
+    (0x11302, 0x11303, 120, false),
+    (0x11305, 0x1130C, 120, false),
+    (0x1130F, 0x11310, 120, false),
+    (0x11313, 0x11328, 120, false),
+    (0x1132A, 0x11330, 120, false),
+    (0x11332, 0x11333, 120, false),
+    (0x11335, 0x11339, 120, false),
+    (0x1133B, 0x1133B, 1, true),
+    (0x1133C, 0x1133C, 120, true),
+    (0x1133D, 0x1133F, 120, false),
+    (0x11340, 0x11340, 120, true),
+    (0x11341, 0x11344, 120, false),
+    (0x11347, 0x11348, 120, false),
+    (0x1134B, 0x1134D, 120, false),
+    (0x11350, 0x11350, 120, false),
+    (0x11357, 0x11357, 120, false),
+    (0x1135D, 0x11363, 120, false),
+    (0x11366, 0x1136C, 1, true),
+    (0x11370, 0x11374, 1, true),
+    (0x11400, 0x11437, 121, false),
+    (0x11438, 0x1143F, 121, true),
+    (0x11440, 0x11441, 121, false),
+    (0x11442, 0x11444, 121, true),
+    (0x11445, 0x11445, 121, false),
+    (0x11446, 0x11446, 121, true),
+    (0x11447, 0x1144A, 121, false),
+    (0x11450, 0x11459, 121, false),
+    (0x1145E, 0x1145E, 121, true),
+    (0x1145F, 0x11461, 121, false),
+    (0x11480, 0x114B2, 122, false),
+    (0x114B3, 0x114B8, 122, true),
+    (0x114B9, 0x114B9, 122, false),
+    (0x114BA, 0x114BA, 122, true),
+    (0x114BB, 0x114BE, 122, false),
+    (0x114BF, 0x114C0, 122, true),
+    (0x114C1, 0x114C1, 122, false),
+    (0x114C2, 0x114C3, 122, true),
+    (0x114C4, 0x114C5, 122, false),
+    (0x114C7, 0x114C7, 122, false),
+    (0x114D0, 0x114D9, 122, false),
+    (0x11580, 0x115B1, 123, false),
+    (0x115B2, 0x115B5, 123, true),
+    (0x115B8, 0x115BB, 123, false),
+    (0x115BC, 0x115BD, 123, true),
+    (0x115BE, 0x115BE, 123, false),
+    (0x115BF, 0x115C0, 123, true),
+    (0x115D8, 0x115DB, 123, false),
+    (0x115DC, 0x115DD, 123, true),
+    (0x11600, 0x11632, 124, false),
+    (0x11633, 0x1163A, 124, true),
+    (0x1163B, 0x1163C, 124, false),
+    (0x1163D, 0x1163D, 124, true),
+    (0x1163E, 0x1163E, 124, false),
+    (0x1163F, 0x11640, 124, true),
+    (0x11644, 0x11644, 124, false),
+    (0x11650, 0x11659, 124, false),
+    (0x11680, 0x116AA, 125, false),
+    (0x116AB, 0x116AB, 125, true),
+    (0x116AC, 0x116AC, 125, false),
+    (0x116AD, 0x116AD, 125, true),
+    (0x116AE, 0x116AF, 125, false),
+    (0x116B0, 0x116B5, 125, true),
+    (0x116B6, 0x116B6, 125, false),
+    (0x116B7, 0x116B7, 125, true),
+    (0x116B8, 0x116B8, 125, false),
+    (0x116C0, 0x116C9, 125, false),
+    (0x11700, 0x1171A, 126, false),
+    (0x1171D, 0x1171F, 126, true),
+    (0x11720, 0x11721, 126, false),
+    (0x11722, 0x11725, 126, true),
+    (0x11726, 0x11726, 126, false),
+    (0x11727, 0x1172B, 126, true),
+    (0x11730, 0x1173B, 126, false),
+    (0x1173F, 0x1173F, 0, false),
+    (0x11740, 0x11746, 126, false),
+    (0x11800, 0x1182E, 127, false),
+    (0x1182F, 0x11837, 127, true),
+    (0x11838, 0x11838, 127, false),
+    (0x11839, 0x1183A, 127, true),
+    (0x118A0, 0x118F2, 128, false),
+    (0x118FF, 0x118FF, 128, false),
+    (0x11900, 0x11906, 129, false),
+    (0x11909, 0x11909, 129, false),
+    (0x1190C, 0x11913, 129, false),
+    (0x11915, 0x11916, 129, false),
+    (0x11918, 0x11935, 129, false),
+    (0x11937, 0x11938, 129, false),
+    (0x1193B, 0x1193C, 129, true),
+    (0x1193D, 0x1193D, 129, false),
+    (0x1193E, 0x1193E, 129, true),
+    (0x1193F, 0x11942, 129, false),
+    (0x11943, 0x11943, 129, true),
+    (0x11950, 0x11959, 129, false),
+    (0x119A0, 0x119A7, 130, false),
+    (0x119AA, 0x119D3, 130, false),
+    (0x119D4, 0x119D7, 130, true),
+    (0x119DA, 0x119DB, 130, true),
+    (0x119DC, 0x119DF, 130, false),
+    (0x119E0, 0x119E0, 130, true),
+    (0x119E1, 0x119E1, 130, false),
+    (0x119E3, 0x119E4, 130, false),
+    (0x11A00, 0x11A00, 131, false),
+    (0x11A01, 0x11A0A, 131, true),
+    (0x11A0B, 0x11A32, 131, false),
+    (0x11A33, 0x11A38, 131, true),
+    (0x11A39, 0x11A3A, 131, false),
+    (0x11A3B, 0x11A3E, 131, true),
+    (0x11A47, 0x11A47, 131, true),
+    (0x11A50, 0x11A50, 132, false),
+    (0x11A51, 0x11A56, 132, true),
+    (0x11A57, 0x11A58, 132, false),
+    (0x11A59, 0x11A5B, 132, true),
+    (0x11A5C, 0x11A89, 132, false),
+    (0x11A8A, 0x11A96, 132, true),
+    (0x11A97, 0x11A97, 132, false),
+    (0x11A98, 0x11A99, 132, true),
+    (0x11A9D, 0x11A9D, 132, false),
+    (0x11AB0, 0x11ABF, 32, false),
+    (0x11AC0, 0x11AF8, 133, false),
+    (0x11C00, 0x11C08, 134, false),
+    (0x11C0A, 0x11C2F, 134, false),
+    (0x11C30, 0x11C36, 134, true),
+    (0x11C38, 0x11C3D, 134, true),
+    (0x11C3E, 0x11C3E, 134, false),
+    (0x11C3F, 0x11C3F, 134, true),
+    (0x11C40, 0x11C40, 134, false),
+    (0x11C50, 0x11C6C, 134, false),
+    (0x11C72, 0x11C8F, 135, false),
+    (0x11C92, 0x11CA7, 135, true),
+    (0x11CA9, 0x11CA9, 135, false),
+    (0x11CAA, 0x11CB0, 135, true),
+    (0x11CB1, 0x11CB1, 135, false),
+    (0x11CB2, 0x11CB3, 135, true),
+    (0x11CB4, 0x11CB4, 135, false),
+    (0x11CB5, 0x11CB6, 135, true),
+    (0x11D00, 0x11D06, 136, false),
+    (0x11D08, 0x11D09, 136, false),
+    (0x11D0B, 0x11D30, 136, false),
+    (0x11D31, 0x11D36, 136, true),
+    (0x11D3A, 0x11D3A, 136, true),
+    (0x11D3C, 0x11D3D, 136, true),
+    (0x11D3F, 0x11D45, 136, true),
+    (0x11D46, 0x11D46, 136, false),
+    (0x11D47, 0x11D47, 136, true),
+    (0x11D50, 0x11D59, 136, false),
+    (0x11D60, 0x11D65, 137, false),
+    (0x11D67, 0x11D68, 137, false),
+    (0x11D6A, 0x11D8E, 137, false),
+    (0x11D90, 0x11D91, 137, true),
+    (0x11D93, 0x11D94, 137, false),
+    (0x11D95, 0x11D95, 137, true),
+    (0x11D96, 0x11D96, 137, false),
+    (0x11D97, 0x11D97, 137, true),
+    (0x11D98, 0x11D98, 137, false),
+    (0x11DA0, 0x11DA9, 137, false),
+    (0x11EE0, 0x11EF2, 138, false),
+    (0x11EF3, 0x11EF4, 138, true),
+    (0x11EF5, 0x11EF6, 138, false),
+    (0x11FB0, 0x11FB0, 58, false),
+    (0x11FC0, 0x11FD4, 19, false),
+    (0x11FD5, 0x11FF1, 0, false),
+    (0x12000, 0x12399, 139, false),
+    (0x12400, 0x1246E, 139, false),
+    (0x12480, 0x12543, 139, false),
+    (0x12F90, 0x12FF0, 140, false),
+    (0x13000, 0x1342E, 141, false),
+    (0x14400, 0x14646, 142, false),
+    (0x16800, 0x16A38, 60, false),
+    (0x16A40, 0x16A5E, 143, false),
+    (0x16A60, 0x16A69, 143, false),
+    (0x16A70, 0x16ABE, 144, false),
+    (0x16AC0, 0x16AC9, 144, false),
+    (0x16AD0, 0x16AED, 145, false),
+    (0x16AF0, 0x16AF4, 1, true),
+    (0x16B00, 0x16B2F, 146, false),
+    (0x16B30, 0x16B36, 146, true),
+    (0x16B3C, 0x16B3F, 0, false),
+    (0x16B40, 0x16B43, 146, false),
+    (0x16B45, 0x16B45, 0, false),
+    (0x16B50, 0x16B59, 146, false),
+    (0x16B5B, 0x16B61, 146, false),
+    (0x16B63, 0x16B77, 146, false),
+    (0x16B7D, 0x16B8F, 146, false),
+    (0x16E40, 0x16E96, 147, false),
+    (0x16F00, 0x16F4A, 148, false),
+    (0x16F4F, 0x16F4F, 148, true),
+    (0x16F50, 0x16F87, 148, false),
+    (0x16F8F, 0x16F92, 148, true),
+    (0x16F93, 0x16F9F, 148, false),
+    (0x16FE0, 0x16FE0, 149, false),
+    (0x16FE1, 0x16FE1, 150, false),
+    (0x16FE3, 0x16FE3, 53, false),
+    (0x16FE4, 0x16FE4, 151, true),
+    (0x16FF0, 0x16FF1, 53, false),
+    (0x17000, 0x187F7, 149, false),
+    (0x18800, 0x18AFF, 149, false),
+    (0x18B00, 0x18CD5, 151, false),
+    (0x18D00, 0x18D08, 149, false),
+    (0x1AFF0, 0x1AFF3, 55, false),
+    (0x1AFF5, 0x1AFFB, 55, false),
+    (0x1AFFD, 0x1AFFE, 55, false),
+    (0x1B000, 0x1B000, 55, false),
+    (0x1B001, 0x1B11F, 54, false),
+    (0x1B120, 0x1B122, 55, false),
+    (0x1B150, 0x1B152, 54, false),
+    (0x1B164, 0x1B167, 55, false),
+    (0x1B170, 0x1B2FB, 150, false),
+    (0x1BC00, 0x1BC6A, 152, false),
+    (0x1BC70, 0x1BC7C, 152, false),
+    (0x1BC80, 0x1BC88, 152, false),
+    (0x1BC90, 0x1BC99, 152, false),
+    (0x1BC9C, 0x1BC9C, 0, false),
+    (0x1BC9D, 0x1BC9E, 152, true),
+    (0x1CF00, 0x1CF2D, 1, true),
+    (0x1CF30, 0x1CF46, 1, true),
+    (0x1CF50, 0x1CFC3, 0, false),
+    (0x1D000, 0x1D0F5, 0, false),
+    (0x1D100, 0x1D126, 0, false),
+    (0x1D129, 0x1D164, 0, false),
+    (0x1D165, 0x1D166, 1, false),
+    (0x1D167, 0x1D169, 1, true),
+    (0x1D16A, 0x1D16C, 0, false),
+    (0x1D16D, 0x1D172, 1, false),
+    (0x1D17B, 0x1D182, 1, true),
+    (0x1D183, 0x1D184, 0, false),
+    (0x1D185, 0x1D18B, 1, true),
+    (0x1D18C, 0x1D1A9, 0, false),
+    (0x1D1AA, 0x1D1AD, 1, true),
+    (0x1D1AE, 0x1D1EA, 0, false),
+    (0x1D200, 0x1D241, 0, false),
+    (0x1D242, 0x1D244, 1, true),
+    (0x1D245, 0x1D245, 0, false),
+    (0x1D2E0, 0x1D2F3, 0, false),
+    (0x1D300, 0x1D356, 0, false),
+    (0x1D360, 0x1D371, 0, false),
+    (0x1D372, 0x1D376, 53, false),
+    (0x1D377, 0x1D378, 0, false),
+    (0x1D400, 0x1D454, 0, false),
+    (0x1D456, 0x1D49C, 0, false),
+    (0x1D49E, 0x1D49F, 0, false),
+    (0x1D4A2, 0x1D4A2, 0, false),
+    (0x1D4A5, 0x1D4A6, 0, false),
+    (0x1D4A9, 0x1D4AC, 0, false),
+    (0x1D4AE, 0x1D4B9, 0, false),
+    (0x1D4BB, 0x1D4BB, 0, false),
+    (0x1D4BD, 0x1D4C3, 0, false),
+    (0x1D4C5, 0x1D505, 0, false),
+    (0x1D507, 0x1D50A, 0, false),
+    (0x1D50D, 0x1D514, 0, false),
+    (0x1D516, 0x1D51C, 0, false),
+    (0x1D51E, 0x1D539, 0, false),
+    (0x1D53B, 0x1D53E, 0, false),
+    (0x1D540, 0x1D544, 0, false),
+    (0x1D546, 0x1D546, 0, false),
+    (0x1D54A, 0x1D550, 0, false),
+    (0x1D552, 0x1D6A5, 0, false),
+    (0x1D6A8, 0x1D7CB, 0, false),
+    (0x1D7CE, 0x1D9FF, 0, false),
+    (0x1DA00, 0x1DA36, 153, true),
+    (0x1DA37, 0x1DA3A, 0, false),
+    (0x1DA3B, 0x1DA6C, 153, true),
+    (0x1DA6D, 0x1DA74, 0, false),
+    (0x1DA75, 0x1DA75, 153, true),
+    (0x1DA76, 0x1DA83, 0, false),
+    (0x1DA84, 0x1DA84, 153, true),
+    (0x1DA85, 0x1DA86, 0, false),
+    (0x1DA9B, 0x1DA9F, 153, true),
+    (0x1DAA1, 0x1DAAF, 153, true),
+    (0x1DF00, 0x1DF1E, 2, false),
+    (0x1E000, 0x1E006, 1, true),
+    (0x1E008, 0x1E018, 1, true),
+    (0x1E01B, 0x1E021, 1, true),
+    (0x1E023, 0x1E024, 1, true),
+    (0x1E026, 0x1E02A, 1, true),
+    (0x1E100, 0x1E12C, 154, false),
+    (0x1E130, 0x1E136, 154, true),
+    (0x1E137, 0x1E13D, 154, false),
+    (0x1E140, 0x1E149, 154, false),
+    (0x1E14E, 0x1E14E, 154, false),
+    (0x1E14F, 0x1E14F, 0, false),
+    (0x1E290, 0x1E2AD, 155, false),
+    (0x1E2AE, 0x1E2AE, 155, true),
+    (0x1E2C0, 0x1E2EB, 156, false),
+    (0x1E2EC, 0x1E2EF, 156, true),
+    (0x1E2F0, 0x1E2F9, 156, false),
+    (0x1E2FF, 0x1E2FF, 0, false),
+    (0x1E7E0, 0x1E7E6, 30, false),
+    (0x1E7E8, 0x1E7EB, 30, false),
+    (0x1E7ED, 0x1E7EE, 30, false),
+    (0x1E7F0, 0x1E7FE, 30, false),
+    (0x1E800, 0x1E8C4, 157, false),
+    (0x1E8C7, 0x1E8CF, 157, false),
+    (0x1E8D0, 0x1E8D6, 1, true),
+    (0x1E900, 0x1E943, 158, false),
+    (0x1E944, 0x1E94A, 158, true),
+    (0x1E94B, 0x1E94B, 158, false),
+    (0x1E950, 0x1E959, 158, false),
+    (0x1EC71, 0x1ECB4, 0, false),
+    (0x1ED01, 0x1ED3D, 0, false),
+    (0x1EE00, 0x1EE03, 8, false),
+    (0x1EE05, 0x1EE1F, 8, false),
+    (0x1EE21, 0x1EE22, 8, false),
+    (0x1EE24, 0x1EE24, 8, false),
+    (0x1EE27, 0x1EE27, 8, false),
+    (0x1EE29, 0x1EE32, 8, false),
+    (0x1EE34, 0x1EE37, 8, false),
+    (0x1EE39, 0x1EE39, 8, false),
+    (0x1EE3B, 0x1EE3B, 8, false),
+    (0x1EE42, 0x1EE42, 8, false),
+    (0x1EE47, 0x1EE47, 8, false),
+    (0x1EE49, 0x1EE49, 8, false),
+    (0x1EE4B, 0x1EE4B, 8, false),
+    (0x1EE4D, 0x1EE4F, 8, false),
+    (0x1EE51, 0x1EE52, 8, false),
+    (0x1EE54, 0x1EE54, 8, false),
+    (0x1EE57, 0x1EE57, 8, false),
+    (0x1EE59, 0x1EE59, 8, false),
+    (0x1EE5B, 0x1EE5B, 8, false),
+    (0x1EE5D, 0x1EE5D, 8, false),
+    (0x1EE5F, 0x1EE5F, 8, false),
+    (0x1EE61, 0x1EE62, 8, false),
+    (0x1EE64, 0x1EE64, 8, false),
+    (0x1EE67, 0x1EE6A, 8, false),
+    (0x1EE6C, 0x1EE72, 8, false),
+    (0x1EE74, 0x1EE77, 8, false),
+    (0x1EE79, 0x1EE7C, 8, false),
+    (0x1EE7E, 0x1EE7E, 8, false),
+    (0x1EE80, 0x1EE89, 8, false),
+    (0x1EE8B, 0x1EE9B, 8, false),
+    (0x1EEA1, 0x1EEA3, 8, false),
+    (0x1EEA5, 0x1EEA9, 8, false),
+    (0x1EEAB, 0x1EEBB, 8, false),
+    (0x1EEF0, 0x1EEF1, 0, false),
+    (0x1F000, 0x1F02B, 0, false),
+    (0x1F030, 0x1F093, 0, false),
+    (0x1F0A0, 0x1F0AE, 0, false),
+    (0x1F0B1, 0x1F0BF, 0, false),
+    (0x1F0C1, 0x1F0CF, 0, false),
+    (0x1F0D1, 0x1F0F5, 0, false),
+    (0x1F100, 0x1F1AD, 0, false),
+    (0x1F1E6, 0x1F202, 0, false),
+    (0x1F210, 0x1F23B, 0, false),
+    (0x1F240, 0x1F248, 0, false),
+    (0x1F250, 0x1F251, 0, false),
+    (0x1F260, 0x1F265, 0, false),
+    (0x1F300, 0x1F3FA, 0, false),
+    (0x1F400, 0x1F6D7, 0, false),
+    (0x1F6DD, 0x1F6EC, 0, false),
+    (0x1F6F0, 0x1F6FC, 0, false),
+    (0x1F700, 0x1F773, 0, false),
+    (0x1F780, 0x1F7D8, 0, false),
+    (0x1F7E0, 0x1F7EB, 0, false),
+    (0x1F7F0, 0x1F7F0, 0, false),
+    (0x1F800, 0x1F80B, 0, false),
+    (0x1F810, 0x1F847, 0, false),
+    (0x1F850, 0x1F859, 0, false),
+    (0x1F860, 0x1F887, 0, false),
+    (0x1F890, 0x1F8AD, 0, false),
+    (0x1F8B0, 0x1F8B1, 0, false),
+    (0x1F900, 0x1FA53, 0, false),
+    (0x1FA60, 0x1FA6D, 0, false),
+    (0x1FA70, 0x1FA74, 0, false),
+    (0x1FA78, 0x1FA7C, 0, false),
+    (0x1FA80, 0x1FA86, 0, false),
+    (0x1FA90, 0x1FAAC, 0, false),
+    (0x1FAB0, 0x1FABA, 0, false),
+    (0x1FAC0, 0x1FAC5, 0, false),
+    (0x1FAD0, 0x1FAD9, 0, false),
+    (0x1FAE0, 0x1FAE7, 0, false),
+    (0x1FAF0, 0x1FAF6, 0, false),
+    (0x1FB00, 0x1FB92, 0, false),
+    (0x1FB94, 0x1FBCA, 0, false),
+    (0x1FBF0, 0x1FBF9, 0, false),
+    (0x20000, 0x2A6DF, 53, false),
+    (0x2A700, 0x2B738, 53, false),
+    (0x2B740, 0x2B81D, 53, false),
+    (0x2B820, 0x2CEA1, 53, false),
+    (0x2CEB0, 0x2EBE0, 53, false),
+    (0x2F800, 0x2FA1D, 53, false),
+    (0x30000, 0x3134A, 53, false),
+    (0xE0100, 0xE01EF, 1, true),
+];
@@ -1,14 +1,37 @@
-use ethaddr::Address;
+mod account;
+mod vault;
 
-use crate::bip32::{hdk, path::Path as Bip32path};
+use crate::address::Address;
+
+use crate::bip32::{
+    hdk::ExtendedPrivateKey,
+    path::{Path as Bip32path, DEFAULT_TEMPLATE},
+};
 use crate::bip39::mnemonic::{Mnemonic, Seed};
+use crate::keystore;
 use crate::wallet::Wallet;
-use anyhow::{anyhow, Ok, Result};
+pub use account::Account;
+use anyhow::{anyhow, ensure, Ok, Result};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fs,
+    io::Write as _,
+    path::Path,
+};
+use tempfile::NamedTempFile;
+use uuid::Uuid;
+pub use vault::{HDWalletVault, VaultContents, VAULT_VERSION};
 
 pub struct HDWallet {
-    wallets: Vec<Wallet>,
+    accounts: BTreeMap<usize, Account>,
+    addresses: HashMap<Address, usize>,
+    next_index: usize,
+    removed: BTreeSet<usize>,
     mnemonic: Mnemonic,
     seed: Seed,
+    derivation_template: String,
+    passphrase_hint: Option<String>,
+    passphrase_fingerprint: Option<Address>,
 }
 
 impl HDWallet {
@@ -22,47 +45,546 @@ impl HDWallet {
         Self::new_from_mnemonic(&mnemonic, password)
     }
 
-    fn new_from_mnemonic(mnemonic: &Mnemonic, password: Option<String>) -> Result<Self> {
+    pub fn new_from_mnemonic(mnemonic: &Mnemonic, password: Option<String>) -> Result<Self> {
         let password = password.unwrap_or_else(|| "".to_string());
         let seed = mnemonic.to_seed(&password);
 
         Ok(HDWallet {
-            wallets: Vec::new(),
+            accounts: BTreeMap::new(),
+            addresses: HashMap::new(),
+            next_index: 0,
+            removed: BTreeSet::new(),
             mnemonic: mnemonic.clone(),
             seed: seed,
+            derivation_template: DEFAULT_TEMPLATE.to_string(),
+            passphrase_hint: None,
+            passphrase_fingerprint: None,
         })
     }
 
+    /// Creates the hidden wallet for another mnemonic passphrase. It shares
+    /// the mnemonic and derivation template but none of the accounts.
+    pub fn with_passphrase(&self, passphrase: Option<String>) -> Result<Self> {
+        let mut wallet = Self::new_from_mnemonic(&self.mnemonic, passphrase)?;
+        wallet.derivation_template = self.derivation_template.clone();
+        Ok(wallet)
+    }
+
+    /// Creates the hidden wallets for several mnemonic passphrases.
+    pub fn hidden_wallets<I, S>(&self, passphrases: I) -> Result<Vec<Self>>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        passphrases
+            .into_iter()
+            .map(|passphrase| self.with_passphrase(Some(passphrase.into())))
+            .collect()
+    }
+
+    /// Sets the HD path template used to derive accounts, for example
+    /// `m/44'/60'/{index}'/0/0`. It can only be changed before any accounts
+    /// have been derived.
+    pub fn set_derivation_template(&mut self, template: &str) -> Result<()> {
+        ensure!(
+            self.next_index == 0,
+            "derivation template cannot change once accounts are derived",
+        );
+        Bip32path::for_template(template, 0)?;
+        self.derivation_template = template.to_string();
+        Ok(())
+    }
+
+    pub fn get_derivation_template(&self) -> &str {
+        &self.derivation_template
+    }
+
+    /// Sets a hint for the mnemonic passphrase which is stored in the vault.
+    pub fn set_passphrase_hint(&mut self, hint: Option<String>) {
+        self.passphrase_hint = hint;
+    }
+
+    pub fn get_passphrase_hint(&self) -> Option<&str> {
+        self.passphrase_hint.as_deref()
+    }
+
+    /// Returns the passphrase fingerprint, the address of account 0 with the
+    /// default HD path template. It does not depend on the wallet's
+    /// derivation template, so the template can change after the check is
+    /// enabled. Every passphrase gives a different wallet, so a mistyped
+    /// passphrase shows up as an unexpected fingerprint.
+    pub fn passphrase_fingerprint(&self) -> Result<Address> {
+        let path = Bip32path::for_template(DEFAULT_TEMPLATE, 0)?;
+        let key = ExtendedPrivateKey::new_master(&self.seed)?.derive(&path)?;
+        Ok(key.wallet()?.address())
+    }
+
+    /// Enables or disables storing the passphrase fingerprint in the vault.
+    /// When stored, opening the vault with a different passphrase fails.
+    pub fn set_passphrase_check(&mut self, enabled: bool) -> Result<()> {
+        self.passphrase_fingerprint = if enabled {
+            Some(self.passphrase_fingerprint()?)
+        } else {
+            None
+        };
+        Ok(())
+    }
+
+    pub fn has_passphrase_check(&self) -> bool {
+        self.passphrase_fingerprint.is_some()
+    }
+
+    /// Derives accounts until `num_accounts` account indices have been used
+    /// and returns the addresses of the newly derived accounts. Indices of
+    /// removed accounts are not derived again.
     pub fn add_accounts(&mut self, num_accounts: usize) -> Result<Vec<Address>> {
-        let old_len = self.wallets.len();
-        let mut addresses = Vec::with_capacity(num_accounts - old_len);
+        let old_len = self.next_index;
+        let mut addresses = Vec::with_capacity(num_accounts.saturating_sub(old_len));
 
         if old_len > num_accounts {
             return Ok(addresses);
         }
 
+        let master_fingerprint = self.master_fingerprint()?;
         for i in old_len..num_accounts {
-            let path = Bip32path::for_index(i);
-            let wallet = hdk::derive(self.seed.as_ref(), &path)?;
-            addresses.push(wallet.address());
-            self.wallets.push(wallet);
+            let (path, key) = self.derive_account(i)?;
+            let wallet = key.wallet()?;
+            let address = wallet.address();
+
+            addresses.push(address);
+            self.addresses.insert(address, i);
+            self.accounts.insert(
+                i,
+                Account {
+                    index: i,
+                    path,
+                    master_fingerprint,
+                    public_key: key.public_key(),
+                    address,
+                    label: None,
+                    hidden: false,
+                    wallet,
+                },
+            );
         }
+        self.next_index = num_accounts;
 
         Ok(addresses)
     }
 
+    /// Returns the addresses of all accounts that are not hidden, ordered by
+    /// account index.
     pub fn get_addresses(&self) -> Vec<Address> {
-        self.wallets.iter().map(|w| w.address()).collect()
+        self.accounts().map(Account::address).collect()
+    }
+
+    /// Returns an iterator over all accounts that are not hidden, ordered by
+    /// account index.
+    pub fn accounts(&self) -> impl Iterator<Item = &Account> + '_ {
+        self.accounts.values().filter(|account| !account.hidden)
+    }
+
+    /// Returns an iterator over all accounts including hidden ones, ordered
+    /// by account index.
+    pub fn all_accounts(&self) -> impl Iterator<Item = &Account> + '_ {
+        self.accounts.values()
     }
 
     pub fn get_wallet(&self, address: &Address) -> Result<&Wallet> {
-        self.wallets
-            .iter()
-            .find(|w| w.address() == *address)
-            .ok_or_else(|| anyhow!("Wallet not found"))
+        Ok(self.get_account(address)?.wallet())
+    }
+
+    /// Returns the account with the specified address.
+    pub fn get_account(&self, address: &Address) -> Result<&Account> {
+        let index = self.index_of(address)?;
+        Ok(&self.accounts[&index])
+    }
+
+    /// Returns the account with the specified account index.
+    pub fn get_account_by_index(&self, index: usize) -> Result<&Account> {
+        self.accounts
+            .get(&index)
+            .ok_or_else(|| anyhow!("no account with index {index}"))
+    }
+
+    /// Returns the account with the specified label.
+    pub fn get_account_by_label(&self, label: &str) -> Result<&Account> {
+        self.accounts
+            .values()
+            .find(|account| account.label() == Some(label))
+            .ok_or_else(|| anyhow!("no account labeled '{label}'"))
+    }
+
+    /// Sets a label for the account with the specified address. Labels must
+    /// be unique within the wallet.
+    pub fn set_label(&mut self, address: &Address, label: impl Into<String>) -> Result<()> {
+        let index = self.index_of(address)?;
+        let label = label.into();
+        let existing = self.accounts.values().find(|a| a.label() == Some(&label));
+        if let Some(other) = existing {
+            ensure!(
+                other.index == index,
+                "label '{label}' already used by account {}",
+                other.index,
+            );
+        }
+
+        self.account_mut(index).label = Some(label);
+        Ok(())
+    }
+
+    /// Removes the label of the account with the specified address.
+    pub fn remove_label(&mut self, address: &Address) -> Result<()> {
+        let index = self.index_of(address)?;
+        self.account_mut(index).label = None;
+        Ok(())
+    }
+
+    /// Returns the label of the account with the specified address.
+    pub fn get_label(&self, address: &Address) -> Option<&str> {
+        self.get_account(address).ok()?.label()
+    }
+
+    /// Hides or unhides the account with the specified address. Hidden
+    /// accounts keep their keys but are not listed by `get_addresses`.
+    pub fn set_hidden(&mut self, address: &Address, hidden: bool) -> Result<()> {
+        let index = self.index_of(address)?;
+        self.account_mut(index).hidden = hidden;
+        Ok(())
+    }
+
+    /// Removes the account with the specified address from the wallet. Its
+    /// index will not be derived again.
+    pub fn remove_account(&mut self, address: &Address) -> Result<Account> {
+        let index = self.index_of(address)?;
+        self.addresses.remove(address);
+        self.removed.insert(index);
+        Ok(self
+            .accounts
+            .remove(&index)
+            .expect("address index out of sync"))
+    }
+
+    /// Returns the BIP-0032 fingerprint of the master key.
+    pub fn master_fingerprint(&self) -> Result<[u8; 4]> {
+        Ok(ExtendedPrivateKey::new_master(&self.seed)?.fingerprint())
     }
 
     pub fn get_mnemonic(&self) -> &Mnemonic {
         &self.mnemonic
     }
+
+    /// Writes the wallet to an encrypted vault file. The vault contains the
+    /// mnemonic entropy and account metadata, but not the passphrase. The file is
+    /// replaced atomically, and on Unix it is only readable and writable by
+    /// its owner, also when it existed before.
+    pub fn save<P, S>(&self, path: P, password: S) -> Result<()>
+    where
+        P: AsRef<Path>,
+        S: AsRef<[u8]>,
+    {
+        let contents = VaultContents {
+            entropy: hex::encode(self.mnemonic.as_bytes()),
+            language: self.mnemonic.language(),
+            passphrase_hint: self.passphrase_hint.clone(),
+            passphrase_fingerprint: self.passphrase_fingerprint,
+            derivation_template: self.derivation_template.clone(),
+            accounts: self.next_index,
+            labels: self
+                .accounts
+                .values()
+                .filter_map(|account| Some((account.index, account.label.clone()?)))
+                .collect(),
+            hidden: self
+                .accounts
+                .values()
+                .filter(|account| account.hidden)
+                .map(|account| account.index)
+                .collect(),
+            removed: self.removed.clone(),
+        };
+        let plaintext = serde_json::to_vec(&contents)?;
+
+        let vault = HDWalletVault {
+            crypto: keystore::encrypt_crypto(plaintext, password)?,
+            id: Uuid::new_v4(),
+            version: VAULT_VERSION,
+        };
+
+        // NOTE: The vault is written to a temporary file in the same directory
+        // and renamed over the target, so a failed write never destroys the
+        // previous vault. Temporary files are only accessible by their owner.
+        let path = path.as_ref();
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        let mut file = NamedTempFile::new_in(dir)?;
+        file.write_all(serde_json::to_string(&vault)?.as_bytes())?;
+        file.as_file().sync_all()?;
+        file.persist(path)?;
+
+        Ok(())
+    }
+
+    /// Reopens a wallet from an encrypted vault file, deriving the same
+    /// accounts it had when it was saved. The mnemonic passphrase is not part
+    /// of the vault and must be provided again, it is checked against the
+    /// passphrase fingerprint if the vault has one.
+    pub fn open<P, S>(path: P, password: S, passphrase: Option<String>) -> Result<Self>
+    where
+        P: AsRef<Path>,
+        S: AsRef<[u8]>,
+    {
+        let vault: HDWalletVault = serde_json::from_str(&fs::read_to_string(path)?)?;
+        ensure!(
+            vault.version == VAULT_VERSION,
+            "unsupported vault version {}",
+            vault.version,
+        );
+
+        let plaintext = keystore::decrypt_crypto(vault.crypto, password)?;
+        let contents: VaultContents = serde_json::from_slice(&plaintext)?;
+
+        let mnemonic =
+            Mnemonic::from_entropy_in(hex::decode(&contents.entropy)?, contents.language)?;
+        let mut wallet = Self::new_from_mnemonic(&mnemonic, passphrase)?;
+        wallet.set_derivation_template(&contents.derivation_template)?;
+        if let Some(fingerprint) = contents.passphrase_fingerprint {
+            ensure!(
+                wallet.passphrase_fingerprint()? == fingerprint,
+                "wrong mnemonic passphrase{}",
+                match &contents.passphrase_hint {
+                    Some(hint) => format!(", hint: {hint}"),
+                    None => String::new(),
+                },
+            );
+        }
+        wallet.passphrase_hint = contents.passphrase_hint;
+        wallet.passphrase_fingerprint = contents.passphrase_fingerprint;
+        wallet.add_accounts(contents.accounts)?;
+
+        for index in contents.removed {
+            if let Some(account) = wallet.accounts.remove(&index) {
+                wallet.addresses.remove(&account.address);
+            }
+            wallet.removed.insert(index);
+        }
+        for (index, label) in contents.labels {
+            if let Some(account) = wallet.accounts.get_mut(&index) {
+                account.label = Some(label);
+            }
+        }
+        for index in contents.hidden {
+            if let Some(account) = wallet.accounts.get_mut(&index) {
+                account.hidden = true;
+            }
+        }
+
+        Ok(wallet)
+    }
+
+    fn derive_account(&self, index: usize) -> Result<(Bip32path, ExtendedPrivateKey)> {
+        let path = Bip32path::for_template(&self.derivation_template, index)?;
+        let key = ExtendedPrivateKey::new_master(&self.seed)?.derive(&path)?;
+        Ok((path, key))
+    }
+
+    fn index_of(&self, address: &Address) -> Result<usize> {
+        self.addresses
+            .get(address)
+            .copied()
+            .ok_or_else(|| anyhow!("no account with address {address}"))
+    }
+
+    fn account_mut(&mut self, index: usize) -> &mut Account {
+        self.accounts
+            .get_mut(&index)
+            .expect("address index out of sync")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str =
+        "myth like bonus scare over problem client lizard pioneer submit female collect";
+
+    #[test]
+    fn vault_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("wallet.vault");
+
+        let mut wallet =
+            HDWallet::new_from_mnemonic_phrase(MNEMONIC, Some("secret".to_string())).unwrap();
+        wallet
+            .set_derivation_template("m/44'/60'/{index}'/0/0")
+            .unwrap();
+        wallet.set_passphrase_hint(Some("the usual".to_string()));
+        let addresses = wallet.add_accounts(4).unwrap();
+        wallet.set_label(&addresses[1], "savings").unwrap();
+        wallet.set_hidden(&addresses[2], true).unwrap();
+        wallet.remove_account(&addresses[3]).unwrap();
+        fs::write(&path, "previous vault").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt as _;
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+        }
+        wallet.save(&path, "password").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt as _;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+
+        let mut reopened = HDWallet::open(&path, "password", Some("secret".to_string())).unwrap();
+        assert_eq!(reopened.get_addresses(), addresses[..2]);
+        assert_eq!(reopened.get_mnemonic().to_phrase(), MNEMONIC);
+        assert_eq!(reopened.get_derivation_template(), "m/44'/60'/{index}'/0/0");
+        assert_eq!(reopened.get_passphrase_hint(), Some("the usual"));
+        assert_eq!(reopened.get_label(&addresses[1]), Some("savings"));
+        assert_eq!(reopened.get_label(&addresses[0]), None);
+        assert!(reopened.get_account(&addresses[2]).unwrap().is_hidden());
+        assert!(reopened.get_account(&addresses[3]).is_err());
+        assert!(reopened.add_accounts(5).unwrap().len() == 1);
+
+        assert!(HDWallet::open(&path, "wrong password", None).is_err());
+    }
+
+    #[test]
+    fn passphrase_check() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("wallet.vault");
+
+        let mut wallet =
+            HDWallet::new_from_mnemonic_phrase(MNEMONIC, Some("secret".to_string())).unwrap();
+        wallet.set_passphrase_hint(Some("the usual".to_string()));
+        wallet.set_passphrase_check(true).unwrap();
+        let fingerprint = wallet.passphrase_fingerprint().unwrap();
+        assert_eq!(fingerprint, wallet.add_accounts(1).unwrap()[0]);
+        wallet.save(&path, "password").unwrap();
+
+        let reopened = HDWallet::open(&path, "password", Some("secret".to_string())).unwrap();
+        assert!(reopened.has_passphrase_check());
+        assert_eq!(reopened.passphrase_fingerprint().unwrap(), fingerprint);
+
+        let err = HDWallet::open(&path, "password", Some("secert".to_string()))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "wrong mnemonic passphrase, hint: the usual"
+        );
+
+        wallet.set_passphrase_check(false).unwrap();
+        wallet.save(&path, "password").unwrap();
+        assert!(HDWallet::open(&path, "password", None).is_ok());
+    }
+
+    #[test]
+    fn passphrase_check_with_changed_template() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("wallet.vault");
+
+        let mut wallet =
+            HDWallet::new_from_mnemonic_phrase(MNEMONIC, Some("secret".to_string())).unwrap();
+        wallet.set_passphrase_check(true).unwrap();
+        let fingerprint = wallet.passphrase_fingerprint().unwrap();
+        wallet
+            .set_derivation_template("m/44'/60'/{index}'/0/0")
+            .unwrap();
+        assert_eq!(wallet.passphrase_fingerprint().unwrap(), fingerprint);
+        let addresses = wallet.add_accounts(2).unwrap();
+        wallet.save(&path, "password").unwrap();
+
+        let reopened = HDWallet::open(&path, "password", Some("secret".to_string())).unwrap();
+        assert_eq!(reopened.get_addresses(), addresses);
+        assert!(HDWallet::open(&path, "password", Some("secert".to_string())).is_err());
+    }
+
+    #[test]
+    fn hidden_wallets() {
+        let mut wallet = HDWallet::new_from_mnemonic_phrase(MNEMONIC, None).unwrap();
+        wallet
+            .set_derivation_template("m/44'/60'/{index}'/0/0")
+            .unwrap();
+        let hidden = wallet.hidden_wallets(["one", "two"]).unwrap();
+
+        let fingerprints = [&wallet, &hidden[0], &hidden[1]]
+            .map(|wallet| wallet.passphrase_fingerprint().unwrap());
+        assert_ne!(fingerprints[0], fingerprints[1]);
+        assert_ne!(fingerprints[1], fingerprints[2]);
+        assert_eq!(
+            hidden[1].get_derivation_template(),
+            "m/44'/60'/{index}'/0/0"
+        );
+        assert_eq!(hidden[1].all_accounts().count(), 0);
+
+        let mut one =
+            HDWallet::new_from_mnemonic_phrase(MNEMONIC, Some("one".to_string())).unwrap();
+        one.set_derivation_template("m/44'/60'/{index}'/0/0")
+            .unwrap();
+        assert_eq!(one.passphrase_fingerprint().unwrap(), fingerprints[1]);
+    }
+
+    #[test]
+    fn derivation_template_is_frozen() {
+        let mut wallet = HDWallet::new_from_mnemonic_phrase(MNEMONIC, None).unwrap();
+        assert!(wallet.set_derivation_template("m/44'/60'/0'/0").is_err());
+        wallet.add_accounts(1).unwrap();
+        assert!(wallet
+            .set_derivation_template("m/44'/60'/{index}'/0/0")
+            .is_err());
+    }
+
+    #[test]
+    fn account_metadata_and_lookup() {
+        let mut wallet = HDWallet::new_from_mnemonic_phrase(MNEMONIC, None).unwrap();
+        let addresses = wallet.add_accounts(3).unwrap();
+
+        let account = wallet.get_account_by_index(2).unwrap();
+        assert_eq!(account.index(), 2);
+        assert_eq!(account.address(), addresses[2]);
+        assert_eq!(account.path().to_string(), "m/44'/60'/0'/0/2");
+
+        let master = ExtendedPrivateKey::new_master(&wallet.seed).unwrap();
+        let key = master.derive(account.path()).unwrap();
+        assert_eq!(account.fingerprint(), key.fingerprint());
+        assert_eq!(account.key_origin().fingerprint, master.fingerprint());
+        assert_eq!(
+            account.key_expression(),
+            format!(
+                "[{}/44'/60'/0'/0/2]{}",
+                hex::encode(master.fingerprint()),
+                key.public_key(),
+            ),
+        );
+
+        wallet.set_label(&addresses[0], "main").unwrap();
+        assert_eq!(
+            wallet.get_account_by_label("main").unwrap().address(),
+            addresses[0],
+        );
+        assert!(wallet.set_label(&addresses[1], "main").is_err());
+        wallet.remove_label(&addresses[0]).unwrap();
+        assert!(wallet.get_account_by_label("main").is_err());
+
+        wallet.set_hidden(&addresses[1], true).unwrap();
+        assert_eq!(wallet.get_addresses(), [addresses[0], addresses[2]]);
+        assert_eq!(wallet.all_accounts().count(), 3);
+        assert!(wallet.get_wallet(&addresses[1]).is_ok());
+
+        let removed = wallet.remove_account(&addresses[0]).unwrap();
+        assert_eq!(removed.index(), 0);
+        assert!(wallet.get_account_by_index(0).is_err());
+        assert!(wallet
+            .get_wallet(&addresses[0])
+            .unwrap_err()
+            .to_string()
+            .contains("no account with address"));
+    }
 }
@@ -0,0 +1,74 @@
+//! Module containing the account data model of an HD wallet.
+
+use crate::address::Address;
+use crate::{
+    bip32::{hdk::ExtendedPublicKey, origin::KeyOrigin, path::Path},
+    wallet::Wallet,
+};
+
+/// An account derived from the HD wallet seed, together with its metadata.
+#[derive(Debug)]
+pub struct Account {
+    pub(super) index: usize,
+    pub(super) path: Path,
+    pub(super) master_fingerprint: [u8; 4],
+    pub(super) public_key: ExtendedPublicKey,
+    pub(super) address: Address,
+    pub(super) label: Option<String>,
+    pub(super) hidden: bool,
+    pub(super) wallet: Wallet,
+}
+
+impl Account {
+    /// Returns the account index used in the derivation path template.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the full derivation path of the account.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the BIP-0032 fingerprint of the account key.
+    pub fn fingerprint(&self) -> [u8; 4] {
+        self.public_key.fingerprint()
+    }
+
+    /// Returns the extended public key of the account.
+    pub fn extended_public_key(&self) -> &ExtendedPublicKey {
+        &self.public_key
+    }
+
+    /// Returns the master key fingerprint and derivation path of the
+    /// account key.
+    pub fn key_origin(&self) -> KeyOrigin {
+        KeyOrigin::new(self.master_fingerprint, self.path.clone())
+    }
+
+    /// Returns the key expression with origin, for example
+    /// `[d34db33f/44'/60'/0'/0/0]xpub...`.
+    pub fn key_expression(&self) -> String {
+        self.key_origin().key_expression(&self.public_key)
+    }
+
+    /// Returns the account address.
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Returns the account label, if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Returns whether the account is hidden from the address list.
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    /// Returns the wallet holding the account private key.
+    pub fn wallet(&self) -> &Wallet {
+        &self.wallet
+    }
+}
@@ -0,0 +1,53 @@
+//! Module containing the encrypted HD wallet vault file format.
+
+use crate::address::Address;
+use crate::{bip39::wordlist::Language, keystore::CryptoJson};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use uuid::Uuid;
+
+/// The current version of the vault file format.
+pub const VAULT_VERSION: u8 = 1;
+
+/// This struct represents the deserialized form of an encrypted HD wallet
+/// vault file. The "crypto" part uses the same scheme as the Web3 Secret
+/// Storage keystore, with the serialized [`VaultContents`] as plaintext.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct HDWalletVault {
+    pub crypto: CryptoJson,
+    pub id: Uuid,
+    pub version: u8,
+}
+
+/// The plaintext contents of a vault, everything needed to reopen an
+/// `HDWallet` with exactly the same accounts.
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultContents {
+    /// The hex encoded BIP-0039 mnemonic entropy.
+    pub entropy: String,
+    /// The language of the mnemonic words. The seed is derived from the
+    /// phrase, so it depends on the language.
+    #[serde(default)]
+    pub language: Language,
+    /// An optional hint for the mnemonic passphrase. The passphrase itself is
+    /// never stored.
+    #[serde(default)]
+    pub passphrase_hint: Option<String>,
+    /// The address of account 0, used to detect a mistyped passphrase.
+    #[serde(default)]
+    pub passphrase_fingerprint: Option<Address>,
+    /// The HD path template used to derive accounts.
+    pub derivation_template: String,
+    /// The number of account indices that have been derived.
+    pub accounts: usize,
+    /// Account labels by account index.
+    #[serde(default)]
+    pub labels: BTreeMap<usize, String>,
+    /// Indices of hidden accounts.
+    #[serde(default)]
+    pub hidden: BTreeSet<usize>,
+    /// Indices of removed accounts, these are not derived again.
+    #[serde(default)]
+    pub removed: BTreeSet<usize>,
+}
@@ -0,0 +1,319 @@
+//! Module implementing an account manager that holds HD derived accounts,
+//! imported private keys and keystore backed accounts as one set.
+
+use crate::address::Address;
+use crate::{
+    bip32::path::Path as Bip32path,
+    hdwallet::HDWallet,
+    keystore::{self, EthKeystore},
+    transaction::Transaction,
+    wallet::{Signature, Wallet},
+};
+use anyhow::{anyhow, ensure, Context as _, Result};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Where the key of an account comes from.
+#[derive(Clone, Debug)]
+pub enum AccountSource {
+    /// An account derived from the HD wallet seed.
+    Derived { index: usize, path: Bip32path },
+    /// A raw private key imported into the keyring.
+    Imported,
+    /// An account backed by an encrypted keystore file.
+    Keystore { path: PathBuf, unlocked: bool },
+}
+
+/// A keystore file that is only decrypted when unlocked.
+struct KeystoreAccount {
+    path: PathBuf,
+    wallet: Option<Wallet>,
+}
+
+/// A unified set of accounts with a single address based signing entry point.
+#[derive(Default)]
+pub struct Keyring {
+    hdwallet: Option<HDWallet>,
+    imported: BTreeMap<Address, Wallet>,
+    keystores: BTreeMap<Address, KeystoreAccount>,
+}
+
+impl Keyring {
+    /// Creates a new empty keyring.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new keyring with accounts derived from an HD wallet.
+    pub fn with_hdwallet(hdwallet: HDWallet) -> Self {
+        Self {
+            hdwallet: Some(hdwallet),
+            ..Default::default()
+        }
+    }
+
+    pub fn hdwallet(&self) -> Option<&HDWallet> {
+        self.hdwallet.as_ref()
+    }
+
+    /// Returns the HD wallet for deriving more accounts.
+    pub fn hdwallet_mut(&mut self) -> Option<&mut HDWallet> {
+        self.hdwallet.as_mut()
+    }
+
+    /// Imports a wallet holding a raw private key.
+    pub fn import_wallet(&mut self, wallet: Wallet) -> Result<Address> {
+        let address = wallet.address();
+        self.ensure_unknown(&address)?;
+        self.imported.insert(address, wallet);
+        Ok(address)
+    }
+
+    /// Imports a raw 32-byte private key.
+    pub fn import_private_key(&mut self, secret: impl AsRef<[u8]>) -> Result<Address> {
+        self.import_wallet(Wallet::from_secret(secret)?)
+    }
+
+    /// Imports a Web3 Secret Storage keystore file. The account stays locked
+    /// until it is unlocked with its password.
+    pub fn import_keystore(&mut self, path: impl AsRef<Path>) -> Result<Address> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("failed to read keystore '{}'", path.display()))?;
+        let keystore: EthKeystore = serde_json::from_str(&contents)?;
+        let address = keystore.address;
+
+        self.ensure_unknown(&address)?;
+        self.keystores.insert(
+            address,
+            KeystoreAccount {
+                path: path.to_owned(),
+                wallet: None,
+            },
+        );
+        Ok(address)
+    }
+
+    /// Decrypts the keystore of a keystore backed account so it can sign.
+    pub fn unlock<S>(&mut self, address: &Address, password: S) -> Result<()>
+    where
+        S: AsRef<[u8]>,
+    {
+        let account = self
+            .keystores
+            .get_mut(address)
+            .ok_or_else(|| anyhow!("no keystore account with address {address}"))?;
+
+        let wallet = Wallet::from_secret(keystore::decrypt_key(&account.path, password)?)?;
+        ensure!(
+            wallet.address() == *address,
+            "keystore '{}' does not contain the key for {address}",
+            account.path.display(),
+        );
+        account.wallet = Some(wallet);
+        Ok(())
+    }
+
+    /// Forgets the decrypted key of a keystore backed account.
+    pub fn lock(&mut self, address: &Address) -> Result<()> {
+        let account = self
+            .keystores
+            .get_mut(address)
+            .ok_or_else(|| anyhow!("no keystore account with address {address}"))?;
+        account.wallet = None;
+        Ok(())
+    }
+
+    /// Removes an imported or keystore backed account. HD derived accounts
+    /// are removed through the HD wallet.
+    pub fn remove(&mut self, address: &Address) -> Result<()> {
+        if self.imported.remove(address).is_some() || self.keystores.remove(address).is_some() {
+            return Ok(());
+        }
+        Err(anyhow!("no imported account with address {address}"))
+    }
+
+    /// Returns the addresses of all accounts: visible HD derived accounts
+    /// first, followed by imported and keystore backed accounts.
+    pub fn addresses(&self) -> Vec<Address> {
+        self.accounts().map(|(address, _)| address).collect()
+    }
+
+    /// Returns an iterator over all accounts and their sources.
+    pub fn accounts(&self) -> impl Iterator<Item = (Address, AccountSource)> + '_ {
+        let derived = self
+            .hdwallet
+            .iter()
+            .flat_map(HDWallet::accounts)
+            .map(|account| {
+                (
+                    account.address(),
+                    AccountSource::Derived {
+                        index: account.index(),
+                        path: account.path().clone(),
+                    },
+                )
+            });
+        let imported = self
+            .imported
+            .keys()
+            .map(|address| (*address, AccountSource::Imported));
+        let keystores = self.keystores.iter().map(|(address, account)| {
+            (
+                *address,
+                AccountSource::Keystore {
+                    path: account.path.clone(),
+                    unlocked: account.wallet.is_some(),
+                },
+            )
+        });
+
+        derived.chain(imported).chain(keystores)
+    }
+
+    /// Returns where the key of the account with the specified address comes
+    /// from.
+    pub fn source(&self, address: &Address) -> Result<AccountSource> {
+        if let Some(account) = self
+            .hdwallet
+            .as_ref()
+            .and_then(|hdwallet| hdwallet.get_account(address).ok())
+        {
+            return Ok(AccountSource::Derived {
+                index: account.index(),
+                path: account.path().clone(),
+            });
+        }
+        if self.imported.contains_key(address) {
+            return Ok(AccountSource::Imported);
+        }
+        if let Some(account) = self.keystores.get(address) {
+            return Ok(AccountSource::Keystore {
+                path: account.path.clone(),
+                unlocked: account.wallet.is_some(),
+            });
+        }
+        Err(anyhow!("no account with address {address}"))
+    }
+
+    /// Returns the wallet for the specified address, regardless of where its
+    /// key comes from. Keystore backed accounts must be unlocked first.
+    pub fn get_wallet(&self, address: &Address) -> Result<&Wallet> {
+        if let Some(wallet) = self
+            .hdwallet
+            .as_ref()
+            .and_then(|hdwallet| hdwallet.get_wallet(address).ok())
+        {
+            return Ok(wallet);
+        }
+        if let Some(wallet) = self.imported.get(address) {
+            return Ok(wallet);
+        }
+        if let Some(account) = self.keystores.get(address) {
+            return account
+                .wallet
+                .as_ref()
+                .ok_or_else(|| anyhow!("keystore account {address} is locked"));
+        }
+        Err(anyhow!("no account with address {address}"))
+    }
+
+    /// Signs a 32-byte hash with the key of the specified account.
+    pub fn sign(&self, address: &Address, message: [u8; 32]) -> Result<Signature> {
+        self.get_wallet(address)?.sign(message)
+    }
+
+    /// Signs a message with the key of the specified account.
+    pub fn sign_message(&self, address: &Address, message: &[u8]) -> Result<Signature> {
+        self.get_wallet(address)?.sign_message(message)
+    }
+
+    /// Signs a transaction with the key of the specified account and returns
+    /// the RLP encoded signed transaction.
+    pub fn sign_transaction(
+        &self,
+        address: &Address,
+        transaction: &Transaction,
+    ) -> Result<Vec<u8>> {
+        transaction.sign(self.get_wallet(address)?)
+    }
+
+    fn ensure_unknown(&self, address: &Address) -> Result<()> {
+        ensure!(
+            self.source(address).is_err(),
+            "account {address} is already part of the keyring",
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    const MNEMONIC: &str =
+        "myth like bonus scare over problem client lizard pioneer submit female collect";
+
+    #[test]
+    fn unified_accounts() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let mut hdwallet = HDWallet::new_from_mnemonic_phrase(MNEMONIC, None).unwrap();
+        let derived = hdwallet.add_accounts(2).unwrap();
+        let mut keyring = Keyring::with_hdwallet(hdwallet);
+
+        let imported = keyring.import_private_key([0x11; 32]).unwrap();
+
+        let keystore_wallet = Wallet::from_secret([0x22; 32]).unwrap();
+        let id = keystore_wallet.encrypt_keystore(&dir, "password").unwrap();
+        let keystore_path = dir.path().join(id);
+        let keystored = keyring.import_keystore(&keystore_path).unwrap();
+        assert_eq!(keystored, keystore_wallet.address());
+
+        assert_eq!(
+            keyring.addresses(),
+            [derived[0], derived[1], imported, keystored],
+        );
+        assert!(matches!(
+            keyring.source(&derived[1]).unwrap(),
+            AccountSource::Derived { index: 1, .. },
+        ));
+        assert!(matches!(
+            keyring.source(&imported).unwrap(),
+            AccountSource::Imported,
+        ));
+        assert!(matches!(
+            keyring.source(&keystored).unwrap(),
+            AccountSource::Keystore {
+                unlocked: false,
+                ..
+            },
+        ));
+
+        // Importing an account twice is rejected, no matter the source.
+        assert!(keyring.import_private_key([0x11; 32]).is_err());
+        let secret = keyring.get_wallet(&derived[0]).unwrap().secret();
+        assert!(keyring.import_private_key(secret).is_err());
+
+        let message = hex!("0000000000000000000000000000000000000000000000000000000000000001");
+        assert!(keyring.sign(&keystored, message).is_err());
+        keyring.unlock(&keystored, "password").unwrap();
+        for address in keyring.addresses() {
+            let signature = keyring.sign(&address, message).unwrap();
+            assert_eq!(
+                signature,
+                keyring.get_wallet(&address).unwrap().sign(message).unwrap()
+            );
+        }
+
+        keyring.lock(&keystored).unwrap();
+        assert!(keyring.sign_message(&keystored, b"hello").is_err());
+        keyring.remove(&imported).unwrap();
+        assert!(keyring.source(&imported).is_err());
+        assert!(keyring.remove(&derived[0]).is_err());
+    }
+}
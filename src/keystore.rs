@@ -1,3 +1,4 @@
+mod bls_keystore;
 mod eth_keystore;
 
 use aes::{
@@ -10,16 +11,21 @@ use pbkdf2::pbkdf2;
 use scrypt::{scrypt, Params as ScryptParams};
 use sha2::Sha256;
 use sha3::{Digest as _, Keccak256};
+use unicode_normalization::UnicodeNormalization as _;
 use uuid::Uuid;
 
 use crate::utils::{self, address_from_pk};
-use anyhow::{anyhow, Error};
+use anyhow::{anyhow, ensure, Error};
 use std::{
     fs::File,
     io::{Read, Write},
     path::Path,
 };
 
+pub use bls_keystore::{
+    BlsCryptoJson, BlsKdf, BlsKeystore, ChecksumModule, CipherModule, CipherModuleParams,
+    EmptyParams, KdfModule,
+};
 pub use eth_keystore::{CipherparamsJson, CryptoJson, EthKeystore, KdfType, KdfparamsType};
 
 const DEFAULT_CIPHER: &str = "aes-128-ctr";
@@ -29,6 +35,9 @@ const DEFAULT_KDF_PARAMS_DKLEN: u8 = 32u8;
 const DEFAULT_KDF_PARAMS_LOG_N: u8 = 13u8;
 const DEFAULT_KDF_PARAMS_R: u32 = 8u32;
 const DEFAULT_KDF_PARAMS_P: u32 = 1u32;
+const BLS_KEYSTORE_VERSION: u8 = 4u8;
+const BLS_CHECKSUM_FUNCTION: &str = "sha256";
+const BLS_PBKDF2_PRF: &str = "hmac-sha256";
 
 pub fn decrypt_key<P, S>(path: P, password: S) -> Result<Vec<u8>, Error>
 where
@@ -41,8 +50,94 @@ where
     file.read_to_string(&mut contents)?;
     let keystore: EthKeystore = serde_json::from_str(&contents)?;
 
+    decrypt_crypto(keystore.crypto, password)
+}
+
+pub fn encrypt_key<P, B, S>(dir: P, pk: B, password: S) -> Result<String, Error>
+where
+    P: AsRef<Path>,
+    B: AsRef<[u8]>,
+    S: AsRef<[u8]>,
+{
+    let crypto = encrypt_crypto(&pk, password)?;
+
+    // use id as the filename
+    let id = Uuid::new_v4();
+    let name = id.to_string();
+
+    // Construct and serialize the encrypted JSON keystore.
+    let keystore = EthKeystore {
+        id,
+        version: 3,
+        crypto,
+        address: address_from_pk(&pk)?,
+    };
+    let contents = serde_json::to_string(&keystore)?;
+
+    // Create a file in write-only mode, to store the encrypted JSON keystore.
+    let mut file = File::create(dir.as_ref().join(name))?;
+    file.write_all(contents.as_bytes())?;
+
+    Ok(id.to_string())
+}
+
+/// Encrypts arbitrary data into the "crypto" part of a Web3 Secret Storage
+/// file, using scrypt for key derivation and AES-128-CTR for encryption.
+pub(crate) fn encrypt_crypto<B, S>(data: B, password: S) -> Result<CryptoJson, Error>
+where
+    B: AsRef<[u8]>,
+    S: AsRef<[u8]>,
+{
+    // Generate a random salt.
+    let salt = utils::generate_salt(DEFAULT_KEY_SIZE);
+
     // Derive the key.
-    let key = match keystore.crypto.kdfparams {
+    let mut key = vec![0u8; DEFAULT_KDF_PARAMS_DKLEN as usize];
+    let scrypt_params = ScryptParams::new(
+        DEFAULT_KDF_PARAMS_LOG_N,
+        DEFAULT_KDF_PARAMS_R,
+        DEFAULT_KDF_PARAMS_P,
+        ScryptParams::RECOMMENDED_LEN,
+    )?;
+    scrypt(password.as_ref(), &salt, &scrypt_params, key.as_mut_slice())?;
+
+    // Encrypt the data using AES-128-CTR.
+    let iv = utils::generate_salt(DEFAULT_IV_SIZE);
+    let encryptor = Aes128Ctr::new(&key[..16], &iv[..16]).expect("invalid length");
+
+    let mut ciphertext = data.as_ref().to_vec();
+    encryptor.apply_keystream(&mut ciphertext);
+
+    // Calculate the MAC.
+    let mac = Keccak256::new()
+        .chain(&key[16..32])
+        .chain(&ciphertext)
+        .finalize();
+
+    Ok(CryptoJson {
+        cipher: String::from(DEFAULT_CIPHER),
+        cipherparams: CipherparamsJson { iv },
+        ciphertext,
+        kdf: KdfType::Scrypt,
+        kdfparams: KdfparamsType::Scrypt {
+            dklen: DEFAULT_KDF_PARAMS_DKLEN,
+            n: 2u32.pow(DEFAULT_KDF_PARAMS_LOG_N as u32),
+            p: DEFAULT_KDF_PARAMS_P,
+            r: DEFAULT_KDF_PARAMS_R,
+            salt,
+        },
+        mac: mac.to_vec(),
+    })
+}
+
+/// Verifies the MAC of and decrypts the "crypto" part of a Web3 Secret
+/// Storage file.
+pub(crate) fn decrypt_crypto<S>(crypto: CryptoJson, password: S) -> Result<Vec<u8>, Error>
+where
+    S: AsRef<[u8]>,
+{
+    // Derive the key.
+    let key = match crypto.kdfparams {
         KdfparamsType::Pbkdf2 {
             c,
             dklen,
@@ -72,92 +167,206 @@ where
     // Derive the MAC from the derived key and ciphertext.
     let derived_mac = Keccak256::new()
         .chain(&key[16..32])
-        .chain(&keystore.crypto.ciphertext)
+        .chain(&crypto.ciphertext)
         .finalize();
 
-    if derived_mac.as_slice() != keystore.crypto.mac.as_slice() {
+    if derived_mac.as_slice() != crypto.mac.as_slice() {
         return Err(anyhow!("MAC mismatch"));
     }
 
-    // Decrypt the private key bytes using AES-128-CTR
+    // Decrypt the bytes using AES-128-CTR
     let decryptor =
-        Aes128Ctr::new(&key[..16], &keystore.crypto.cipherparams.iv[..16]).expect("invalid length");
+        Aes128Ctr::new(&key[..16], &crypto.cipherparams.iv[..16]).expect("invalid length");
 
-    let mut pk = keystore.crypto.ciphertext;
-    decryptor.apply_keystream(&mut pk);
+    let mut data = crypto.ciphertext;
+    decryptor.apply_keystream(&mut data);
 
-    Ok(pk)
+    Ok(data)
 }
 
-pub fn encrypt_key<P, B, S>(dir: P, pk: B, password: S) -> Result<String, Error>
+/// Decrypts the secret of an EIP-2335 BLS keystore file.
+pub fn decrypt_bls_key<P, S>(path: P, password: S) -> Result<Vec<u8>, Error>
+where
+    P: AsRef<Path>,
+    S: AsRef<str>,
+{
+    let mut file = File::open(path)?;
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)?;
+    let keystore: BlsKeystore = serde_json::from_str(&contents)?;
+
+    decrypt_bls_keystore(&keystore, password)
+}
+
+/// Decrypts the secret of an already deserialized EIP-2335 BLS keystore.
+pub fn decrypt_bls_keystore<S>(keystore: &BlsKeystore, password: S) -> Result<Vec<u8>, Error>
+where
+    S: AsRef<str>,
+{
+    ensure!(
+        keystore.version == BLS_KEYSTORE_VERSION,
+        "unsupported EIP-2335 keystore version {}",
+        keystore.version,
+    );
+    ensure!(
+        keystore.crypto.checksum.function == BLS_CHECKSUM_FUNCTION,
+        "unsupported checksum function '{}'",
+        keystore.crypto.checksum.function,
+    );
+    ensure!(
+        keystore.crypto.cipher.function == DEFAULT_CIPHER,
+        "unsupported cipher function '{}'",
+        keystore.crypto.cipher.function,
+    );
+
+    let password = normalize_bls_password(password.as_ref());
+    let key = derive_bls_key(&keystore.crypto.kdf.function, &password)?;
+
+    // Verify the checksum of the derived key and cipher message.
+    let ciphertext = &keystore.crypto.cipher.message;
+    let checksum = Sha256::new()
+        .chain(&key[16..32])
+        .chain(ciphertext)
+        .finalize();
+
+    if checksum.as_slice() != keystore.crypto.checksum.message.as_slice() {
+        return Err(anyhow!("checksum mismatch"));
+    }
+
+    // Decrypt the secret bytes using AES-128-CTR
+    let iv = &keystore.crypto.cipher.params.iv;
+    ensure!(iv.len() == DEFAULT_IV_SIZE, "invalid cipher IV length");
+    let decryptor = Aes128Ctr::new(&key[..16], iv).expect("invalid length");
+
+    let mut secret = ciphertext.clone();
+    decryptor.apply_keystream(&mut secret);
+
+    Ok(secret)
+}
+
+/// Writes an EIP-2335 BLS keystore file for the specified secret to the
+/// directory. The public key and EIP-2334 derivation path are stored as-is.
+pub fn encrypt_bls_key<P, B, K, S>(
+    dir: P,
+    secret: B,
+    pubkey: K,
+    derivation_path: &str,
+    password: S,
+) -> Result<String, Error>
 where
     P: AsRef<Path>,
     B: AsRef<[u8]>,
-    S: AsRef<[u8]>,
+    K: AsRef<[u8]>,
+    S: AsRef<str>,
 {
-    // Generate a random salt.
-    // let mut salt = vec![0u8; DEFAULT_KEY_SIZE];
-    // rng.fill_bytes(salt.as_mut_slice());
     let salt = utils::generate_salt(DEFAULT_KEY_SIZE);
+    let kdf = BlsKdf::Scrypt {
+        dklen: DEFAULT_KDF_PARAMS_DKLEN,
+        n: 2u32.pow(DEFAULT_KDF_PARAMS_LOG_N as u32),
+        p: DEFAULT_KDF_PARAMS_P,
+        r: DEFAULT_KDF_PARAMS_R,
+        salt,
+    };
 
-    // Derive the key.
-    let mut key = vec![0u8; DEFAULT_KDF_PARAMS_DKLEN as usize];
-    let scrypt_params = ScryptParams::new(
-        DEFAULT_KDF_PARAMS_LOG_N,
-        DEFAULT_KDF_PARAMS_R,
-        DEFAULT_KDF_PARAMS_P,
-        ScryptParams::RECOMMENDED_LEN,
-    )?;
-    scrypt(password.as_ref(), &salt, &scrypt_params, key.as_mut_slice())?;
+    let password = normalize_bls_password(password.as_ref());
+    let key = derive_bls_key(&kdf, &password)?;
 
-    // Encrypt the private key using AES-128-CTR.
-    // let mut iv = vec![0u8; DEFAULT_IV_SIZE];
-    // rng.fill_bytes(iv.as_mut_slice());
+    // Encrypt the secret using AES-128-CTR.
     let iv = utils::generate_salt(DEFAULT_IV_SIZE);
     let encryptor = Aes128Ctr::new(&key[..16], &iv[..16]).expect("invalid length");
 
-    let mut ciphertext = pk.as_ref().to_vec();
+    let mut ciphertext = secret.as_ref().to_vec();
     encryptor.apply_keystream(&mut ciphertext);
 
-    // Calculate the MAC.
-    let mac = Keccak256::new()
+    // Calculate the checksum.
+    let checksum = Sha256::new()
         .chain(&key[16..32])
         .chain(&ciphertext)
         .finalize();
 
-    // use id as the filename
     let id = Uuid::new_v4();
     let name = id.to_string();
 
-    // Construct and serialize the encrypted JSON keystore.
-    let keystore = EthKeystore {
-        id,
-        version: 3,
-        crypto: CryptoJson {
-            cipher: String::from(DEFAULT_CIPHER),
-            cipherparams: CipherparamsJson { iv },
-            ciphertext: ciphertext.to_vec(),
-            kdf: KdfType::Scrypt,
-            kdfparams: KdfparamsType::Scrypt {
-                dklen: DEFAULT_KDF_PARAMS_DKLEN,
-                n: 2u32.pow(DEFAULT_KDF_PARAMS_LOG_N as u32),
-                p: DEFAULT_KDF_PARAMS_P,
-                r: DEFAULT_KDF_PARAMS_R,
-                salt,
+    let keystore = BlsKeystore {
+        crypto: BlsCryptoJson {
+            kdf: KdfModule {
+                function: kdf,
+                message: Vec::new(),
+            },
+            checksum: ChecksumModule {
+                function: String::from(BLS_CHECKSUM_FUNCTION),
+                params: EmptyParams::default(),
+                message: checksum.to_vec(),
+            },
+            cipher: CipherModule {
+                function: String::from(DEFAULT_CIPHER),
+                params: CipherModuleParams { iv },
+                message: ciphertext,
             },
-            mac: mac.to_vec(),
         },
-        address: address_from_pk(&pk)?,
+        description: None,
+        pubkey: pubkey.as_ref().to_vec(),
+        path: derivation_path.to_string(),
+        uuid: id,
+        version: BLS_KEYSTORE_VERSION,
     };
     let contents = serde_json::to_string(&keystore)?;
 
-    // Create a file in write-only mode, to store the encrypted JSON keystore.
     let mut file = File::create(dir.as_ref().join(name))?;
     file.write_all(contents.as_bytes())?;
 
     Ok(id.to_string())
 }
 
+/// Processes a password as specified by EIP-2335: the password is NFKD
+/// normalized and the C0, C1 and `Delete` control codes are stripped before
+/// it is UTF-8 encoded.
+pub fn normalize_bls_password(password: &str) -> Vec<u8> {
+    password
+        .nfkd()
+        .filter(|c| !matches!(*c as u32, 0x00..=0x1f | 0x7f..=0x9f))
+        .collect::<String>()
+        .into_bytes()
+}
+
+/// Derives the 32-byte decryption key for an EIP-2335 keystore.
+fn derive_bls_key(kdf: &BlsKdf, password: &[u8]) -> Result<Vec<u8>, Error> {
+    let key = match kdf {
+        BlsKdf::Pbkdf2 {
+            c,
+            dklen,
+            prf,
+            salt,
+        } => {
+            ensure!(prf == BLS_PBKDF2_PRF, "unsupported PBKDF2 PRF '{prf}'");
+            let mut key = vec![0u8; *dklen as usize];
+            pbkdf2::<Hmac<Sha256>>(password, salt, *c, key.as_mut_slice())
+                .expect("invalid length of key");
+            key
+        }
+        BlsKdf::Scrypt {
+            dklen,
+            n,
+            p,
+            r,
+            salt,
+        } => {
+            ensure!(
+                n.is_power_of_two(),
+                "scrypt N parameter must be a power of two"
+            );
+            let mut key = vec![0u8; *dklen as usize];
+            let log_n = n.trailing_zeros() as u8;
+            let scrypt_params = ScryptParams::new(log_n, *r, *p, ScryptParams::RECOMMENDED_LEN)?;
+            scrypt(password, salt, &scrypt_params, key.as_mut_slice())?;
+            key
+        }
+    };
+    ensure!(key.len() >= 32, "derived key must be at least 32 bytes");
+
+    Ok(key)
+}
+
 struct Aes128Ctr {
     inner: ctr::CtrCore<Aes128, ctr::flavors::Ctr128BE>,
 }
@@ -173,3 +382,99 @@ impl Aes128Ctr {
         self.inner.apply_keystream_partial(buf.into());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    // Test vectors from EIP-2335.
+    const PASSWORD: &str = "\u{1d531}\u{1d522}\u{1d530}\u{1d531}\u{1d52d}\u{1d51e}\u{1d530}\u{1d530}\u{1d534}\u{1d52c}\u{1d52f}\u{1d521}\u{1f511}";
+    const SECRET: [u8; 32] =
+        hex!("000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f");
+
+    fn keystore(kdf: serde_json::Value, checksum: &str, message: &str) -> BlsKeystore {
+        serde_json::from_value(serde_json::json!({
+            "crypto": {
+                "kdf": kdf,
+                "checksum": {
+                    "function": "sha256",
+                    "params": {},
+                    "message": checksum,
+                },
+                "cipher": {
+                    "function": "aes-128-ctr",
+                    "params": {
+                        "iv": "264daa3f303d7259501c93d997d84fe6",
+                    },
+                    "message": message,
+                },
+            },
+            "pubkey": "9612d7a727c9d0a22e185a1c768478dfe919cada9266988cb32359c11f2b7b27f4ae4040902382ae2910c15e2b420d07",
+            "path": "m/12381/60/3141592653/589793238",
+            "uuid": "1d85ae20-35c5-4611-98e8-aa14a633906f",
+            "version": 4,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn password_normalization() {
+        assert_eq!(
+            normalize_bls_password(PASSWORD),
+            "testpassword\u{1f511}".as_bytes()
+        );
+        assert_eq!(
+            normalize_bls_password("pass\u{7f}word\u{0}\u{85}"),
+            b"password"
+        );
+    }
+
+    #[test]
+    fn decrypt_scrypt_test_vector() {
+        let keystore = keystore(
+            serde_json::json!({
+                "function": "scrypt",
+                "params": {
+                    "dklen": 32,
+                    "n": 262144,
+                    "p": 1,
+                    "r": 8,
+                    "salt": "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3",
+                },
+                "message": "",
+            }),
+            "d2217fe5f3e9a1e34581ef8a78f7c9928e436d36dacc5e846690a5581e8ea484",
+            "06ae90d55fe0a6e9c5c3bc5b170827b2e5cce3929ed3f116c2811e6366dfe20f",
+        );
+        assert_eq!(decrypt_bls_keystore(&keystore, PASSWORD).unwrap(), SECRET);
+    }
+
+    #[test]
+    fn decrypt_pbkdf2_test_vector() {
+        let keystore = keystore(
+            serde_json::json!({
+                "function": "pbkdf2",
+                "params": {
+                    "dklen": 32,
+                    "c": 262144,
+                    "prf": "hmac-sha256",
+                    "salt": "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3",
+                },
+                "message": "",
+            }),
+            "8a9f5d9912ed7e75ea794bc5a89bca5f193721d30868ade6f73043c6ea6febf1",
+            "cee03fde2af33149775b7223e7845e4fb2c8ae1792e5f99fe9ecf474cc8c16ad",
+        );
+        assert_eq!(decrypt_bls_keystore(&keystore, PASSWORD).unwrap(), SECRET);
+        assert!(decrypt_bls_keystore(&keystore, "testpassword").is_err());
+    }
+
+    #[test]
+    fn bls_keystore_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let id = encrypt_bls_key(&dir, SECRET, [0x96; 48], "m/12381/3600/0/0/0", PASSWORD).unwrap();
+        let secret = decrypt_bls_key(dir.path().join(id), "testpassword\u{1f511}").unwrap();
+        assert_eq!(secret, SECRET);
+    }
+}
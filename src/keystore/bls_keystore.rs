@@ -0,0 +1,167 @@
+use hex::{FromHex, ToHex};
+use serde::{de::Deserializer, ser::Serializer, Deserialize, Serialize};
+use uuid::Uuid;
+
+/// This struct represents the deserialized form of an encrypted BLS keystore based on
+/// [EIP-2335](https://eips.ethereum.org/EIPS/eip-2335).
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BlsKeystore {
+    pub crypto: BlsCryptoJson,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(serialize_with = "buffer_to_hex", deserialize_with = "hex_to_buffer")]
+    pub pubkey: Vec<u8>,
+    pub path: String,
+    pub uuid: Uuid,
+    pub version: u8,
+}
+
+/// Represents the "crypto" part of an EIP-2335 keystore, made of three modules.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BlsCryptoJson {
+    pub kdf: KdfModule,
+    pub checksum: ChecksumModule,
+    pub cipher: CipherModule,
+}
+
+/// The key derivation module of an EIP-2335 keystore.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "function", content = "params", rename_all = "lowercase")]
+pub enum BlsKdf {
+    Pbkdf2 {
+        c: u32,
+        dklen: u8,
+        prf: String,
+        #[serde(serialize_with = "buffer_to_hex", deserialize_with = "hex_to_buffer")]
+        salt: Vec<u8>,
+    },
+    Scrypt {
+        dklen: u8,
+        n: u32,
+        p: u32,
+        r: u32,
+        #[serde(serialize_with = "buffer_to_hex", deserialize_with = "hex_to_buffer")]
+        salt: Vec<u8>,
+    },
+}
+
+/// Represents the "kdf" module, the function parameters are flattened into
+/// the module next to its (always empty) message.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct KdfModule {
+    #[serde(flatten)]
+    pub function: BlsKdf,
+    #[serde(serialize_with = "buffer_to_hex", deserialize_with = "hex_to_buffer")]
+    pub message: Vec<u8>,
+}
+
+/// Represents the "checksum" module. Only SHA-256 is defined by the EIP.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ChecksumModule {
+    pub function: String,
+    pub params: EmptyParams,
+    #[serde(serialize_with = "buffer_to_hex", deserialize_with = "hex_to_buffer")]
+    pub message: Vec<u8>,
+}
+
+/// Represents the "cipher" module. Only AES-128-CTR is defined by the EIP.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CipherModule {
+    pub function: String,
+    pub params: CipherModuleParams,
+    #[serde(serialize_with = "buffer_to_hex", deserialize_with = "hex_to_buffer")]
+    pub message: Vec<u8>,
+}
+
+/// Represents the "params" of the cipher module.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CipherModuleParams {
+    #[serde(serialize_with = "buffer_to_hex", deserialize_with = "hex_to_buffer")]
+    pub iv: Vec<u8>,
+}
+
+/// An empty JSON object, used for modules without parameters.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct EmptyParams {}
+
+// Serialization helpers for converting byte buffers to and from hex strings.
+
+/// Serializes a byte buffer into a hex string.
+fn buffer_to_hex<T, S>(buffer: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: AsRef<[u8]>,
+    S: Serializer,
+{
+    serializer.serialize_str(&buffer.encode_hex::<String>())
+}
+
+/// Deserializes a hex string into a byte buffer.
+fn hex_to_buffer<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    use serde::de::Error;
+    String::deserialize(deserializer)
+        .and_then(|string| Vec::from_hex(string).map_err(|err| Error::custom(err.to_string())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_eip2335_keystore() {
+        let data = r#"
+        {
+            "crypto": {
+                "kdf": {
+                    "function": "pbkdf2",
+                    "params": {
+                        "dklen": 32,
+                        "c": 262144,
+                        "prf": "hmac-sha256",
+                        "salt": "d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"
+                    },
+                    "message": ""
+                },
+                "checksum": {
+                    "function": "sha256",
+                    "params": {},
+                    "message": "8a9f5d9912ed7e75ea794bc5a89bca5f193721d30868ade6f73043c6ea6febf1"
+                },
+                "cipher": {
+                    "function": "aes-128-ctr",
+                    "params": {
+                        "iv": "264daa3f303d7259501c93d997d84fe6"
+                    },
+                    "message": "cee03fde2af33149775b7223e7845e4fb2c8ae1792e5f99fe9ecf474cc8c16ad"
+                }
+            },
+            "description": "This is a test keystore that uses PBKDF2 to secure the secret.",
+            "pubkey": "9612d7a727c9d0a22e185a1c768478dfe919cada9266988cb32359c11f2b7b27f4ae4040902382ae2910c15e2b420d07",
+            "path": "m/12381/60/0/0",
+            "uuid": "64625def-3331-4eea-ab6f-782f3ed16a83",
+            "version": 4
+        }"#;
+        let keystore: BlsKeystore = serde_json::from_str(data).unwrap();
+        assert_eq!(keystore.version, 4);
+        assert_eq!(keystore.path, "m/12381/60/0/0");
+        assert_eq!(keystore.pubkey.len(), 48);
+        assert!(matches!(
+            keystore.crypto.kdf.function,
+            BlsKdf::Pbkdf2 {
+                c: 262144,
+                dklen: 32,
+                ..
+            }
+        ));
+
+        let roundtrip = serde_json::to_value(&keystore).unwrap();
+        assert_eq!(roundtrip["crypto"]["kdf"]["function"], "pbkdf2");
+        assert_eq!(roundtrip["crypto"]["kdf"]["params"]["c"], 262144);
+        assert_eq!(
+            roundtrip["crypto"]["checksum"]["params"],
+            serde_json::json!({})
+        );
+    }
+}
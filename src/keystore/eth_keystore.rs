@@ -1,4 +1,4 @@
-use ethereum_types::H160 as Address;
+use crate::address::Address;
 use hex::{FromHex, ToHex};
 use serde::{de::Deserializer, ser::Serializer, Deserialize, Serialize};
 use uuid::Uuid;
@@ -7,6 +7,7 @@ use uuid::Uuid;
 /// [Web3 Secret Storage Definition](https://github.com/ethereum/wiki/wiki/Web3-Secret-Storage-Definition).
 #[derive(Debug, Deserialize, Serialize)]
 pub struct EthKeystore {
+    #[serde(serialize_with = "buffer_to_hex")]
     pub address: Address,
     pub crypto: CryptoJson,
     pub id: Uuid,
@@ -113,7 +114,7 @@ mod tests {
         }"#;
         let keystore: EthKeystore = serde_json::from_str(data).unwrap();
         assert_eq!(
-            keystore.address.as_bytes().to_vec(),
+            keystore.address.to_vec(),
             hex::decode("00000398232e2064f896018496b4b44b3d62751f").unwrap()
         );
     }
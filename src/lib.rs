@@ -1,7 +1,15 @@
+pub mod abi;
+pub mod address;
 pub mod bip32;
 pub mod bip39;
+pub mod bip85;
+pub mod ens;
 pub mod hdwallet;
+pub mod keyring;
 pub mod keystore;
+pub mod signer;
+pub mod siwe;
 pub mod transaction;
 pub mod utils;
+pub mod vanity;
 pub mod wallet;
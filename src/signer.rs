@@ -0,0 +1,244 @@
+//! Module defining the signer abstraction over key backends.
+//!
+//! Anything that can produce a secp256k1 signature for an address can
+//! implement [`Signer`]: an in-memory [`Wallet`], a keystore that is
+//! decrypted lazily, or a remote or threshold signer. Backends that need to
+//! wait on I/O implement [`AsyncSigner`] instead.
+
+use crate::address::Address;
+use crate::{
+    transaction::Transaction,
+    utils::hash,
+    wallet::{Signature, Wallet},
+};
+use anyhow::Result;
+use std::future::Future;
+
+/// A synchronous signer.
+pub trait Signer {
+    /// Returns the address of the signing key.
+    fn address(&self) -> Address;
+
+    /// Signs a 32-byte hash.
+    fn sign_hash(&self, hash: [u8; 32]) -> Result<Signature>;
+
+    /// Signs the Keccak-256 hash of a message.
+    fn sign_message(&self, message: &[u8]) -> Result<Signature> {
+        self.sign_hash(hash::keccak256(message))
+    }
+
+    /// Signs EIP-712 typed data given its domain separator and the hash of
+    /// the message struct.
+    fn sign_typed_data(
+        &self,
+        domain_separator: [u8; 32],
+        struct_hash: [u8; 32],
+    ) -> Result<Signature> {
+        self.sign_hash(typed_data_hash(domain_separator, struct_hash))
+    }
+
+    /// Signs a transaction and returns it RLP encoded with its signature.
+    fn sign_transaction(&self, transaction: &Transaction) -> Result<Vec<u8>> {
+        let signature = self.sign_hash(transaction.get_unsigned_rlp_encoded())?;
+        Ok(transaction.get_signed_rlp_encoded(signature))
+    }
+}
+
+/// An asynchronous signer, for backends such as remote signers.
+///
+/// Every [`Signer`] is also an `AsyncSigner` that completes immediately,
+/// forwarding each method to its `Signer` version.
+pub trait AsyncSigner: Sync {
+    /// Returns the address of the signing key.
+    fn address(&self) -> Address;
+
+    /// Signs a 32-byte hash.
+    fn sign_hash(&self, hash: [u8; 32]) -> impl Future<Output = Result<Signature>> + Send;
+
+    /// Signs the Keccak-256 hash of a message.
+    fn sign_message(&self, message: &[u8]) -> impl Future<Output = Result<Signature>> + Send {
+        self.sign_hash(hash::keccak256(message))
+    }
+
+    /// Signs EIP-712 typed data given its domain separator and the hash of
+    /// the message struct.
+    fn sign_typed_data(
+        &self,
+        domain_separator: [u8; 32],
+        struct_hash: [u8; 32],
+    ) -> impl Future<Output = Result<Signature>> + Send {
+        self.sign_hash(typed_data_hash(domain_separator, struct_hash))
+    }
+
+    /// Signs a transaction and returns it RLP encoded with its signature.
+    fn sign_transaction(
+        &self,
+        transaction: &Transaction,
+    ) -> impl Future<Output = Result<Vec<u8>>> + Send {
+        let message = transaction.get_unsigned_rlp_encoded();
+        let transaction = transaction.clone();
+        async move {
+            let signature = self.sign_hash(message).await?;
+            Ok(transaction.get_signed_rlp_encoded(signature))
+        }
+    }
+}
+
+impl<T> AsyncSigner for T
+where
+    T: Signer + Sync,
+{
+    fn address(&self) -> Address {
+        Signer::address(self)
+    }
+
+    fn sign_hash(&self, hash: [u8; 32]) -> impl Future<Output = Result<Signature>> + Send {
+        std::future::ready(Signer::sign_hash(self, hash))
+    }
+
+    fn sign_message(&self, message: &[u8]) -> impl Future<Output = Result<Signature>> + Send {
+        std::future::ready(Signer::sign_message(self, message))
+    }
+
+    fn sign_typed_data(
+        &self,
+        domain_separator: [u8; 32],
+        struct_hash: [u8; 32],
+    ) -> impl Future<Output = Result<Signature>> + Send {
+        std::future::ready(Signer::sign_typed_data(self, domain_separator, struct_hash))
+    }
+
+    fn sign_transaction(
+        &self,
+        transaction: &Transaction,
+    ) -> impl Future<Output = Result<Vec<u8>>> + Send {
+        std::future::ready(Signer::sign_transaction(self, transaction))
+    }
+}
+
+impl Signer for Wallet {
+    fn address(&self) -> Address {
+        Wallet::address(self)
+    }
+
+    fn sign_hash(&self, hash: [u8; 32]) -> Result<Signature> {
+        self.sign(hash)
+    }
+}
+
+/// Returns the EIP-712 signing hash: `keccak256(0x1901 ‖ domainSeparator ‖ hashStruct)`.
+pub fn typed_data_hash(domain_separator: [u8; 32], struct_hash: [u8; 32]) -> [u8; 32] {
+    hash::keccak256([&[0x19, 0x01][..], &domain_separator, &struct_hash].concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethnum::AsU256 as _;
+    use hex_literal::hex;
+
+    const PRIVATE_KEY: [u8; 32] =
+        hex!("4f3edf983ac636a65a842ce7c78d9aa706d3b113bce9c46f30d7d21715b23b1d");
+
+    /// A signer that only decrypts its key when signing.
+    struct LazySigner {
+        secret: [u8; 32],
+        address: Address,
+    }
+
+    impl Signer for LazySigner {
+        fn address(&self) -> Address {
+            self.address
+        }
+
+        fn sign_hash(&self, hash: [u8; 32]) -> Result<Signature> {
+            Wallet::from_secret(self.secret)?.sign(hash)
+        }
+    }
+
+    /// A signer that refuses to blind sign hashes, like a hardware wallet
+    /// that has to show the message.
+    struct MessageOnlySigner(Wallet);
+
+    impl Signer for MessageOnlySigner {
+        fn address(&self) -> Address {
+            self.0.address()
+        }
+
+        fn sign_hash(&self, _: [u8; 32]) -> Result<Signature> {
+            anyhow::bail!("blind signing is disabled")
+        }
+
+        fn sign_message(&self, message: &[u8]) -> Result<Signature> {
+            Signer::sign_message(&self.0, message)
+        }
+    }
+
+    fn transaction() -> Transaction {
+        Transaction {
+            chain_id: 1.as_u256(),
+            gas: 21_000.as_u256(),
+            to: Some(Address::default()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn wallet_and_custom_signers_agree() {
+        let wallet = Wallet::from_secret(PRIVATE_KEY).unwrap();
+        let lazy = LazySigner {
+            secret: PRIVATE_KEY,
+            address: wallet.address(),
+        };
+        let signers: [&dyn Signer; 2] = [&wallet, &lazy];
+
+        for signer in signers {
+            assert_eq!(signer.address(), wallet.address());
+            assert_eq!(
+                signer.sign_message(b"hello").unwrap(),
+                wallet.sign_message(b"hello").unwrap(),
+            );
+            assert_eq!(
+                signer.sign_transaction(&transaction()).unwrap(),
+                transaction().sign(&wallet).unwrap(),
+            );
+        }
+    }
+
+    #[test]
+    fn typed_data_signing_hash() {
+        // Example from EIP-712 with the "Mail" message.
+        let domain_separator =
+            hex!("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f");
+        let struct_hash = hex!("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e");
+        assert_eq!(
+            typed_data_hash(domain_separator, struct_hash),
+            hex!("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"),
+        );
+    }
+
+    #[tokio::test]
+    async fn async_signer() {
+        let wallet = Wallet::from_secret(PRIVATE_KEY).unwrap();
+        assert_eq!(
+            AsyncSigner::sign_message(&wallet, b"hello").await.unwrap(),
+            Signer::sign_message(&wallet, b"hello").unwrap(),
+        );
+        assert_eq!(
+            AsyncSigner::sign_transaction(&wallet, &transaction())
+                .await
+                .unwrap(),
+            transaction().sign_async(&wallet).await.unwrap(),
+        );
+
+        // Overridden methods are used instead of signing the hash.
+        let signer = MessageOnlySigner(Wallet::from_secret(PRIVATE_KEY).unwrap());
+        assert_eq!(
+            AsyncSigner::sign_message(&signer, b"hello").await.unwrap(),
+            Signer::sign_message(&wallet, b"hello").unwrap(),
+        );
+        assert!(AsyncSigner::sign_transaction(&signer, &transaction())
+            .await
+            .is_err());
+    }
+}
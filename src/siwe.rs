@@ -0,0 +1,680 @@
+//! Module implementing Sign-In with Ethereum (EIP-4361) messages.
+//!
+//! A message is signed as an EIP-191 personal message, and verified by
+//! recovering the signer and checking the message against the expected
+//! domain, nonce and the current time.
+//!
+//! <https://eips.ethereum.org/EIPS/eip-4361>
+
+use crate::{
+    address::Address,
+    utils::{self, hash::eip191_hash},
+    wallet::{Signature, Wallet},
+};
+use anyhow::{bail, ensure, Context as _, Result};
+use rand::Rng as _;
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::Range,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const HEADER: &str = " wants you to sign in with your Ethereum account:";
+
+/// A Sign-In with Ethereum message.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Message {
+    /// The URI scheme of the origin of the request, if not `https`.
+    pub scheme: Option<String>,
+    /// The RFC 3986 authority requesting the signing, for example
+    /// `example.com` or `localhost:8080`.
+    pub domain: String,
+    pub address: Address,
+    /// A human-readable assertion the user signs, without newlines.
+    pub statement: Option<String>,
+    /// The RFC 3986 URI referring to the resource that is the subject of the
+    /// signing.
+    pub uri: String,
+    /// The message version, always `1`.
+    pub version: String,
+    pub chain_id: u64,
+    /// A random alphanumeric string of at least 8 characters, to prevent
+    /// replay attacks.
+    pub nonce: String,
+    pub issued_at: Timestamp,
+    pub expiration_time: Option<Timestamp>,
+    pub not_before: Option<Timestamp>,
+    pub request_id: Option<String>,
+    pub resources: Vec<String>,
+}
+
+impl Message {
+    /// Creates a message issued now with a random nonce. Optional fields can
+    /// be set afterwards.
+    pub fn new(domain: &str, address: Address, uri: &str, chain_id: u64) -> Result<Self> {
+        let message = Self {
+            scheme: None,
+            domain: domain.to_owned(),
+            address,
+            statement: None,
+            uri: uri.to_owned(),
+            version: "1".to_owned(),
+            chain_id,
+            nonce: generate_nonce(),
+            issued_at: Timestamp::from(SystemTime::now()),
+            expiration_time: None,
+            not_before: None,
+            request_id: None,
+            resources: Vec::new(),
+        };
+        message.validate()?;
+        Ok(message)
+    }
+
+    /// Checks that all fields are well-formed, so that the message can be
+    /// parsed back from its string representation.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(scheme) = &self.scheme {
+            ensure!(is_scheme(scheme), "invalid SIWE scheme '{scheme}'");
+        }
+        ensure!(
+            !self.domain.is_empty()
+                && !self
+                    .domain
+                    .contains(|c: char| c.is_whitespace() || c == '/'),
+            "invalid SIWE domain '{}'",
+            self.domain
+        );
+        if let Some(statement) = &self.statement {
+            ensure!(
+                !statement.is_empty() && !statement.contains('\n'),
+                "SIWE statement must be a single non-empty line"
+            );
+        }
+        ensure!(is_uri(&self.uri), "invalid SIWE URI '{}'", self.uri);
+        ensure!(
+            self.version == "1",
+            "unsupported SIWE version '{}'",
+            self.version
+        );
+        ensure!(
+            self.nonce.len() >= 8 && self.nonce.bytes().all(|b| b.is_ascii_alphanumeric()),
+            "SIWE nonce must be at least 8 alphanumeric characters"
+        );
+        if let Some(request_id) = &self.request_id {
+            ensure!(
+                !request_id.contains(char::is_whitespace),
+                "invalid SIWE request ID '{request_id}'"
+            );
+        }
+        for resource in &self.resources {
+            ensure!(is_uri(resource), "invalid SIWE resource '{resource}'");
+        }
+        Ok(())
+    }
+
+    /// Signs the message with a wallet as an EIP-191 personal message.
+    pub fn sign(&self, wallet: &Wallet) -> Result<Signature> {
+        self.validate()?;
+        ensure!(
+            wallet.address() == self.address,
+            "wallet {} cannot sign in as {}",
+            wallet.address(),
+            self.address
+        );
+        wallet.sign_personal_message(self.to_string())
+    }
+
+    /// Verifies a signed message: the signature must recover the message
+    /// address, the domain and nonce must match the expected ones, and `now`
+    /// must be within the message's validity period.
+    pub fn verify(
+        &self,
+        signature: &Signature,
+        domain: &str,
+        nonce: &str,
+        now: SystemTime,
+    ) -> Result<()> {
+        let signer = signature.recover(eip191_hash(self.to_string()))?;
+        ensure!(
+            signer == self.address,
+            "SIWE message signed by {signer} instead of {}",
+            self.address
+        );
+        ensure!(
+            self.domain == domain,
+            "SIWE message for domain '{}' instead of '{domain}'",
+            self.domain
+        );
+        ensure!(self.nonce == nonce, "SIWE nonce mismatch");
+        if let Some(expiration_time) = &self.expiration_time {
+            ensure!(
+                now < expiration_time.time(),
+                "SIWE message expired at {expiration_time}"
+            );
+        }
+        if let Some(not_before) = &self.not_before {
+            ensure!(
+                now >= not_before.time(),
+                "SIWE message not valid before {not_before}"
+            );
+        }
+        Ok(())
+    }
+}
+
+impl Display for Message {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if let Some(scheme) = &self.scheme {
+            write!(f, "{scheme}://")?;
+        }
+        writeln!(f, "{}{HEADER}", self.domain)?;
+        writeln!(f, "{}", self.address.checksum())?;
+        writeln!(f)?;
+        if let Some(statement) = &self.statement {
+            writeln!(f, "{statement}")?;
+        }
+        writeln!(f)?;
+        writeln!(f, "URI: {}", self.uri)?;
+        writeln!(f, "Version: {}", self.version)?;
+        writeln!(f, "Chain ID: {}", self.chain_id)?;
+        writeln!(f, "Nonce: {}", self.nonce)?;
+        write!(f, "Issued At: {}", self.issued_at)?;
+        if let Some(expiration_time) = &self.expiration_time {
+            write!(f, "\nExpiration Time: {expiration_time}")?;
+        }
+        if let Some(not_before) = &self.not_before {
+            write!(f, "\nNot Before: {not_before}")?;
+        }
+        if let Some(request_id) = &self.request_id {
+            write!(f, "\nRequest ID: {request_id}")?;
+        }
+        if !self.resources.is_empty() {
+            write!(f, "\nResources:")?;
+            for resource in &self.resources {
+                write!(f, "\n- {resource}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Message {
+    type Err = anyhow::Error;
+
+    /// Strictly parses a message: every line must be in the order and format
+    /// of EIP-4361, and the address must be EIP-55 checksummed. Forms that
+    /// the ABNF allows but that do not format back to the same text, such as
+    /// a chain ID with leading zeros or an empty resources list, are
+    /// rejected so that signatures verify over the text that was signed.
+    fn from_str(s: &str) -> Result<Self> {
+        let mut lines = s.split('\n').peekable();
+        let mut next = |what: &str| {
+            lines
+                .next()
+                .with_context(|| format!("SIWE message missing {what}"))
+        };
+
+        let origin = next("header")?
+            .strip_suffix(HEADER)
+            .context("invalid SIWE message header")?;
+        let (scheme, domain) = match origin.split_once("://") {
+            Some((scheme, domain)) => (Some(scheme.to_owned()), domain),
+            None => (None, origin),
+        };
+
+        let address = next("address")?;
+        let parsed = address.parse::<Address>()?;
+        ensure!(
+            parsed.checksum() == address,
+            "SIWE address '{address}' is not EIP-55 checksummed"
+        );
+        ensure!(next("empty line")?.is_empty(), "expected empty line");
+        let statement = match next("statement")? {
+            "" => None,
+            statement => {
+                ensure!(next("empty line")?.is_empty(), "expected empty line");
+                Some(statement.to_owned())
+            }
+        };
+
+        let mut field = |tag: &str| -> Result<String> {
+            let line = next(tag)?;
+            line.strip_prefix(tag)
+                .and_then(|line| line.strip_prefix(": "))
+                .map(str::to_owned)
+                .with_context(|| format!("expected SIWE field '{tag}' but got '{line}'"))
+        };
+        let uri = field("URI")?;
+        let version = field("Version")?;
+        let chain_id = field("Chain ID")?;
+        ensure!(
+            !chain_id.is_empty()
+                && chain_id.bytes().all(|b| b.is_ascii_digit())
+                && (chain_id == "0" || !chain_id.starts_with('0')),
+            "invalid SIWE chain ID '{chain_id}'"
+        );
+        let nonce = field("Nonce")?;
+        let issued_at = field("Issued At")?.parse()?;
+
+        let mut optional = |tag: &str| {
+            let value = lines
+                .peek()?
+                .strip_prefix(tag)?
+                .strip_prefix(": ")?
+                .to_owned();
+            lines.next();
+            Some(value)
+        };
+        let expiration_time = optional("Expiration Time").map(|t| t.parse()).transpose()?;
+        let not_before = optional("Not Before").map(|t| t.parse()).transpose()?;
+        let request_id = optional("Request ID");
+
+        let mut resources = Vec::new();
+        if lines.next_if_eq(&"Resources:").is_some() {
+            for line in lines.by_ref() {
+                let resource = line
+                    .strip_prefix("- ")
+                    .with_context(|| format!("invalid SIWE resource line '{line}'"))?;
+                resources.push(resource.to_owned());
+            }
+            ensure!(!resources.is_empty(), "empty SIWE resources list");
+        }
+        if let Some(line) = lines.next() {
+            bail!("unexpected line '{line}' in SIWE message");
+        }
+
+        let message = Self {
+            scheme,
+            domain: domain.to_owned(),
+            address: parsed,
+            statement,
+            uri,
+            version,
+            chain_id: chain_id.parse()?,
+            nonce,
+            issued_at,
+            expiration_time,
+            not_before,
+            request_id,
+            resources,
+        };
+        message.validate()?;
+
+        // The signature is verified over the formatted message, so only the
+        // canonical text is accepted.
+        ensure!(
+            message.to_string() == s,
+            "SIWE message is not in canonical form"
+        );
+        Ok(message)
+    }
+}
+
+/// An RFC 3339 timestamp that keeps its original representation, since the
+/// exact text is part of the signed message.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Timestamp {
+    text: String,
+    time: SystemTime,
+}
+
+impl Timestamp {
+    pub fn time(&self) -> SystemTime {
+        self.time
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+}
+
+impl From<SystemTime> for Timestamp {
+    /// Formats a time in UTC with millisecond precision.
+    fn from(time: SystemTime) -> Self {
+        let millis = match time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_millis() as i64,
+            Err(err) => -(err.duration().as_millis() as i64),
+        };
+        let (days, millis) = (millis.div_euclid(86_400_000), millis.rem_euclid(86_400_000));
+        let (year, month, day) = civil_from_days(days);
+        let seconds = millis / 1000;
+        let text = format!(
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60,
+            millis % 1000,
+        );
+        Self {
+            time: time_from_millis(days * 86_400_000 + millis),
+            text,
+        }
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = anyhow::Error;
+
+    /// Parses an RFC 3339 timestamp such as `2021-09-30T16:25:24Z` or
+    /// `2021-09-30T18:25:24.000+02:00`.
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || format!("invalid RFC 3339 timestamp '{s}'");
+        let number = |s: &str, range| digits(s, range).with_context(invalid);
+        let separator = |i: usize, expected: &[u8]| -> Result<()> {
+            ensure!(
+                s.as_bytes().get(i).is_some_and(|b| expected.contains(b)),
+                invalid()
+            );
+            Ok(())
+        };
+
+        let (year, month, day) = (number(s, 0..4)?, number(s, 5..7)?, number(s, 8..10)?);
+        let (hour, minute, second) = (number(s, 11..13)?, number(s, 14..16)?, number(s, 17..19)?);
+        separator(4, b"-")?;
+        separator(7, b"-")?;
+        separator(10, b"Tt")?;
+        separator(13, b":")?;
+        separator(16, b":")?;
+        ensure!(
+            matches!(month, 1..=12)
+                && day >= 1
+                && day <= days_in_month(year, month)
+                && hour < 24
+                && minute < 60
+                && second <= 60,
+            invalid()
+        );
+
+        let mut rest = &s[19..];
+        let mut millis = 0;
+        if let Some(fraction) = rest.strip_prefix('.') {
+            let len = fraction.bytes().take_while(u8::is_ascii_digit).count();
+            ensure!(len > 0, invalid());
+            millis = format!("{:0<3}", &fraction[..len.min(3)]).parse()?;
+            rest = &fraction[len..];
+        }
+        let offset = match rest {
+            "Z" | "z" => 0,
+            _ => {
+                let sign = match rest.as_bytes().first() {
+                    Some(b'+') => 1,
+                    Some(b'-') => -1,
+                    _ => bail!(invalid()),
+                };
+                ensure!(rest.len() == 6 && rest.as_bytes()[3] == b':', invalid());
+                let (hours, minutes) = (number(rest, 1..3)?, number(rest, 4..6)?);
+                ensure!(hours < 24 && minutes < 60, invalid());
+                sign * (hours * 60 + minutes)
+            }
+        };
+
+        let seconds =
+            days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second
+                - offset * 60;
+        Ok(Self {
+            text: s.to_owned(),
+            time: time_from_millis(seconds * 1000 + millis),
+        })
+    }
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Generates a random alphanumeric nonce of 17 characters, about 96 bits.
+pub fn generate_nonce() -> String {
+    const CHARSET: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    let mut rng = utils::get_rng();
+    (0..17)
+        .map(|_| CHARSET[rng.gen_range(0..CHARSET.len())] as char)
+        .collect()
+}
+
+// Returns whether a string is an RFC 3986 URI scheme.
+fn is_scheme(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_alphabetic())
+        && s.bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'))
+}
+
+// Returns whether a string looks like an absolute RFC 3986 URI: a scheme
+// followed by a non-empty part without whitespace.
+fn is_uri(s: &str) -> bool {
+    match s.split_once(':') {
+        Some((scheme, rest)) => {
+            is_scheme(scheme) && !rest.is_empty() && !rest.contains(char::is_whitespace)
+        }
+        None => false,
+    }
+}
+
+// Parses the decimal digits of a string range, without a sign.
+fn digits(s: &str, range: Range<usize>) -> Option<i64> {
+    let digits = s.get(range)?;
+    match digits.bytes().all(|b| b.is_ascii_digit()) {
+        true => digits.parse().ok(),
+        false => None,
+    }
+}
+
+fn time_from_millis(millis: i64) -> SystemTime {
+    match u64::try_from(millis) {
+        Ok(millis) => UNIX_EPOCH + Duration::from_millis(millis),
+        Err(_) => UNIX_EPOCH - Duration::from_millis(millis.unsigned_abs()),
+    }
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Returns the number of days since the Unix epoch of a proleptic Gregorian
+// calendar date, using Howard Hinnant's `days_from_civil` algorithm.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+// The inverse of `days_from_civil`.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    const MESSAGE: &str = "\
+service.invalid wants you to sign in with your Ethereum account:
+0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2
+
+I accept the ServiceOrg Terms of Service: https://service.invalid/tos
+
+URI: https://service.invalid/login
+Version: 1
+Chain ID: 1
+Nonce: 32891756
+Issued At: 2021-09-30T16:25:24Z
+Resources:
+- ipfs://bafybeiemxf5abjwjbikoz4mc3a3dla6ual3jsgpdr4cjr3oz3evfyavhwq/
+- https://example.com/my-web2-claim.json";
+
+    fn at(seconds: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(seconds)
+    }
+
+    #[test]
+    fn parse_messages() {
+        let message = MESSAGE.parse::<Message>().unwrap();
+        assert_eq!(message.domain, "service.invalid");
+        assert_eq!(
+            message.statement.as_deref(),
+            Some("I accept the ServiceOrg Terms of Service: https://service.invalid/tos")
+        );
+        assert_eq!(message.chain_id, 1);
+        assert_eq!(message.issued_at.time(), at(1_633_019_124));
+        assert_eq!(message.resources.len(), 2);
+        assert_eq!(message.to_string(), MESSAGE);
+
+        let minimal = "\
+https://localhost:4361 wants you to sign in with your Ethereum account:
+0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2
+
+
+URI: https://localhost/login
+Version: 1
+Chain ID: 137
+Nonce: abcdefgh
+Issued At: 2021-09-30T18:25:24.123+02:00
+Expiration Time: 2021-10-01T16:25:24Z
+Not Before: 2021-09-30T16:25:24Z
+Request ID: some-id";
+        let message = minimal.parse::<Message>().unwrap();
+        assert_eq!(message.scheme.as_deref(), Some("https"));
+        assert_eq!(message.domain, "localhost:4361");
+        assert_eq!(message.statement, None);
+        assert_eq!(
+            message.issued_at.time(),
+            at(1_633_019_124) + Duration::from_millis(123)
+        );
+        assert_eq!(message.request_id.as_deref(), Some("some-id"));
+        assert_eq!(message.to_string(), minimal);
+
+        for (from, to) in [
+            (
+                "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2",
+                "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2",
+            ),
+            ("Version: 1", "Version: 2"),
+            ("Nonce: 32891756", "Nonce: 3289"),
+            ("Chain ID: 1", "Chain ID: +1"),
+            (
+                "Issued At: 2021-09-30T16:25:24Z",
+                "Issued At: 2021-09-31T16:25:24Z",
+            ),
+            (
+                "Issued At: 2021-09-30T16:25:24Z",
+                "Issued At: 2021-09-30 16:25:24Z",
+            ),
+            ("Version: 1\n", ""),
+            ("Resources:", "Resources: none"),
+            ("Chain ID: 1", "Chain ID: 01"),
+            ("Chain ID: 1", "Chain ID: 00"),
+            ("\n\nURI", "\nURI"),
+        ] {
+            let invalid = MESSAGE.replace(from, to);
+            assert!(invalid.parse::<Message>().is_err(), "{invalid}");
+        }
+        assert!(format!("{MESSAGE}\n").parse::<Message>().is_err());
+
+        let empty_resources = &MESSAGE[..MESSAGE.find("\n- ").unwrap()];
+        assert_eq!(
+            empty_resources.parse::<Message>().unwrap_err().to_string(),
+            "empty SIWE resources list"
+        );
+    }
+
+    #[test]
+    fn timestamps() {
+        for (text, seconds) in [
+            ("1970-01-01T00:00:00Z", 0),
+            ("2024-02-29T12:00:00Z", 1_709_208_000),
+            ("2024-02-29T14:30:00+02:30", 1_709_208_000),
+        ] {
+            assert_eq!(text.parse::<Timestamp>().unwrap().time(), at(seconds));
+        }
+        assert_eq!(
+            "1969-12-31T23:59:59Z".parse::<Timestamp>().unwrap().time(),
+            UNIX_EPOCH - Duration::from_secs(1)
+        );
+        for text in [
+            "2023-02-29T00:00:00Z",
+            "2021-09-30T24:00:00Z",
+            "2021-09-30T16:25:24",
+            "2021-09-30T16:25:24.Z",
+            "2021-09-30T16:25:24+0200",
+        ] {
+            assert!(text.parse::<Timestamp>().is_err(), "{text}");
+        }
+
+        let timestamp = Timestamp::from(at(1_709_208_000) + Duration::from_millis(42));
+        assert_eq!(timestamp.as_str(), "2024-02-29T12:00:00.042Z");
+        assert_eq!(timestamp.as_str().parse::<Timestamp>().unwrap(), timestamp);
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let wallet = Wallet::from_secret(hex!(
+            "4f3edf983ac636a65a842ce7c78d9aa706d3b113bce9c46f30d7d21715b23b1d"
+        ))
+        .unwrap();
+        let mut message = Message::new(
+            "example.com",
+            wallet.address(),
+            "https://example.com/login",
+            1,
+        )
+        .unwrap();
+        message.statement = Some("Sign in to Example".to_owned());
+        message.issued_at = "2021-09-30T16:25:24Z".parse().unwrap();
+        message.not_before = Some(message.issued_at.clone());
+        message.expiration_time = Some("2021-09-30T17:25:24Z".parse().unwrap());
+        message.resources = vec!["https://example.com/terms".to_owned()];
+
+        let signature = message.sign(&wallet).unwrap();
+        let parsed = message.to_string().parse::<Message>().unwrap();
+        assert_eq!(parsed, message);
+
+        let (domain, nonce) = ("example.com", message.nonce.as_str());
+        let now = at(1_633_019_124 + 60);
+        parsed.verify(&signature, domain, nonce, now).unwrap();
+
+        assert!(parsed.verify(&signature, "evil.com", nonce, now).is_err());
+        assert!(parsed
+            .verify(&signature, domain, "otherNonce", now)
+            .is_err());
+        assert!(parsed
+            .verify(&signature, domain, nonce, at(1_633_019_123))
+            .is_err());
+        assert!(parsed
+            .verify(&signature, domain, nonce, at(1_633_022_724))
+            .is_err());
+
+        let mut tampered = parsed.clone();
+        tampered.chain_id = 5;
+        assert!(tampered.verify(&signature, domain, nonce, now).is_err());
+
+        let other = Wallet::from_secret([1; 32]).unwrap();
+        assert!(message.sign(&other).is_err());
+        assert!(Message::new("example.com", wallet.address(), "not a uri", 1).is_err());
+    }
+}
@@ -2,12 +2,14 @@
 //! implementation.
 
 pub mod accesslist;
-mod rlp;
+pub(crate) mod rlp;
+pub mod token;
 
+use crate::address::Address;
+use crate::signer::{AsyncSigner, Signer};
 use crate::utils::hash;
 use crate::{transaction::accesslist::AccessList, utils::serialization, wallet::Signature};
 use anyhow::Result;
-use ethaddr::Address;
 use ethnum::U256;
 use serde::Deserialize;
 
@@ -55,11 +57,35 @@ pub struct Transaction {
 impl Transaction {
     // Sign with a wallet.
     pub fn sign_with_wallet(&mut self, wallet: &crate::wallet::Wallet) -> Result<Vec<u8>> {
-        let message = self.get_unsigned_rlp_encoded();
-        let signature = wallet.sign(message)?;
-        let encoded = self.get_signed_rlp_encoded(signature);
+        self.sign(wallet)
+    }
+
+    /// Signs the transaction with any signer and returns the RLP encoded
+    /// signed transaction.
+    pub fn sign<S>(&self, signer: &S) -> Result<Vec<u8>>
+    where
+        S: Signer + ?Sized,
+    {
+        signer.sign_transaction(self)
+    }
+
+    /// Signs the transaction with an asynchronous signer and returns the RLP
+    /// encoded signed transaction.
+    pub async fn sign_async<S>(&self, signer: &S) -> Result<Vec<u8>>
+    where
+        S: AsyncSigner,
+    {
+        signer.sign_transaction(self).await
+    }
 
-        Ok(encoded)
+    /// Returns the address of the contract deployed by a contract creation
+    /// transaction sent by `sender`, or `None` if the transaction calls an
+    /// existing account.
+    pub fn contract_address(&self, sender: Address) -> Option<Address> {
+        match self.to {
+            Some(_) => None,
+            None => Some(Address::create(sender, self.nonce)),
+        }
     }
 
     /// Returns the RLP encoded transaction without signature.
@@ -107,9 +133,9 @@ impl Transaction {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::address;
     use crate::transaction::accesslist::StorageSlot;
     use crate::wallet::Wallet;
-    use ethaddr::address;
     use ethnum::AsU256 as _;
     use hex_literal::hex;
     use serde_json::{json, Value};
@@ -188,6 +214,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn contract_creation_address() {
+        let sender = Wallet::from_secret(DETERMINISTIC_PRIVATE_KEY)
+            .unwrap()
+            .address();
+        let mut tx = Transaction::default();
+        assert_eq!(
+            tx.contract_address(sender),
+            Some(address!("0xe78A0F7E598Cc8b0Bb87894B0F60dD2a88d6a8Ab")),
+        );
+        tx.to = Some(sender);
+        assert_eq!(tx.contract_address(sender), None);
+    }
+
     #[test]
     fn encode() {
         assert_eq!(
@@ -1,8 +1,8 @@
 //! Module with EIP-2930 access list type definition with RLP encoding and JSON
 //! serialization implementation.
 
+use crate::address::Address;
 use crate::{transaction::rlp, utils::serialization};
-use ethaddr::Address;
 use serde::Deserialize;
 
 /// An Ethereum virtual machine storage slot.
@@ -37,7 +37,7 @@ impl AccessList {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ethaddr::address;
+    use crate::address;
     use hex_literal::hex;
     use serde_json::json;
 
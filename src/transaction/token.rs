@@ -0,0 +1,516 @@
+//! Module for building and describing ERC-20, ERC-721 and ERC-1155 token
+//! transfer and approval transactions.
+//!
+//! ERC-721 `approve` and `transferFrom` share their selectors with ERC-20, so
+//! descriptions of these calls name the value an amount or token ID. Amounts
+//! are in the token's base units, as the decimals are not known offline.
+
+use crate::address::Address;
+use crate::{
+    abi::{Function, Value},
+    transaction::Transaction,
+};
+use anyhow::{ensure, Context as _, Result};
+use ethnum::U256;
+use std::fmt::{self, Display, Formatter};
+
+/// A call to a token contract.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TokenCall {
+    /// ERC-20 `transfer(address,uint256)`.
+    Erc20Transfer { to: Address, amount: U256 },
+    /// ERC-20 `approve(address,uint256)`. ERC-721 tokens share this selector,
+    /// with the token ID as amount.
+    Erc20Approve { spender: Address, amount: U256 },
+    /// ERC-20 `transferFrom(address,address,uint256)`. ERC-721 tokens share
+    /// this selector, with the token ID as amount.
+    Erc20TransferFrom {
+        from: Address,
+        to: Address,
+        amount: U256,
+    },
+    /// ERC-721 `safeTransferFrom(address,address,uint256[,bytes])`, without
+    /// the data argument when `data` is empty.
+    Erc721SafeTransferFrom {
+        from: Address,
+        to: Address,
+        token_id: U256,
+        data: Vec<u8>,
+    },
+    /// ERC-1155 `safeTransferFrom(address,address,uint256,uint256,bytes)`.
+    Erc1155SafeTransferFrom {
+        from: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+        data: Vec<u8>,
+    },
+    /// ERC-721 and ERC-1155 `setApprovalForAll(address,bool)`, which lets the
+    /// operator transfer all of the owner's tokens of the contract.
+    SetApprovalForAll { operator: Address, approved: bool },
+}
+
+const SIGNATURES: [&str; 7] = [
+    "transfer(address to, uint256 amount) returns (bool)",
+    "approve(address spender, uint256 amount) returns (bool)",
+    "transferFrom(address from, address to, uint256 amount) returns (bool)",
+    "safeTransferFrom(address from, address to, uint256 tokenId)",
+    "safeTransferFrom(address from, address to, uint256 tokenId, bytes data)",
+    "safeTransferFrom(address from, address to, uint256 id, uint256 amount, bytes data)",
+    "setApprovalForAll(address operator, bool approved)",
+];
+
+impl TokenCall {
+    /// Returns the ABI of the called token function.
+    pub fn function(&self) -> Function {
+        let signature = match self {
+            Self::Erc20Transfer { .. } => SIGNATURES[0],
+            Self::Erc20Approve { .. } => SIGNATURES[1],
+            Self::Erc20TransferFrom { .. } => SIGNATURES[2],
+            Self::Erc721SafeTransferFrom { data, .. } if data.is_empty() => SIGNATURES[3],
+            Self::Erc721SafeTransferFrom { .. } => SIGNATURES[4],
+            Self::Erc1155SafeTransferFrom { .. } => SIGNATURES[5],
+            Self::SetApprovalForAll { .. } => SIGNATURES[6],
+        };
+        signature.parse().unwrap()
+    }
+
+    /// Returns the ABI encoded calldata for the call.
+    pub fn calldata(&self) -> Vec<u8> {
+        let args = match self {
+            Self::Erc20Transfer { to, amount } => vec![(*to).into(), (*amount).into()],
+            Self::Erc20Approve { spender, amount } => vec![(*spender).into(), (*amount).into()],
+            Self::Erc20TransferFrom { from, to, amount } => {
+                vec![(*from).into(), (*to).into(), (*amount).into()]
+            }
+            Self::Erc721SafeTransferFrom {
+                from,
+                to,
+                token_id,
+                data,
+            } => {
+                let mut args = vec![(*from).into(), (*to).into(), (*token_id).into()];
+                if !data.is_empty() {
+                    args.push(Value::Bytes(data.clone()));
+                }
+                args
+            }
+            Self::Erc1155SafeTransferFrom {
+                from,
+                to,
+                id,
+                amount,
+                data,
+            } => vec![
+                (*from).into(),
+                (*to).into(),
+                (*id).into(),
+                (*amount).into(),
+                Value::Bytes(data.clone()),
+            ],
+            Self::SetApprovalForAll { operator, approved } => {
+                vec![(*operator).into(), Value::Bool(*approved)]
+            }
+        };
+        self.function()
+            .encode_input(&args)
+            .expect("token call arguments match their ABI")
+    }
+
+    /// Decodes token calldata. Calldata of any other function is rejected.
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let selector = data.first_chunk::<4>().context("calldata too short")?;
+        let function = SIGNATURES
+            .iter()
+            .map(|signature| signature.parse::<Function>().unwrap())
+            .find(|function| function.selector() == *selector)
+            .with_context(|| format!("unknown token function 0x{}", hex::encode(selector)))?;
+        let args = function.decode_input(data)?;
+
+        let address = |i: usize| args[i].as_address().unwrap();
+        let uint = |i: usize| args[i].as_uint().unwrap();
+        let bytes = |i: usize| args[i].as_bytes().unwrap().to_vec();
+        Ok(match (function.name.as_str(), args.len()) {
+            ("transfer", _) => Self::Erc20Transfer {
+                to: address(0),
+                amount: uint(1),
+            },
+            ("approve", _) => Self::Erc20Approve {
+                spender: address(0),
+                amount: uint(1),
+            },
+            ("transferFrom", _) => Self::Erc20TransferFrom {
+                from: address(0),
+                to: address(1),
+                amount: uint(2),
+            },
+            ("setApprovalForAll", _) => Self::SetApprovalForAll {
+                operator: address(0),
+                approved: args[1].as_bool().unwrap(),
+            },
+            (_, 3) => Self::Erc721SafeTransferFrom {
+                from: address(0),
+                to: address(1),
+                token_id: uint(2),
+                data: Vec::new(),
+            },
+            (_, 4) => Self::Erc721SafeTransferFrom {
+                from: address(0),
+                to: address(1),
+                token_id: uint(2),
+                data: bytes(3),
+            },
+            _ => Self::Erc1155SafeTransferFrom {
+                from: address(0),
+                to: address(1),
+                id: uint(2),
+                amount: uint(3),
+                data: bytes(4),
+            },
+        })
+    }
+
+    /// Checks the call for common mistakes when sent to the specified token
+    /// contract: zero addresses, tokens sent to the token contract itself and
+    /// transfers of zero tokens.
+    pub fn validate(&self, token: Address) -> Result<()> {
+        ensure!(
+            token != Address::default(),
+            "token contract is the zero address"
+        );
+        let (recipient, amount) = match self {
+            Self::Erc20Transfer { to, amount } => (to, Some(amount)),
+            Self::Erc20Approve { spender, .. } => (spender, None),
+            Self::Erc20TransferFrom { to, amount, .. } => (to, Some(amount)),
+            Self::Erc721SafeTransferFrom { to, .. } => (to, None),
+            Self::Erc1155SafeTransferFrom { to, amount, .. } => (to, Some(amount)),
+            Self::SetApprovalForAll { operator, .. } => (operator, None),
+        };
+        ensure!(
+            *recipient != Address::default(),
+            "recipient is the zero address"
+        );
+        ensure!(*recipient != token, "recipient is the token contract");
+        ensure!(amount != Some(&U256::ZERO), "token amount is zero");
+        Ok(())
+    }
+
+    /// Builds a transaction calling the specified token contract. The chain
+    /// ID, nonce, fees and gas limit are taken from `base`, whose recipient
+    /// and calldata are replaced. Token calls are not payable, so `base` must
+    /// not send Ether.
+    pub fn transaction(&self, token: Address, base: Transaction) -> Result<Transaction> {
+        self.validate(token)?;
+        ensure!(base.value == U256::ZERO, "token calls do not accept Ether");
+        Ok(Transaction {
+            to: Some(token),
+            data: self.calldata(),
+            ..base
+        })
+    }
+}
+
+impl Display for TokenCall {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Erc20Transfer { to, amount } => {
+                write!(f, "transfer {amount} in base units of the token to {to}")
+            }
+            Self::Erc20Approve { spender, amount } if *amount == U256::ZERO => write!(
+                f,
+                "approve {spender} for amount or token ID 0, revoking an ERC-20 allowance"
+            ),
+            Self::Erc20Approve { spender, amount } if *amount == U256::MAX => write!(
+                f,
+                "approve {spender} for an unlimited amount or token ID {amount}"
+            ),
+            Self::Erc20Approve { spender, amount } => {
+                write!(f, "approve {spender} for amount or token ID {amount}")
+            }
+            Self::Erc20TransferFrom { from, to, amount } => {
+                write!(
+                    f,
+                    "transfer amount or token ID {amount} from {from} to {to}"
+                )
+            }
+            Self::Erc721SafeTransferFrom {
+                from,
+                to,
+                token_id,
+                data,
+            } => {
+                write!(f, "transfer NFT #{token_id} from {from} to {to}")?;
+                if !data.is_empty() {
+                    write!(f, " with {} bytes of data", data.len())?;
+                }
+                Ok(())
+            }
+            Self::Erc1155SafeTransferFrom {
+                from,
+                to,
+                id,
+                amount,
+                data,
+            } => {
+                write!(f, "transfer {amount} of token #{id} from {from} to {to}")?;
+                if !data.is_empty() {
+                    write!(f, " with {} bytes of data", data.len())?;
+                }
+                Ok(())
+            }
+            Self::SetApprovalForAll {
+                operator,
+                approved: true,
+            } => write!(
+                f,
+                "allow {operator} to transfer all of your tokens of the contract"
+            ),
+            Self::SetApprovalForAll {
+                operator,
+                approved: false,
+            } => write!(
+                f,
+                "revoke the approval of {operator} for all of your tokens of the contract"
+            ),
+        }
+    }
+}
+
+/// Returns a human-readable description of what a transaction does, to be
+/// shown before signing it.
+pub fn describe(tx: &Transaction) -> String {
+    let mut description = match tx.to {
+        None => format!("deploy a contract with {} bytes of code", tx.data.len()),
+        Some(to) if tx.data.is_empty() => return format!("send {} wei to {to}", tx.value),
+        Some(to) => match TokenCall::decode(&tx.data) {
+            Ok(call) => format!("{call} on token contract {to}"),
+            Err(_) => match tx.data.first_chunk::<4>() {
+                Some(selector) => format!(
+                    "call function 0x{} on {to} with {} bytes of arguments",
+                    hex::encode(selector),
+                    tx.data.len() - 4,
+                ),
+                None => format!("call {to} with {} bytes of data", tx.data.len()),
+            },
+        },
+    };
+    if tx.value != U256::ZERO {
+        description.push_str(&format!(", sending {} wei", tx.value));
+    }
+    description
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    const TOKEN: Address = Address(hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"));
+    const ALICE: Address = Address(hex!("90f8bf6a479f320ead074411a4b0e7944ea8c9c1"));
+    const BOB: Address = Address(hex!("ffcf8fdee72ac11b5c542428b35eef5769c409f0"));
+
+    #[test]
+    fn build_transactions() {
+        let base = Transaction {
+            chain_id: U256::ONE,
+            nonce: U256::new(7),
+            gas: U256::new(60_000),
+            ..Default::default()
+        };
+        let tx = TokenCall::Erc20Transfer {
+            to: BOB,
+            amount: U256::new(1_000_000),
+        }
+        .transaction(TOKEN, base.clone())
+        .unwrap();
+        assert_eq!(tx.to, Some(TOKEN));
+        assert_eq!(tx.nonce, U256::new(7));
+        assert_eq!(
+            tx.data,
+            hex!(
+                "a9059cbb
+                 000000000000000000000000ffcf8fdee72ac11b5c542428b35eef5769c409f0
+                 00000000000000000000000000000000000000000000000000000000000f4240"
+            ),
+        );
+
+        for (call, token) in [
+            (
+                TokenCall::Erc20Transfer {
+                    to: Address::default(),
+                    amount: U256::ONE,
+                },
+                TOKEN,
+            ),
+            (
+                TokenCall::Erc20Transfer {
+                    to: TOKEN,
+                    amount: U256::ONE,
+                },
+                TOKEN,
+            ),
+            (
+                TokenCall::Erc20TransferFrom {
+                    from: ALICE,
+                    to: BOB,
+                    amount: U256::ZERO,
+                },
+                TOKEN,
+            ),
+            (
+                TokenCall::Erc20Approve {
+                    spender: BOB,
+                    amount: U256::ZERO,
+                },
+                Address::default(),
+            ),
+        ] {
+            assert!(call.transaction(token, base.clone()).is_err(), "{call}");
+        }
+
+        let payable = Transaction {
+            value: U256::ONE,
+            ..base
+        };
+        assert!(TokenCall::Erc20Approve {
+            spender: BOB,
+            amount: U256::ZERO
+        }
+        .transaction(TOKEN, payable)
+        .is_err());
+    }
+
+    #[test]
+    fn selectors_and_roundtrip() {
+        let calls = [
+            TokenCall::Erc20Transfer {
+                to: BOB,
+                amount: U256::ONE,
+            },
+            TokenCall::Erc20Approve {
+                spender: BOB,
+                amount: U256::MAX,
+            },
+            TokenCall::Erc20TransferFrom {
+                from: ALICE,
+                to: BOB,
+                amount: U256::ONE,
+            },
+            TokenCall::Erc721SafeTransferFrom {
+                from: ALICE,
+                to: BOB,
+                token_id: U256::new(42),
+                data: Vec::new(),
+            },
+            TokenCall::Erc721SafeTransferFrom {
+                from: ALICE,
+                to: BOB,
+                token_id: U256::new(42),
+                data: vec![1, 2, 3],
+            },
+            TokenCall::Erc1155SafeTransferFrom {
+                from: ALICE,
+                to: BOB,
+                id: U256::new(42),
+                amount: U256::new(3),
+                data: Vec::new(),
+            },
+            TokenCall::SetApprovalForAll {
+                operator: BOB,
+                approved: true,
+            },
+        ];
+        let selectors = [
+            "a9059cbb", "095ea7b3", "23b872dd", "42842e0e", "b88d4fde", "f242432a", "a22cb465",
+        ];
+        for (call, selector) in calls.iter().zip(selectors) {
+            let data = call.calldata();
+            assert_eq!(hex::encode(&data[..4]), selector);
+            assert_eq!(TokenCall::decode(&data).unwrap(), *call);
+        }
+        assert!(TokenCall::decode(&hex!("70a08231")).is_err());
+    }
+
+    #[test]
+    fn describe_transactions() {
+        let tx = |to, value, data: Vec<u8>| Transaction {
+            to,
+            value: U256::new(value),
+            data,
+            ..Default::default()
+        };
+        let approve = TokenCall::Erc20Approve {
+            spender: BOB,
+            amount: U256::MAX,
+        };
+        assert_eq!(
+            describe(&tx(Some(TOKEN), 0, approve.calldata())),
+            format!(
+                "approve {BOB} for an unlimited amount or token ID {} on token contract {TOKEN}",
+                U256::MAX
+            ),
+        );
+        for (call, description) in [
+            (
+                TokenCall::Erc20Transfer {
+                    to: BOB,
+                    amount: U256::new(1_000_000),
+                },
+                format!("transfer 1000000 in base units of the token to {BOB}"),
+            ),
+            (
+                TokenCall::Erc20Approve {
+                    spender: BOB,
+                    amount: U256::new(42),
+                },
+                format!("approve {BOB} for amount or token ID 42"),
+            ),
+            (
+                TokenCall::Erc20Approve {
+                    spender: BOB,
+                    amount: U256::ZERO,
+                },
+                format!("approve {BOB} for amount or token ID 0, revoking an ERC-20 allowance"),
+            ),
+            (
+                TokenCall::Erc20TransferFrom {
+                    from: ALICE,
+                    to: BOB,
+                    amount: U256::new(42),
+                },
+                format!("transfer amount or token ID 42 from {ALICE} to {BOB}"),
+            ),
+            (
+                TokenCall::SetApprovalForAll {
+                    operator: BOB,
+                    approved: true,
+                },
+                format!("allow {BOB} to transfer all of your tokens of the contract"),
+            ),
+            (
+                TokenCall::SetApprovalForAll {
+                    operator: BOB,
+                    approved: false,
+                },
+                format!("revoke the approval of {BOB} for all of your tokens of the contract"),
+            ),
+        ] {
+            assert_eq!(
+                describe(&tx(Some(TOKEN), 0, call.calldata())),
+                format!("{description} on token contract {TOKEN}"),
+            );
+        }
+        assert_eq!(
+            describe(&tx(Some(BOB), 5, vec![])),
+            format!("send 5 wei to {BOB}")
+        );
+        assert_eq!(
+            describe(&tx(Some(BOB), 5, hex!("d0e30db0").to_vec())),
+            format!("call function 0xd0e30db0 on {BOB} with 0 bytes of arguments, sending 5 wei"),
+        );
+        assert_eq!(
+            describe(&tx(None, 0, vec![0; 10])),
+            "deploy a contract with 10 bytes of code"
+        );
+    }
+}
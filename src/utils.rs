@@ -1,7 +1,6 @@
+use crate::address::Address;
 use anyhow::Result;
-use ethereum_types::H160 as Address;
 use hmac::Hmac;
-use k256::elliptic_curve::sec1::ToEncodedPoint;
 use k256::SecretKey;
 use pbkdf2::pbkdf2;
 use rand::rngs::StdRng;
@@ -40,14 +39,7 @@ where
     S: AsRef<[u8]>,
 {
     let secret_key = SecretKey::from_slice(pk.as_ref())?;
-    let public_key: [u8; 65] = secret_key
-        .public_key()
-        .to_encoded_point(false)
-        .as_bytes()
-        .try_into()?;
-    debug_assert_eq!(public_key[0], 0x04);
-    let hash = hash::keccak256(&public_key[1..]);
-    Ok(Address::from_slice(&hash[12..]))
+    Ok(Address::from_public_key(&secret_key.public_key()))
 }
 
 // Derives a key from the given password and salt using PBKDF2.
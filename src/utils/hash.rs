@@ -1,3 +1,4 @@
+use ripemd::Ripemd160;
 use sha2::{Digest as _, Sha256};
 use sha3::Keccak256;
 
@@ -8,9 +9,27 @@ pub fn keccak256(data: impl AsRef<[u8]>) -> [u8; 32] {
     hasher.finalize().into()
 }
 
+/// Returns the EIP-191 hash of a personal message, the Keccak-256 hash of
+/// the message prefixed by `"\x19Ethereum Signed Message:\n"` and its length.
+pub fn eip191_hash(message: impl AsRef<[u8]>) -> [u8; 32] {
+    let message = message.as_ref();
+    let mut hasher = Keccak256::new();
+    hasher.update(format!("\x19Ethereum Signed Message:\n{}", message.len()));
+    hasher.update(message);
+    hasher.finalize().into()
+}
+
 /// Returns the SHA256 hash of the specified input.
 pub fn sha256(data: impl AsRef<[u8]>) -> [u8; 32] {
     let mut hasher = Sha256::new();
     hasher.update(data.as_ref());
     hasher.finalize().into()
 }
+
+/// Returns the RIPEMD-160 hash of the SHA256 hash of the specified input, as
+/// used for BIP-0032 key fingerprints.
+pub fn hash160(data: impl AsRef<[u8]>) -> [u8; 20] {
+    let mut hasher = Ripemd160::new();
+    hasher.update(sha256(data));
+    hasher.finalize().into()
+}
@@ -0,0 +1,449 @@
+//! Module implementing a multi-threaded vanity address search, for random
+//! wallet keys as well as for `CREATE2` salts.
+//!
+//! Every attempt is independent, so the number of attempts needed follows a
+//! geometric distribution: a pattern with difficulty `d` is found within
+//! `d * ln(2)` attempts half of the time.
+
+use crate::{address::Address, keystore, utils, wallet::Wallet};
+use anyhow::{ensure, Result};
+use rand::{rngs::StdRng, RngCore as _};
+use std::{
+    num::NonZeroUsize,
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// An address pattern, a hex prefix and suffix.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pattern {
+    prefix: String,
+    suffix: String,
+    case_sensitive: bool,
+}
+
+impl Pattern {
+    /// Creates a pattern for addresses starting with `prefix` and ending with
+    /// `suffix`, without `0x`. When case sensitive, the letters must match the
+    /// EIP-55 checksummed address.
+    pub fn new(prefix: &str, suffix: &str, case_sensitive: bool) -> Result<Self> {
+        let prefix = prefix.strip_prefix("0x").unwrap_or(prefix);
+        for part in [prefix, suffix] {
+            ensure!(
+                part.bytes().all(|b| b.is_ascii_hexdigit()),
+                "vanity pattern '{part}' is not hexadecimal"
+            );
+        }
+        ensure!(
+            prefix.len() + suffix.len() <= 40,
+            "vanity pattern longer than an address"
+        );
+
+        let normalize = |part: &str| match case_sensitive {
+            true => part.to_owned(),
+            false => part.to_ascii_lowercase(),
+        };
+        Ok(Self {
+            prefix: normalize(prefix),
+            suffix: normalize(suffix),
+            case_sensitive,
+        })
+    }
+
+    /// Returns whether an address matches the pattern.
+    pub fn matches(&self, address: &Address) -> bool {
+        let lowercase = hex::encode(address.0);
+        if !lowercase.starts_with(&self.prefix.to_ascii_lowercase())
+            || !lowercase.ends_with(&self.suffix.to_ascii_lowercase())
+        {
+            return false;
+        }
+        if !self.case_sensitive {
+            return true;
+        }
+
+        // Only compute the checksum for the rare case-insensitive matches.
+        let checksum = address.checksum();
+        checksum[2..].starts_with(&self.prefix) && checksum.ends_with(&self.suffix)
+    }
+
+    /// Returns the expected number of attempts to find a matching address.
+    /// Every hex digit has 16 possible values, and a case sensitive letter
+    /// matches its checksum case half of the time.
+    pub fn difficulty(&self) -> f64 {
+        self.prefix
+            .chars()
+            .chain(self.suffix.chars())
+            .map(|c| match c {
+                'a'..='f' | 'A'..='F' if self.case_sensitive => 32.0,
+                _ => 16.0,
+            })
+            .product()
+    }
+}
+
+/// The progress of a running search.
+#[derive(Clone, Copy, Debug)]
+pub struct Progress {
+    /// The number of addresses checked so far, over all threads.
+    pub attempts: u64,
+    pub elapsed: Duration,
+    /// The expected number of attempts of the pattern.
+    pub difficulty: f64,
+}
+
+impl Progress {
+    /// Returns the number of attempts per second.
+    pub fn rate(&self) -> f64 {
+        self.attempts as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+
+    /// Returns the probability that a match would have been found with this
+    /// many attempts.
+    pub fn probability(&self) -> f64 {
+        // Computed as `1 - (1 - 1/d)^n` without losing precision for large
+        // difficulties.
+        -(self.attempts as f64 * (-1.0 / self.difficulty).ln_1p()).exp_m1()
+    }
+
+    /// Returns the estimated time until the search has a 50% probability of
+    /// having found a match, at the current rate. Returns `None` if nothing
+    /// was checked yet or the time is too long to represent.
+    pub fn eta(&self) -> Option<Duration> {
+        let rate = self.rate();
+        if rate == 0.0 {
+            return None;
+        }
+        let median = self.difficulty * std::f64::consts::LN_2;
+        let remaining = (median - self.attempts as f64).max(0.0) / rate;
+        Duration::try_from_secs_f64(remaining).ok()
+    }
+}
+
+/// A handle for cancelling a search from another thread. A cancellation
+/// stops the running searches, or the next search if none is running, and
+/// is used up by the searches it stops: later searches start uncancelled.
+#[derive(Clone, Debug, Default)]
+pub struct CancelHandle(Arc<Mutex<Arc<AtomicBool>>>);
+
+impl CancelHandle {
+    pub fn cancel(&self) {
+        self.token().store(true, Ordering::Relaxed);
+    }
+
+    /// Returns whether a cancellation is pending.
+    pub fn is_cancelled(&self) -> bool {
+        self.token().load(Ordering::Relaxed)
+    }
+
+    // Returns the cancellation token of the running and next searches.
+    fn token(&self) -> Arc<AtomicBool> {
+        self.0.lock().unwrap().clone()
+    }
+
+    // Replaces the token of a finished search if it was cancelled, so the
+    // cancellation does not carry over to later searches.
+    fn finish(&self, token: &Arc<AtomicBool>) {
+        let mut current = self.0.lock().unwrap();
+        if Arc::ptr_eq(&current, token) && token.load(Ordering::Relaxed) {
+            *current = Arc::default();
+        }
+    }
+}
+
+/// A multi-threaded vanity search.
+#[derive(Clone, Debug)]
+pub struct VanitySearch {
+    pattern: Pattern,
+    threads: usize,
+    progress_interval: Duration,
+    cancel: CancelHandle,
+}
+
+impl VanitySearch {
+    /// Creates a search for the pattern using all available cores.
+    pub fn new(pattern: Pattern) -> Self {
+        Self {
+            pattern,
+            threads: thread::available_parallelism().map_or(1, NonZeroUsize::get),
+            progress_interval: Duration::from_secs(1),
+            cancel: CancelHandle::default(),
+        }
+    }
+
+    /// Sets the number of worker threads.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Sets how often progress is reported.
+    pub fn progress_interval(mut self, interval: Duration) -> Self {
+        self.progress_interval = interval;
+        self
+    }
+
+    /// Returns a handle to cancel the running search. The handle is shared
+    /// with clones of the search.
+    pub fn cancel_handle(&self) -> CancelHandle {
+        self.cancel.clone()
+    }
+
+    /// Searches for a random wallet with a matching address. Returns `None`
+    /// if the search was cancelled.
+    pub fn wallet(&self, on_progress: impl FnMut(&Progress)) -> Option<Wallet> {
+        self.search(
+            |rng| {
+                let mut secret = [0; 32];
+                rng.fill_bytes(&mut secret);
+                let wallet = Wallet::from_secret(secret).ok()?;
+                self.pattern.matches(&wallet.address()).then_some(wallet)
+            },
+            on_progress,
+        )
+    }
+
+    /// Searches for a `CREATE2` salt for which `factory` deploys the init code
+    /// to a matching address. Returns `None` if the search was cancelled.
+    pub fn create2_salt(
+        &self,
+        factory: Address,
+        init_code_hash: [u8; 32],
+        on_progress: impl FnMut(&Progress),
+    ) -> Option<[u8; 32]> {
+        self.search(
+            |rng| {
+                let mut salt = [0; 32];
+                rng.fill_bytes(&mut salt);
+                let address = Address::create2(factory, salt, init_code_hash);
+                self.pattern.matches(&address).then_some(salt)
+            },
+            on_progress,
+        )
+    }
+
+    /// Searches for a matching wallet and writes it as an encrypted JSON
+    /// keystore into `dir`. Returns the address and keystore ID, or `None` if
+    /// the search was cancelled.
+    pub fn keystore<P, S>(
+        &self,
+        dir: P,
+        password: S,
+        on_progress: impl FnMut(&Progress),
+    ) -> Result<Option<(Address, String)>>
+    where
+        P: AsRef<Path>,
+        S: AsRef<[u8]>,
+    {
+        let Some(wallet) = self.wallet(on_progress) else {
+            return Ok(None);
+        };
+        let id = keystore::encrypt_key(dir, wallet.secret(), password)?;
+        Ok(Some((wallet.address(), id)))
+    }
+
+    fn search<T, F>(&self, attempt: F, mut on_progress: impl FnMut(&Progress)) -> Option<T>
+    where
+        T: Send,
+        F: Fn(&mut StdRng) -> Option<T> + Sync,
+    {
+        let token = self.cancel.token();
+        let found = self.search_with(&token, attempt, &mut on_progress);
+        self.cancel.finish(&token);
+        found
+    }
+
+    fn search_with<T, F>(
+        &self,
+        token: &AtomicBool,
+        attempt: F,
+        on_progress: &mut impl FnMut(&Progress),
+    ) -> Option<T>
+    where
+        T: Send,
+        F: Fn(&mut StdRng) -> Option<T> + Sync,
+    {
+        if token.load(Ordering::Relaxed) {
+            return None;
+        }
+        let start = Instant::now();
+        let attempts = AtomicU64::new(0);
+        let done = AtomicBool::new(false);
+        let (sender, receiver) = mpsc::channel();
+
+        thread::scope(|scope| {
+            for _ in 0..self.threads {
+                let sender = sender.clone();
+                let (attempt, attempts, done) = (&attempt, &attempts, &done);
+                scope.spawn(move || {
+                    let mut rng = utils::get_rng();
+                    while !done.load(Ordering::Relaxed) && !token.load(Ordering::Relaxed) {
+                        attempts.fetch_add(1, Ordering::Relaxed);
+                        if let Some(found) = attempt(&mut rng) {
+                            done.store(true, Ordering::Relaxed);
+                            let _ = sender.send(found);
+                        }
+                    }
+                });
+            }
+            drop(sender);
+
+            // Poll often enough to notice cancellation quickly, while only
+            // reporting progress at the configured interval.
+            let poll = self.progress_interval.min(Duration::from_millis(50));
+            let mut last_report = start;
+            let found = loop {
+                match receiver.recv_timeout(poll) {
+                    Ok(found) => break Some(found),
+                    Err(mpsc::RecvTimeoutError::Disconnected) => break None,
+                    Err(mpsc::RecvTimeoutError::Timeout) => {}
+                }
+                if token.load(Ordering::Relaxed) {
+                    break None;
+                }
+                if last_report.elapsed() >= self.progress_interval {
+                    last_report = Instant::now();
+                    on_progress(&Progress {
+                        attempts: attempts.load(Ordering::Relaxed),
+                        elapsed: start.elapsed(),
+                        difficulty: self.pattern.difficulty(),
+                    });
+                }
+            };
+            done.store(true, Ordering::Relaxed);
+            found
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::hash::keccak256;
+
+    #[test]
+    fn patterns() {
+        let address = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+            .parse::<Address>()
+            .unwrap();
+        assert!(Pattern::new("5aae", "", false).unwrap().matches(&address));
+        assert!(Pattern::new("0x5AAE", "beaed", false)
+            .unwrap()
+            .matches(&address));
+        assert!(Pattern::new("5aAe", "BeAed", true)
+            .unwrap()
+            .matches(&address));
+        assert!(!Pattern::new("5aae", "", true).unwrap().matches(&address));
+        assert!(!Pattern::new("", "beaee", false).unwrap().matches(&address));
+
+        assert_eq!(
+            Pattern::new("dead", "", false).unwrap().difficulty(),
+            65536.0
+        );
+        assert_eq!(Pattern::new("0", "aB", true).unwrap().difficulty(), 16384.0);
+        assert!(Pattern::new("xyz", "", false).is_err());
+        assert!(Pattern::new(&"0".repeat(30), &"0".repeat(11), false).is_err());
+    }
+
+    #[test]
+    fn search_wallet_and_salt() {
+        let search = VanitySearch::new(Pattern::new("a", "", false).unwrap()).threads(2);
+        let wallet = search.wallet(|_| {}).unwrap();
+        assert!(hex::encode(wallet.address().0).starts_with('a'));
+
+        let dir = tempfile::tempdir().unwrap();
+        let (address, id) = search.keystore(&dir, "password", |_| {}).unwrap().unwrap();
+        let wallet = Wallet::decrypt_keystore(dir.path().join(id), "password").unwrap();
+        assert_eq!(wallet.address(), address);
+        assert!(hex::encode(address.0).starts_with('a'));
+
+        let factory = "0x4e59b44847b379578588920cA78FbF26c0B4956C"
+            .parse::<Address>()
+            .unwrap();
+        let search = VanitySearch::new(Pattern::new("", "0", false).unwrap());
+        let salt = search
+            .create2_salt(factory, keccak256([0x00]), |_| {})
+            .unwrap();
+        assert!(Address::create2(factory, salt, keccak256([0x00]))
+            .to_string()
+            .ends_with('0'));
+    }
+
+    #[test]
+    fn cancellation_and_progress() {
+        // A full address pattern will never be found in time.
+        let search = VanitySearch::new(Pattern::new(&"0".repeat(40), "", false).unwrap())
+            .threads(2)
+            .progress_interval(Duration::from_millis(10));
+        let cancel = search.cancel_handle();
+        let mut reports = Vec::new();
+        let found = search.wallet(|progress| {
+            reports.push(*progress);
+            if reports.len() == 3 {
+                cancel.cancel();
+            }
+        });
+        assert!(found.is_none());
+        assert!(!cancel.is_cancelled());
+        assert!(reports.len() >= 3);
+        assert!(reports[2].attempts >= reports[0].attempts);
+        assert!(reports[0].probability() < 1e-9);
+        assert!(reports[2].eta().is_none());
+
+        let progress = Progress {
+            attempts: 1000,
+            elapsed: Duration::from_secs(1),
+            difficulty: 65536.0,
+        };
+        assert_eq!(progress.rate(), 1000.0);
+        assert!((progress.probability() - 0.0151).abs() < 1e-4);
+        assert_eq!(progress.eta().unwrap().as_secs(), 44);
+    }
+
+    #[test]
+    fn cancellation_before_start() {
+        let search = VanitySearch::new(Pattern::new("a", "", false).unwrap()).threads(2);
+        let cancel = search.cancel_handle();
+
+        // A cancellation before the search starts stops it, and is used up
+        // by it.
+        cancel.cancel();
+        assert!(cancel.is_cancelled());
+        assert!(search.wallet(|_| {}).is_none());
+        assert!(!cancel.is_cancelled());
+        assert!(search.wallet(|_| {}).is_some());
+
+        // Clones share the cancellation.
+        cancel.cancel();
+        assert!(search.clone().wallet(|_| {}).is_none());
+        assert!(search.clone().wallet(|_| {}).is_some());
+
+        // Starting a search on a clone does not undo the cancellation of a
+        // running search.
+        let slow = VanitySearch::new(Pattern::new(&"0".repeat(40), "", false).unwrap())
+            .threads(1)
+            .progress_interval(Duration::from_millis(10));
+        let cancel = slow.cancel_handle();
+        let (started, running) = mpsc::channel();
+        thread::scope(|scope| {
+            let running_search = scope.spawn(|| {
+                slow.wallet(|_| {
+                    let _ = started.send(());
+                })
+            });
+            running.recv().unwrap();
+            cancel.cancel();
+            let fast = VanitySearch {
+                pattern: Pattern::new("a", "", false).unwrap(),
+                ..slow.clone()
+            };
+            fast.wallet(|_| {});
+            assert!(running_search.join().unwrap().is_none());
+        });
+    }
+}
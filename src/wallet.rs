@@ -1,16 +1,16 @@
 mod signature;
 
-use crate::utils;
+use crate::{address::Address, utils};
 use alloy::primitives::Address as AnvilAddress;
-use anyhow::Result;
-use ethaddr::Address;
+use anyhow::{Context as _, Result};
+use digest::{consts::U32, core_api::BlockSizeUser, Digest, FixedOutputReset};
 use k256::{
     ecdsa::{hazmat::SignPrimitive, SigningKey},
     elliptic_curve::sec1::ToEncodedPoint as _,
     PublicKey, SecretKey,
 };
 use sha2::Sha256;
-pub use signature::Signature;
+pub use signature::{Signature, VFormat};
 use std::{
     fmt::{self, Debug, Formatter},
     path::Path,
@@ -47,22 +47,11 @@ impl Wallet {
 
     /// Returns the public address for the private key.
     pub fn address(&self) -> Address {
-        let encoded: [u8; 65] = self.public_key_encoded_uncompressed();
-
-        // Ethereum address is the last 20 bytes of the keccak hash of
-        // the concatenated elliptic curve coordinates of the public key. Note
-        // that an encoded uncompressed public key is serialized into 65 bytes
-        // where the first byte is a SEC1 tag that is always 0x04 (representing
-        // an uncompressed point) and the subsequent bytes are the coordinates
-        // we want. So discard the first byte for the address calculation.
-        debug_assert_eq!(encoded[0], 0x04);
-        let hash = utils::hash::keccak256(&encoded[1..]);
-
-        Address::from_slice(&hash[12..])
+        Address::from_public_key(&self.public_key())
     }
 
     pub fn anvil_address(&self) -> AnvilAddress {
-        AnvilAddress::from_slice(self.address().as_slice())
+        self.address().into()
     }
 
     /// Returns the private key's 32 byte secret.
@@ -71,11 +60,41 @@ impl Wallet {
     }
 
     /// Generate a signature for the specified message. Message is a 32-byte hash.
+    ///
+    /// Signatures are deterministic (RFC 6979 with SHA-256) and canonical:
+    /// S is always in the lower half of the curve order as EIP-2 requires,
+    /// and the recovery ID is always set.
     pub fn sign(&self, message: [u8; 32]) -> Result<Signature> {
+        self.sign_with::<Sha256>(message, &[])
+    }
+
+    /// Generates a hedged signature, mixing 32 random bytes into the RFC 6979
+    /// nonce derivation (RFC 6979 section 3.6). The nonce stays secret even
+    /// if the random source is broken, while fault attacks that rely on
+    /// signing the same message twice with the same nonce no longer work.
+    pub fn sign_hedged(&self, message: [u8; 32]) -> Result<Signature> {
+        let mut entropy = [0; 32];
+        utils::fill_random_bytes(&mut entropy)?;
+        self.sign_with::<Sha256>(message, &entropy)
+    }
+
+    /// Generates a signature, deriving the RFC 6979 nonce with the digest `D`
+    /// and mixing in the additional data `extra_entropy`. With empty
+    /// additional data the signature is deterministic. The canonical
+    /// guarantees of [`Wallet::sign`] hold for every digest.
+    pub fn sign_with<D>(&self, message: [u8; 32], extra_entropy: &[u8]) -> Result<Signature>
+    where
+        D: Digest + BlockSizeUser + FixedOutputReset<OutputSize = U32>,
+    {
         let (signature, recovery_id) = SigningKey::from(&self.0)
             .as_nonzero_scalar()
-            .try_sign_prehashed_rfc6979::<Sha256>(&message.into(), b"")?;
-        Ok(Signature(signature, recovery_id.unwrap()))
+            .try_sign_prehashed_rfc6979::<D>(&message.into(), extra_entropy)?;
+        let signature = Signature(
+            signature,
+            recovery_id.context("signing did not return a recovery ID")?,
+        );
+        debug_assert!(signature.is_low_s());
+        Ok(signature)
     }
 
     /// Sign a message and return the signature.
@@ -84,6 +103,12 @@ impl Wallet {
         self.sign(message)
     }
 
+    /// Signs a personal message as specified by EIP-191 (version `0x45`), as
+    /// done by `personal_sign` and `eth_sign`.
+    pub fn sign_personal_message(&self, message: impl AsRef<[u8]>) -> Result<Signature> {
+        self.sign(utils::hash::eip191_hash(message))
+    }
+
     /// Write the json keystore file to the specified directory.
     pub fn encrypt_keystore<P, S>(&self, keypath: P, password: S) -> Result<String>
     where
@@ -132,6 +157,7 @@ mod tests {
     fn deterministic_signature() {
         let key = Wallet::from_secret(PRIVATE_KEY).unwrap();
         let message = utils::hash::keccak256(b"\x19Ethereum Signed Message:\n12Hello World!");
+        assert_eq!(utils::hash::eip191_hash("Hello World!"), message);
         let expected_result = Signature::from_parts(
             hex!("408790f153cbfa2722fc708a57d97a43b24429724cf060df7c915d468c43bd84"),
             hex!("61c96aac95ce37d7a31087b6634f4a3ea439a9f704b5c818584fa2a32fa83859"),
@@ -139,5 +165,61 @@ mod tests {
         );
 
         assert_eq!(key.sign(message).unwrap(), expected_result);
+        assert_eq!(
+            key.sign_personal_message("Hello World!").unwrap(),
+            expected_result
+        );
+        assert_eq!(
+            key.sign_with::<Sha256>(message, &[]).unwrap(),
+            expected_result
+        );
+    }
+
+    #[test]
+    fn rfc6979_vectors() {
+        // Bitcoin secp256k1 RFC 6979 vectors with SHA-256 message digests.
+        for (secret, message, signature) in [
+            (
+                hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+                "Satoshi Nakamoto",
+                "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8\
+                 2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5",
+            ),
+            (
+                hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+                "All those moments will be lost in time, like tears in rain. Time to die...",
+                "8600dbd41e348fe5c9465ab92d23e3db8b98b873beecd930736488696438cb6b\
+                 547fe64427496db33bf66019dacbf0039c04199abb0122918601db38a72cfc21",
+            ),
+        ] {
+            let key = Wallet::from_secret(secret).unwrap();
+            let message = utils::hash::sha256(message);
+            let actual = key.sign(message).unwrap();
+            assert_eq!(format!("{:064x}{:064x}", actual.r(), actual.s()), signature);
+        }
+    }
+
+    #[test]
+    fn hedged_signatures() {
+        let key = Wallet::from_secret(PRIVATE_KEY).unwrap();
+        let message = utils::hash::keccak256(b"hello");
+
+        let deterministic = key.sign(message).unwrap();
+        let hedged = [
+            key.sign_hedged(message).unwrap(),
+            key.sign_hedged(message).unwrap(),
+            key.sign_with::<Sha256>(message, &[1; 32]).unwrap(),
+            key.sign_with::<sha3::Keccak256>(message, &[]).unwrap(),
+        ];
+        for (i, signature) in hedged.iter().enumerate() {
+            assert_ne!(*signature, deterministic);
+            assert_ne!(hedged[(i + 1) % hedged.len()], *signature);
+            assert!(signature.is_low_s());
+            assert_eq!(signature.recover(message).unwrap(), key.address());
+        }
+        assert_eq!(
+            key.sign_with::<Sha256>(message, &[1; 32]).unwrap(),
+            hedged[2]
+        );
     }
 }
@@ -1,8 +1,10 @@
 //! Module containing signature data model.
 
-use anyhow::bail;
+use crate::address::Address;
+use anyhow::{bail, ensure, Context as _, Result};
 use ethnum::{AsU256 as _, U256};
-use k256::ecdsa::{self, RecoveryId};
+use k256::ecdsa::{self, RecoveryId, VerifyingKey};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     fmt::{self, Display, Formatter},
     str::FromStr,
@@ -12,6 +14,17 @@ use std::{
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Signature(pub ecdsa::Signature, pub RecoveryId);
 
+/// The convention used to encode the y-parity as a V value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VFormat {
+    /// The raw y-parity, 0 or 1, as used by typed transactions.
+    YParity,
+    /// The legacy V value, 27 or 28.
+    Legacy,
+    /// An EIP-155 V value with chain replay protection.
+    Eip155(U256),
+}
+
 impl Signature {
     /// Returns the y-parity in its 256-bit integer representation.
     /// Return 0 for even parity, and 1 for odd parity.
@@ -37,13 +50,126 @@ impl Signature {
         }
     }
 
+    /// Returns the V value in the specified convention.
+    pub fn v_with(&self, format: VFormat) -> U256 {
+        match format {
+            VFormat::YParity => self.y_parity(),
+            VFormat::Legacy => self.v(None),
+            VFormat::Eip155(chain_id) => self.v(Some(chain_id)),
+        }
+    }
+
     /// Creates a signature from its raw parts.
     /// Panics on invalid signature parts.
     pub fn from_parts(r: [u8; 32], s: [u8; 32], y_parity: u8) -> Self {
-        Self(
-            ecdsa::Signature::from_scalars(r, s).unwrap(),
-            y_parity.try_into().unwrap(),
-        )
+        Self::try_from_parts(r, s, y_parity).unwrap()
+    }
+
+    /// Creates a signature from its raw parts.
+    pub fn try_from_parts(r: [u8; 32], s: [u8; 32], y_parity: u8) -> Result<Self> {
+        Ok(Self(
+            ecdsa::Signature::from_scalars(r, s)?,
+            RecoveryId::from_byte(y_parity).context("invalid y-parity")?,
+        ))
+    }
+
+    /// Creates a signature from R, S and a V value in any convention, and
+    /// returns it with the EIP-155 chain ID if V encodes one.
+    pub fn from_rsv(r: [u8; 32], s: [u8; 32], v: U256) -> Result<(Self, Option<U256>)> {
+        let (y_parity, chain_id) = match v.as_u64() {
+            _ if v > u64::MAX.as_u256() => (v.as_u8() & 1 ^ 1, Some((v - 35) / 2)),
+            0 | 1 => (v.as_u8(), None),
+            27 | 28 => (v.as_u8() - 27, None),
+            v @ 35.. => ((v - 35) as u8 & 1, Some(((v - 35) / 2).as_u256())),
+            _ => bail!("invalid V-value {v}"),
+        };
+        Ok((Self::try_from_parts(r, s, y_parity)?, chain_id))
+    }
+
+    /// Parses a signature from its bytes, either 64 bytes in the EIP-2098
+    /// compact form or R and S followed by a big-endian V value in any
+    /// convention. Any EIP-155 chain ID is discarded.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if let Ok(compact) = bytes.try_into() {
+            return Self::from_compact(compact);
+        }
+        ensure!(
+            (65..=96).contains(&bytes.len()),
+            "invalid signature length {}",
+            bytes.len(),
+        );
+
+        let mut v = [0; 32];
+        v[96 - bytes.len()..].copy_from_slice(&bytes[64..]);
+        let (signature, _) = Self::from_rsv(
+            bytes[0..32].try_into().unwrap(),
+            bytes[32..64].try_into().unwrap(),
+            U256::from_be_bytes(v),
+        )?;
+        Ok(signature)
+    }
+
+    /// Returns R, S and the V value in the specified convention. V uses as
+    /// few bytes as possible, but at least one.
+    pub fn to_bytes(&self, format: VFormat) -> Vec<u8> {
+        let v = self.v_with(format).to_be_bytes();
+        let start = v.iter().position(|b| *b != 0).unwrap_or(31);
+
+        let mut bytes = Vec::with_capacity(64 + v.len() - start);
+        bytes.extend_from_slice(&self.r().to_be_bytes());
+        bytes.extend_from_slice(&self.s().to_be_bytes());
+        bytes.extend_from_slice(&v[start..]);
+        bytes
+    }
+
+    /// Parses the 64 byte EIP-2098 compact form, where the y-parity is
+    /// stored in the otherwise unused top bit of S.
+    pub fn from_compact(bytes: &[u8; 64]) -> Result<Self> {
+        let mut s: [u8; 32] = bytes[32..].try_into().unwrap();
+        let y_parity = s[0] >> 7;
+        s[0] &= 0x7f;
+        Self::try_from_parts(bytes[..32].try_into().unwrap(), s, y_parity)
+    }
+
+    /// Returns the 64 byte EIP-2098 compact form. High-S signatures have no
+    /// compact form, so the signature is normalized first.
+    pub fn to_compact(&self) -> [u8; 64] {
+        let signature = self.normalize_s();
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(&signature.r().to_be_bytes());
+        bytes[32..].copy_from_slice(&signature.s().to_be_bytes());
+        bytes[32] |= signature.1.is_y_odd() as u8 * 0x80;
+        bytes
+    }
+
+    /// Returns whether S is in the lower half of the curve order, as EIP-2
+    /// requires for transaction signatures.
+    pub fn is_low_s(&self) -> bool {
+        self.0.normalize_s().is_none()
+    }
+
+    /// Returns the equivalent signature with a low S value, negating S and
+    /// flipping the y-parity if necessary.
+    pub fn normalize_s(&self) -> Self {
+        match self.0.normalize_s() {
+            Some(signature) => Self(
+                signature,
+                RecoveryId::new(!self.1.is_y_odd(), self.1.is_x_reduced()),
+            ),
+            None => *self,
+        }
+    }
+
+    /// Recovers the address of the signer of a 32-byte message hash.
+    pub fn recover(&self, message: [u8; 32]) -> Result<Address> {
+        let key = VerifyingKey::recover_from_prehash(&message, &self.0, self.1)?;
+        Ok(Address::from_public_key(&key.into()))
+    }
+
+    /// Rejects signatures with a high S value.
+    pub fn ensure_low_s(&self) -> Result<()> {
+        ensure!(self.is_low_s(), "signature S value is not canonical");
+        Ok(())
     }
 }
 
@@ -63,21 +189,27 @@ impl FromStr for Signature {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut signature = [0; 65];
-        hex::decode_to_slice(s, &mut signature)?;
-
-        let v = signature[64];
-        let y_parity = match v {
-            27 => 0,
-            28 => 1,
-            _ => bail!("invalid V-value, must be 27 or 28 but got {v}"),
-        };
+        let s = s.strip_prefix("0x").unwrap_or(s);
+        Self::from_bytes(&hex::decode(s)?)
+    }
+}
 
-        Ok(Self::from_parts(
-            signature[0..32].try_into().unwrap(),
-            signature[32..64].try_into().unwrap(),
-            y_parity,
-        ))
+impl Serialize for Signature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Signature {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let signature = String::deserialize(deserializer)?;
+        signature.parse().map_err(serde::de::Error::custom)
     }
 }
 
@@ -91,6 +223,117 @@ mod tests {
         assert_eq!(signature.v(Some(U256::new(1))), U256::new(37));
     }
 
+    #[test]
+    fn v_conventions() {
+        let r = [1; 32];
+        let s = [2; 32];
+        for (v, y_parity, chain_id) in [
+            (0, 0, None),
+            (1, 1, None),
+            (27, 0, None),
+            (28, 1, None),
+            (37, 0, Some(1)),
+            (38, 1, Some(1)),
+            (2709, 0, Some(1337)),
+        ] {
+            let (signature, id) = Signature::from_rsv(r, s, U256::new(v)).unwrap();
+            assert_eq!(signature.y_parity(), U256::new(y_parity));
+            assert_eq!(id, chain_id.map(U256::new));
+
+            let format = match chain_id {
+                Some(chain_id) => VFormat::Eip155(U256::new(chain_id)),
+                None if v < 27 => VFormat::YParity,
+                None => VFormat::Legacy,
+            };
+            assert_eq!(signature.v_with(format), U256::new(v));
+            assert_eq!(
+                Signature::from_bytes(&signature.to_bytes(format)).unwrap(),
+                signature
+            );
+        }
+        for v in [2, 26, 29, 34] {
+            assert!(Signature::from_rsv(r, s, U256::new(v)).is_err());
+        }
+
+        let signature = Signature::from_parts(r, s, 1);
+        assert_eq!(signature.to_bytes(VFormat::YParity).len(), 65);
+        assert_eq!(
+            signature.to_bytes(VFormat::Eip155(U256::new(1337))).len(),
+            66
+        );
+        for hex in [
+            "0x0101010101010101010101010101010101010101010101010101010101010101\
+               0202020202020202020202020202020202020202020202020202020202020202\
+               01",
+            "0101010101010101010101010101010101010101010101010101010101010101\
+             0202020202020202020202020202020202020202020202020202020202020202\
+             26",
+        ] {
+            assert_eq!(hex.parse::<Signature>().unwrap(), signature);
+        }
+    }
+
+    #[test]
+    fn compact_signatures() {
+        // EIP-2098 test vectors
+        for (r, s, y_parity, y_parity_and_s) in [
+            (
+                hex_literal::hex!(
+                    "68a020a209d3d56c46f38cc50a33f704f4a9a10a59377f8dd762ac66910e9b90"
+                ),
+                hex_literal::hex!(
+                    "7e865ad05c4035ab5792787d4a0297a43617ae897930a6fe4d822b8faea52064"
+                ),
+                0,
+                "7e865ad05c4035ab5792787d4a0297a43617ae897930a6fe4d822b8faea52064",
+            ),
+            (
+                hex_literal::hex!(
+                    "9328da16089fcba9bececa81663203989f2df5fe1faa6291a45381c81bd17f76"
+                ),
+                hex_literal::hex!(
+                    "139c6d6b623b42da56557e5e734a43dc83345ddfadec52cbe24d0cc64f550793"
+                ),
+                1,
+                "939c6d6b623b42da56557e5e734a43dc83345ddfadec52cbe24d0cc64f550793",
+            ),
+        ] {
+            let signature = Signature::from_parts(r, s, y_parity);
+            let compact = signature.to_compact();
+            assert_eq!(compact[..32], r);
+            assert_eq!(hex::encode(&compact[32..]), y_parity_and_s);
+            assert_eq!(Signature::from_bytes(&compact).unwrap(), signature);
+        }
+    }
+
+    #[test]
+    fn low_s() {
+        // secp256k1 curve order minus 2
+        let high_s =
+            hex_literal::hex!("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd036413f");
+        let signature = Signature::from_parts([1; 32], high_s, 0);
+        assert!(!signature.is_low_s());
+        assert!(signature.ensure_low_s().is_err());
+
+        let normalized = signature.normalize_s();
+        assert!(normalized.is_low_s());
+        assert_eq!(normalized.s(), U256::new(2));
+        assert_eq!(normalized.y_parity(), U256::ONE);
+        assert_eq!(normalized.normalize_s(), normalized);
+        assert_eq!(
+            Signature::from_bytes(&signature.to_compact()).unwrap(),
+            normalized
+        );
+    }
+
+    #[test]
+    fn serde() {
+        let signature = Signature::from_parts([1; 32], [2; 32], 1);
+        let json = serde_json::to_string(&signature).unwrap();
+        assert_eq!(json, format!("\"{signature}\""));
+        assert_eq!(serde_json::from_str::<Signature>(&json).unwrap(), signature);
+    }
+
     #[test]
     fn signature_to_string() {
         let signature = Signature::from_parts([1; 32], [2; 32], 0);